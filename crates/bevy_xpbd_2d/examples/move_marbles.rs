@@ -96,6 +96,8 @@ fn setup(
                 },
                 RigidBody::Dynamic,
                 Collider::ball(marble_radius as Scalar),
+                // Lets the marbles come to rest instead of rolling forever
+                RollingFriction::new(0.5),
                 Marble,
             ));
         }
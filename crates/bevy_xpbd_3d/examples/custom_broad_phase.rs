@@ -82,16 +82,32 @@ impl Plugin for BruteForceBroadPhasePlugin {
 }
 
 fn collect_collision_pairs(
-    bodies: Query<(Entity, &ColliderAabb, &RigidBody)>,
+    bodies: Query<(
+        Entity,
+        &ColliderAabb,
+        &RigidBody,
+        Option<&CollisionExclusions>,
+    )>,
     mut broad_collision_pairs: ResMut<BroadCollisionPairs>,
+    connected_bodies: Res<ConnectedBodies>,
 ) {
     // Clear old collision pairs
     broad_collision_pairs.0.clear();
 
     // Loop through all entity combinations and collect pairs of bodies with intersecting AABBs
-    for [(ent_a, aabb_a, rb_a), (ent_b, aabb_b, rb_b)] in bodies.iter_combinations() {
-        // At least one of the bodies is dynamic and their AABBs intersect
-        if (rb_a.is_dynamic() || rb_b.is_dynamic()) && aabb_a.intersects(&aabb_b.0) {
+    for [(ent_a, aabb_a, rb_a, exclusions_a), (ent_b, aabb_b, rb_b, exclusions_b)] in
+        bodies.iter_combinations()
+    {
+        // Bodies that exclude each other never collide
+        let excluded = exclusions_a.is_some_and(|exclusions| exclusions.excludes(ent_b))
+            || exclusions_b.is_some_and(|exclusions| exclusions.excludes(ent_a));
+
+        // At least one of the bodies is dynamic, they aren't connected by a joint or excluded and their AABBs intersect
+        if (rb_a.is_dynamic() || rb_b.is_dynamic())
+            && !connected_bodies.contains(ent_a, ent_b)
+            && !excluded
+            && aabb_a.intersects(&aabb_b.0)
+        {
             broad_collision_pairs.0.push((ent_a, ent_b));
         }
     }
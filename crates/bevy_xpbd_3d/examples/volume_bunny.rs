@@ -1,6 +1,6 @@
 #![allow(clippy::unnecessary_cast)]
 
-use bevy::{prelude::*, render::render_resource::ShaderType, utils::hashbrown::HashMap};
+use bevy::{prelude::*, render::mesh::PrimitiveTopology, utils::hashbrown::HashMap};
 use bevy_inspector_egui::{
     inspector_options::ReflectInspectorOptions,
     quick::{ResourceInspectorPlugin, WorldInspectorPlugin},
@@ -21,6 +21,10 @@ struct Configuration {
     edge_compliance: f32,
     #[inspector(min = 0.001, max = 1.0, speed = 0.01)]
     volume_compliance: f32,
+    #[inspector(min = 0.0, max = 1.0, speed = 0.001)]
+    edge_damping: f32,
+    #[inspector(min = 0.0, max = 1.0, speed = 0.001)]
+    volume_damping: f32,
 }
 
 fn handle_configuration(
@@ -35,9 +39,11 @@ fn handle_configuration(
         gravity.0 = Vector::NEG_Y * configuration.gravity;
         for mut constraint in volume_constraints.iter_mut() {
             constraint.compliance = configuration.volume_compliance;
+            constraint.damping = configuration.volume_damping;
         }
         for mut constraint in edge_constraints.iter_mut() {
             constraint.compliance = configuration.edge_compliance;
+            constraint.damping = configuration.edge_damping;
         }
     }
 }
@@ -48,9 +54,17 @@ fn main() {
         gravity: 0.0,//9.81,
         edge_compliance: 0.04,
         volume_compliance: 0.1,
+        edge_damping: 0.02,
+        volume_damping: 0.02,
     };
     App::new()
-        .add_plugins((DefaultPlugins, XpbdExamplePlugin))
+        .add_plugins((
+            DefaultPlugins,
+            XpbdExamplePlugin,
+            PhysicsDebugPlugin::default(),
+        ))
+        // Color the constraints by their strain and volume change
+        .insert_resource(PhysicsDebugConfig::soft_bodies(0.1, 0.2, 0.5))
         .insert_resource(config)
         .register_type::<Configuration>()
         .add_plugins(ResourceInspectorPlugin::<Configuration>::default())
@@ -69,10 +83,8 @@ fn main() {
             Update,
             (
                 movement,
-                draw_debug_volume_constraints,
-                draw_debug_edge_constraints,
-                draw_debug_isometric_bend_constraints,
                 handle_configuration,
+                cut,
             ),
         )
         .run();
@@ -102,14 +114,6 @@ fn setup(
     ));
 
     let particle_radius = 0.01;
-    let particle_mesh = meshes.add(
-        Mesh::try_from(shape::Icosphere {
-            radius: particle_radius as f32,
-            ..default()
-        })
-        .unwrap(),
-    );
-    let particle_material = materials.add(StandardMaterial::from(Color::rgb(0.2, 0.7, 0.9)));
 
     // Spawn particles from mesh data
     let verts = mesh_data
@@ -122,17 +126,11 @@ fn setup(
         .map(|position| {
             commands
                 .spawn((
-                    DragParticle::enabled(),
                     RigidBody::Dynamic,
                     LockedAxes::ROTATION_LOCKED, // The RigidBody here acts as a particle
                     Collider::ball(particle_radius),
                     // ColliderDensity(0.0), // TODO figure out why it panics when the density is less than 1.0
-                    PbrBundle {
-                        mesh: particle_mesh.clone(),
-                        material: particle_material.clone(),
-                        transform: Transform::from_translation(*position),
-                        ..default()
-                    },
+                    SpatialBundle::from_transform(Transform::from_translation(*position)),
                 ))
                 .id()
         })
@@ -153,7 +151,8 @@ fn setup(
             &particles[chunk[3]],
             &verts[chunk[3]],
         )
-        .with_compliance(config.volume_compliance);
+        .with_compliance(config.volume_compliance)
+        .with_damping(config.volume_damping);
         // Add the fourth of the rest volume to each particle's mass
         let mass = constraint.rest_volume / 4.0;
         for chunk in chunk.iter() {
@@ -183,10 +182,38 @@ fn setup(
                 &particles[chunk[1]],
                 &verts[chunk[1]],
             )
-            .with_compliance(config.edge_compliance),
+            .with_compliance(config.edge_compliance)
+            .with_damping(config.edge_damping),
         );
     }
 
+    // The surface mesh is rebuilt from the particle positions every frame
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Mesh::new(PrimitiveTopology::TriangleList)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::rgb(0.2, 0.7, 0.9),
+                double_sided: true,
+                cull_mode: None,
+                ..default()
+            }),
+            ..default()
+        },
+        SoftBodySurface {
+            triangles: mesh_data
+                .tetSurfaceTriIds
+                .chunks_exact(3)
+                .map(|chunk| {
+                    [
+                        particles[chunk[0]],
+                        particles[chunk[1]],
+                        particles[chunk[2]],
+                    ]
+                })
+                .collect(),
+        },
+    ));
+
     // Camera
     commands.spawn(Camera3dBundle {
         transform: Transform::from_translation(Vec3::new(0.0, 4.0, 14.0))
@@ -224,6 +251,13 @@ fn movement(
     }
 }
 
+/// Splits the bunny in half along the YZ-plane when space is pressed.
+fn cut(keyboard_input: Res<Input<KeyCode>>, mut cuts: EventWriter<CutSoftBody>) {
+    if keyboard_input.just_pressed(KeyCode::Space) {
+        cuts.send(CutSoftBody::plane(Vector::ZERO, Vector::X));
+    }
+}
+
 fn ui(mut commands: Commands) {
     commands.spawn(Camera2dBundle {
         camera: Camera {
@@ -245,7 +279,7 @@ fn ui(mut commands: Commands) {
             // text
             parent.spawn((
                 TextBundle::from_section(
-                    "Click and drag the particles to move them around",
+                    "Click and drag the particles to move them around\nPress space to cut the bunny in half",
                     TextStyle {
                         font_size: 20.0,
                         color: Color::WHITE,
@@ -14,7 +14,7 @@ impl Plugin for XpbdExamplePlugin {
         app.add_plugins((
             PhysicsPlugins::default(),
             FrameTimeDiagnosticsPlugin,
-            DragParticlePlugin,
+            PhysicsPickingPlugin,
         ))
         .add_state::<AppState>()
         .add_systems(Startup, setup)
@@ -0,0 +1,169 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// A gravity field that overrides the global [`Gravity`] for the [rigid bodies](RigidBody) inside of it.
+///
+/// Gravity fields can be used for things like small planets that bodies orbit and walk on,
+/// or rooms and platforms with their own direction of gravity.
+///
+/// If the entity has a [`Collider`], the field only affects bodies whose [`Position`] is inside of the collider.
+/// Otherwise, the field affects all bodies, and point fields without a [`Position`] pull bodies towards the origin.
+/// The collider should usually be a [`Sensor`] so that it doesn't block the bodies it affects.
+///
+/// Only bodies whose [`CollisionLayers`] interact with the [`CollisionLayers`] of the field are affected.
+///
+/// ## Priorities
+///
+/// When a body is inside of one or more gravity fields, the global [`Gravity`] is ignored,
+/// and only the fields with the highest [`priority`](GravityField::priority) affect the body.
+/// The accelerations of fields with the same priority are added together.
+///
+/// The gravity of fields is scaled by the [`GravityScale`] of bodies just like the global gravity.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+#[cfg_attr(feature = "2d", doc = "use bevy_xpbd_2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use bevy_xpbd_3d::prelude::*;")]
+///
+/// # #[cfg(feature = "f32")]
+/// fn setup(mut commands: Commands) {
+///     // A small planet with a radius of 5
+///     commands.spawn((RigidBody::Static, Collider::ball(5.0)));
+///
+///     // The gravity of the planet that attracts bodies within 30 units of its center
+///     commands.spawn((
+///         Collider::ball(30.0),
+///         Sensor,
+///         GravityField::point(9.81, GravityFalloff::InverseSquare { radius: 5.0 }),
+///     ));
+///
+///     // A room where gravity points to the right
+///     commands.spawn((
+#[cfg_attr(feature = "2d", doc = "        Collider::cuboid(10.0, 10.0),")]
+#[cfg_attr(feature = "3d", doc = "        Collider::cuboid(10.0, 10.0, 10.0),")]
+///         Sensor,
+#[cfg_attr(
+    feature = "2d",
+    doc = "        GravityField::directional(Vec2::X * 9.81).with_priority(1),"
+)]
+#[cfg_attr(
+    feature = "3d",
+    doc = "        GravityField::directional(Vec3::X * 9.81).with_priority(1),"
+)]
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct GravityField {
+    /// The type of the gravity field.
+    pub kind: GravityFieldKind,
+    /// The priority of the field. When fields overlap, only the fields with the highest priority affect a body.
+    pub priority: i32,
+}
+
+impl GravityField {
+    /// Creates a gravity field that pulls bodies towards its center with the given `acceleration`
+    /// and [falloff](GravityFalloff).
+    pub fn point(acceleration: Scalar, falloff: GravityFalloff) -> Self {
+        Self {
+            kind: GravityFieldKind::Point {
+                acceleration,
+                falloff,
+            },
+            priority: 0,
+        }
+    }
+
+    /// Creates a gravity field with the given `acceleration` in the local space of the field.
+    pub fn directional(acceleration: Vector) -> Self {
+        Self {
+            kind: GravityFieldKind::Directional { acceleration },
+            priority: 0,
+        }
+    }
+
+    /// Sets the priority of the field.
+    pub fn with_priority(self, priority: i32) -> Self {
+        Self { priority, ..self }
+    }
+
+    /// Computes the gravitational acceleration at the given `point` for a field
+    /// at the given `position` and `rotation`.
+    ///
+    /// The volume of the field is not taken into account.
+    pub fn acceleration_at(&self, position: Vector, rotation: &Rotation, point: Vector) -> Vector {
+        match self.kind {
+            GravityFieldKind::Point {
+                acceleration,
+                falloff,
+            } => {
+                let offset = position - point;
+                let distance = offset.length();
+                if distance <= Scalar::EPSILON {
+                    return Vector::ZERO;
+                }
+                offset / distance * acceleration * falloff.factor(distance)
+            }
+            GravityFieldKind::Directional { acceleration } => rotation.rotate(acceleration),
+        }
+    }
+}
+
+/// The type of a [`GravityField`].
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum GravityFieldKind {
+    /// Pulls bodies towards the center of the field, like the gravity of a planet.
+    Point {
+        /// The magnitude of the acceleration before the falloff is applied.
+        acceleration: Scalar,
+        /// How the acceleration changes based on the distance to the center of the field.
+        falloff: GravityFalloff,
+    },
+    /// Accelerates bodies in a direction given in the local space of the field.
+    Directional {
+        /// The acceleration in the local space of the field.
+        acceleration: Vector,
+    },
+}
+
+/// Determines how the acceleration of a [point](GravityFieldKind::Point) [`GravityField`] changes
+/// based on the distance to the center of the field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum GravityFalloff {
+    /// The acceleration is the same at every distance.
+    #[default]
+    None,
+    /// The acceleration is inversely proportional to the square of the distance outside of the given radius,
+    /// like the gravity of a planet. Inside of the radius, it decreases linearly towards the center.
+    InverseSquare {
+        /// The radius at which the acceleration has its full magnitude, usually the radius of the planet.
+        radius: Scalar,
+    },
+    /// The acceleration decreases linearly from full magnitude at the center to zero at the given distance.
+    Linear {
+        /// The distance at which the acceleration reaches zero.
+        distance: Scalar,
+    },
+}
+
+impl GravityFalloff {
+    /// Returns the factor that the acceleration is multiplied by at the given distance from the center of the field.
+    pub fn factor(&self, distance: Scalar) -> Scalar {
+        match *self {
+            Self::None => 1.0,
+            Self::InverseSquare { radius } => {
+                if distance < radius {
+                    distance / radius
+                } else {
+                    (radius / distance).powi(2)
+                }
+            }
+            Self::Linear { distance: max } => (1.0 - distance / max).max(0.0),
+        }
+    }
+}
@@ -1,4 +1,8 @@
-use bevy::prelude::*;
+use bevy::{
+    ecs::entity::{EntityMapper, MapEntities},
+    prelude::*,
+    utils::HashSet,
+};
 
 /// A layer used for determining which entities should interact with each other.
 /// Physics layers are used heavily by [`CollisionLayers`].
@@ -210,3 +214,64 @@ impl Default for CollisionLayers {
         }
     }
 }
+
+/// A component that lists entities that an entity should never collide with.
+///
+/// The listed entities can be colliders or rigid bodies. If an entity listed by a collider or its rigid body
+/// is either the other collider or its rigid body, the pair is skipped in the broad phase.
+/// It is enough for one of the two entities to exclude the other.
+///
+/// Bodies connected by a [joint](crate::prelude::Joint) are excluded from colliding with each other automatically,
+/// unless the joint was configured with [`Joint::with_collide_connected`](crate::prelude::Joint::with_collide_connected).
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+#[cfg_attr(feature = "2d", doc = "use bevy_xpbd_2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use bevy_xpbd_3d::prelude::*;")]
+///
+/// fn setup(mut commands: Commands) {
+///     let player = commands.spawn((RigidBody::Dynamic, Collider::ball(0.5))).id();
+///
+///     // The pet can walk through its owner, but collides with everything else
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         Collider::ball(0.25),
+///         CollisionExclusions::new([player]),
+///     ));
+/// }
+/// ```
+#[derive(Reflect, Clone, Component, Debug, Default, Deref, DerefMut, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct CollisionExclusions(pub HashSet<Entity>);
+
+impl CollisionExclusions {
+    /// Creates a new [`CollisionExclusions`] component with the given entities.
+    pub fn new(entities: impl IntoIterator<Item = Entity>) -> Self {
+        Self(entities.into_iter().collect())
+    }
+
+    /// Adds the given entity to the excluded entities.
+    pub fn with_entity(mut self, entity: Entity) -> Self {
+        self.0.insert(entity);
+        self
+    }
+
+    /// Returns `true` if the given entity is excluded.
+    pub fn excludes(&self, entity: Entity) -> bool {
+        self.0.contains(&entity)
+    }
+}
+
+impl MapEntities for CollisionExclusions {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        self.0 = self
+            .0
+            .clone()
+            .into_iter()
+            .map(|e| entity_mapper.get_or_reserve(e))
+            .collect()
+    }
+}
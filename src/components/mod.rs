@@ -2,6 +2,7 @@
 
 mod collider;
 mod forces;
+mod gravity_field;
 mod layers;
 mod locked_axes;
 mod mass_properties;
@@ -10,6 +11,7 @@ mod world_queries;
 
 pub use collider::*;
 pub use forces::*;
+pub use gravity_field::*;
 pub use layers::*;
 pub use locked_axes::*;
 pub use mass_properties::*;
@@ -279,6 +281,19 @@ pub struct TimeSleeping(pub Scalar);
 #[reflect(Component)]
 pub struct SleepingDisabled;
 
+/// A marker component for the static [rigid body](RigidBody) that represents the world in [constraints] and [joints].
+///
+/// The body is spawned by the [`PreparePlugin`] when it is built, and its entity is stored in the [`WorldBodyEntity`] resource.
+/// It is located at the origin and has no collider, and it should not be moved.
+///
+/// If the body is despawned, a new one is spawned at the start of the next physics frame and a warning is logged.
+/// Constraints attached to the old body are not remapped, so they are no longer solved
+/// until they are attached to the new one again.
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct WorldBody;
+
 /// The global position of a [rigid body](RigidBody) or a [collider](Collider).
 ///
 /// ## Relation to `Transform` and `GlobalTransform`
@@ -444,7 +459,7 @@ pub(crate) struct PreSolveAngularVelocity(pub Scalar);
 #[reflect(Component)]
 pub(crate) struct PreSolveAngularVelocity(pub Vector);
 
-/// Controls how [gravity](Gravity) affects a specific [rigid body](RigidBody).
+/// Controls how [gravity](Gravity) and [gravity fields](GravityField) affect a specific [rigid body](RigidBody).
 ///
 /// A gravity scale of `0.0` will disable gravity, while `2.0` will double the gravity.
 /// Using a negative value will flip the direction of the gravity.
@@ -764,6 +779,269 @@ impl From<Scalar> for Friction {
     }
 }
 
+/// Controls how strongly the material of an entity resists rolling on other surfaces,
+/// slowing down the relative angular velocity of bodies around axes along the contact surface.
+///
+/// Without rolling friction, balls and cylinders can roll on flat ground forever,
+/// as they don't slide relative to the ground.
+///
+/// The coefficient is a distance in meters: the largest torque that rolling friction applies
+/// is the coefficient multiplied by the normal force at the contact. Rolling friction is
+/// usually much weaker than [`Friction`], with coefficients like 0.01 for a marble on wood.
+/// The default coefficient is 0.0, which corresponds to no rolling friction.
+///
+/// Rolling friction can be added to [rigid bodies](RigidBody) and [colliders](Collider).
+/// If a collider has no rolling friction, the rolling friction of its rigid body is used.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+#[cfg_attr(feature = "2d", doc = "use bevy_xpbd_2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use bevy_xpbd_3d::prelude::*;")]
+///
+/// fn setup(mut commands: Commands) {
+///     // A marble that comes to rest after rolling for a while
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         Collider::ball(0.1),
+///         RollingFriction::new(0.01).with_combine_rule(CoefficientCombine::Max),
+///     ));
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Component, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct RollingFriction {
+    /// The coefficient of rolling friction, the largest torque per unit of normal force in meters.
+    pub coefficient: Scalar,
+    /// The coefficient combine rule used when two bodies collide.
+    pub combine_rule: CoefficientCombine,
+}
+
+impl RollingFriction {
+    /// A rolling friction coefficient of 0.0 and a combine rule of [`CoefficientCombine::Average`].
+    pub const ZERO: Self = Self {
+        coefficient: 0.0,
+        combine_rule: CoefficientCombine::Average,
+    };
+
+    /// Creates a new [`RollingFriction`] component with the given rolling friction coefficient.
+    pub fn new(coefficient: Scalar) -> Self {
+        Self {
+            coefficient,
+            combine_rule: CoefficientCombine::Average,
+        }
+    }
+
+    /// Sets the [`CoefficientCombine`] rule used.
+    pub fn with_combine_rule(&self, combine_rule: CoefficientCombine) -> Self {
+        Self {
+            combine_rule,
+            ..*self
+        }
+    }
+
+    /// Combines the properties of two [`RollingFriction`] components.
+    pub fn combine(&self, other: Self) -> Self {
+        // Choose rule with higher priority
+        let rule = self.combine_rule.max(other.combine_rule);
+
+        Self {
+            coefficient: match rule {
+                CoefficientCombine::Average => (self.coefficient + other.coefficient) * 0.5,
+                CoefficientCombine::Min => self.coefficient.min(other.coefficient),
+                CoefficientCombine::Multiply => self.coefficient * other.coefficient,
+                CoefficientCombine::Max => self.coefficient.max(other.coefficient),
+            },
+            combine_rule: rule,
+        }
+    }
+}
+
+impl Default for RollingFriction {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<Scalar> for RollingFriction {
+    fn from(coefficient: Scalar) -> Self {
+        Self {
+            coefficient,
+            ..default()
+        }
+    }
+}
+
+/// Controls how strongly the material of an entity resists spinning on other surfaces,
+/// slowing down the relative angular velocity of bodies around the contact normal.
+///
+/// This can be used to stop tops and coins from spinning in place indefinitely.
+///
+/// Like [`RollingFriction`], the coefficient is a distance in meters: the largest torque that
+/// torsional friction applies is the coefficient multiplied by the normal force at the contact.
+/// The default coefficient is 0.0, which corresponds to no torsional friction.
+///
+/// Torsional friction can be added to [rigid bodies](RigidBody) and [colliders](Collider).
+/// If a collider has no torsional friction, the torsional friction of its rigid body is used.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         Collider::cylinder(0.01, 0.2),
+///         TorsionalFriction::new(0.02),
+///     ));
+/// }
+/// ```
+#[cfg(feature = "3d")]
+#[derive(Reflect, Clone, Copy, Component, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct TorsionalFriction {
+    /// The coefficient of torsional friction, the largest torque per unit of normal force in meters.
+    pub coefficient: Scalar,
+    /// The coefficient combine rule used when two bodies collide.
+    pub combine_rule: CoefficientCombine,
+}
+
+#[cfg(feature = "3d")]
+impl TorsionalFriction {
+    /// A torsional friction coefficient of 0.0 and a combine rule of [`CoefficientCombine::Average`].
+    pub const ZERO: Self = Self {
+        coefficient: 0.0,
+        combine_rule: CoefficientCombine::Average,
+    };
+
+    /// Creates a new [`TorsionalFriction`] component with the given torsional friction coefficient.
+    pub fn new(coefficient: Scalar) -> Self {
+        Self {
+            coefficient,
+            combine_rule: CoefficientCombine::Average,
+        }
+    }
+
+    /// Sets the [`CoefficientCombine`] rule used.
+    pub fn with_combine_rule(&self, combine_rule: CoefficientCombine) -> Self {
+        Self {
+            combine_rule,
+            ..*self
+        }
+    }
+
+    /// Combines the properties of two [`TorsionalFriction`] components.
+    pub fn combine(&self, other: Self) -> Self {
+        // Choose rule with higher priority
+        let rule = self.combine_rule.max(other.combine_rule);
+
+        Self {
+            coefficient: match rule {
+                CoefficientCombine::Average => (self.coefficient + other.coefficient) * 0.5,
+                CoefficientCombine::Min => self.coefficient.min(other.coefficient),
+                CoefficientCombine::Multiply => self.coefficient * other.coefficient,
+                CoefficientCombine::Max => self.coefficient.max(other.coefficient),
+            },
+            combine_rule: rule,
+        }
+    }
+}
+
+#[cfg(feature = "3d")]
+impl Default for TorsionalFriction {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+#[cfg(feature = "3d")]
+impl From<Scalar> for TorsionalFriction {
+    fn from(coefficient: Scalar) -> Self {
+        Self {
+            coefficient,
+            ..default()
+        }
+    }
+}
+
+/// The velocity of the surface of a [`Collider`] relative to the collider itself,
+/// expressed in the local space of the collider.
+///
+/// [Friction] and [restitution](Restitution) treat the surface as if it was moving, so bodies
+/// touching it are carried along even though the collider stays in place. This can be used for
+/// conveyor belts, escalators and treadmills made of static geometry.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+#[cfg_attr(feature = "2d", doc = "use bevy_xpbd_2d::{math::*, prelude::*};")]
+#[cfg_attr(feature = "3d", doc = "use bevy_xpbd_3d::{math::*, prelude::*};")]
+///
+/// fn setup(mut commands: Commands) {
+///     // A conveyor belt that carries bodies along the X axis
+///     commands.spawn((
+///         RigidBody::Static,
+#[cfg_attr(feature = "2d", doc = "        Collider::cuboid(10.0, 0.5),")]
+#[cfg_attr(feature = "3d", doc = "        Collider::cuboid(10.0, 0.5, 2.0),")]
+///         SurfaceVelocity::new(Vector::X * 2.0),
+///     ));
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct SurfaceVelocity {
+    /// The linear velocity of the surface in the local space of the collider.
+    pub linear: Vector,
+    /// The angular velocity of the surface around the local origin of the collider.
+    #[cfg(feature = "2d")]
+    pub angular: Scalar,
+    /// The angular velocity of the surface around the local origin of the collider,
+    /// as a local-space rotation axis multiplied by the angular speed in radians per second.
+    #[cfg(feature = "3d")]
+    pub angular: Vector,
+}
+
+impl SurfaceVelocity {
+    /// Creates a new [`SurfaceVelocity`] with the given local-space linear velocity.
+    pub fn new(linear: Vector) -> Self {
+        Self {
+            linear,
+            ..default()
+        }
+    }
+
+    /// Sets the angular velocity of the surface around the local origin of the collider.
+    #[cfg(feature = "2d")]
+    pub fn with_angular(self, angular: Scalar) -> Self {
+        Self { angular, ..self }
+    }
+
+    /// Sets the angular velocity of the surface around the local origin of the collider.
+    #[cfg(feature = "3d")]
+    pub fn with_angular(self, angular: Vector) -> Self {
+        Self { angular, ..self }
+    }
+
+    /// Returns the velocity of the surface at the given point in the local space of the collider.
+    pub fn velocity_at_point(&self, point: Vector) -> Vector {
+        #[cfg(feature = "2d")]
+        {
+            self.linear + self.angular * point.perp()
+        }
+        #[cfg(feature = "3d")]
+        {
+            self.linear + self.angular.cross(point)
+        }
+    }
+}
+
 /// Automatically slows down a dynamic [rigid body](RigidBody), decreasing its
 /// [linear velocity](LinearVelocity) each frame. This can be used to simulate air resistance.
 ///
@@ -848,6 +1126,52 @@ pub struct AngularDamping(pub Scalar);
 #[reflect(Component)]
 pub struct Dominance(pub i8);
 
+/// Enables **continuous collision detection** (CCD) for a [rigid body](RigidBody).
+///
+/// Contacts are normally only computed at the positions of bodies at the end of each substep.
+/// Fast and small bodies like bullets can move through thin colliders within a single substep
+/// without ever overlapping them, which is called *tunneling*. CCD prevents this at an additional cost.
+///
+/// ## Modes
+///
+/// - [`Ccd::Sweep`]: The colliders of the body are swept from their positions at the start of the substep
+///   to their predicted positions against the colliders in [`BroadCollisionPairs`], which are found using swept
+///   [AABBs](ColliderAabb). If a collider hits something, the motion of the body along the normal of the hit
+///   is clamped at the earliest [time of impact](contact_query::time_of_impact), while its tangential motion
+///   is kept. The sweep is linear, so fast rotation can still cause tunneling. Sweeping is only done when
+///   the body moves further than the smallest half-extent of its collider during the substep.
+/// - [`Ccd::Speculative`]: Contacts are computed at the start of the substep with a prediction distance
+///   extended by the distance the body moves, so the solver can stop the body before it passes through colliders.
+///   This is cheaper than sweeping, but the contacts are less accurate, which can cause small ghost collisions.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+#[cfg_attr(feature = "2d", doc = "use bevy_xpbd_2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use bevy_xpbd_3d::prelude::*;")]
+///
+/// fn spawn_bullet(mut commands: Commands) {
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         Collider::ball(0.05),
+#[cfg_attr(feature = "2d", doc = "        LinearVelocity(Vec2::X * 500.0),")]
+#[cfg_attr(feature = "3d", doc = "        LinearVelocity(Vec3::X * 500.0),")]
+///         Ccd::Sweep,
+///     ));
+/// }
+/// ```
+#[derive(Component, Reflect, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub enum Ccd {
+    /// Sweeps the colliders of the body and moves the body back to the earliest time of impact.
+    #[default]
+    Sweep,
+    /// Computes speculative contacts with an extended prediction distance.
+    Speculative,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
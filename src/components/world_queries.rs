@@ -26,6 +26,9 @@ pub struct RigidBodyQuery {
     pub center_of_mass: &'static mut CenterOfMass,
     pub friction: &'static Friction,
     pub restitution: &'static Restitution,
+    pub rolling_friction: Option<&'static RollingFriction>,
+    #[cfg(feature = "3d")]
+    pub torsional_friction: Option<&'static TorsionalFriction>,
     pub locked_axes: Option<&'static LockedAxes>,
     pub dominance: Option<&'static Dominance>,
 }
@@ -0,0 +1,113 @@
+//! Bending constraints for cloth and shells.
+
+use crate::prelude::*;
+use bevy::{prelude::*, utils::HashMap};
+
+/// The type of bending constraint used between triangles that share an edge when building cloth and shells.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// # #[cfg(feature = "f32")]
+/// fn setup(mut commands: Commands) {
+///     // A square of cloth made of two triangles
+///     let positions = [Vec3::ZERO, Vec3::X, Vec3::Z, Vec3::new(1.0, 0.0, 1.0)];
+///     let particles = positions.map(|position| {
+///         commands
+///             .spawn((RigidBody::Dynamic, Position(position), Collider::ball(0.01)))
+///             .id()
+///     });
+///
+///     BendingModel::Dihedral.spawn_constraints(
+///         &mut commands,
+///         &particles,
+///         &positions,
+///         &[[0, 1, 2], [2, 1, 3]],
+///         0.001,
+///     );
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum BendingModel {
+    /// Uses [`IsometricBendingConstraint`]s. They are cheap, but only accurate for
+    /// meshes that have a flat rest shape and don't stretch.
+    #[default]
+    Isometric,
+    /// Uses [`DihedralBendingConstraint`]s. They stay accurate when the mesh stretches,
+    /// and they keep the current shape of the mesh as the rest shape.
+    Dihedral,
+}
+
+impl BendingModel {
+    /// Spawns a bending constraint with the given compliance for each pair of triangles that share an edge.
+    ///
+    /// The triangles are given as indices into `particles` and `positions`, which contain the particle entities
+    /// and their rest positions. Returns the spawned constraint entities.
+    pub fn spawn_constraints(
+        self,
+        commands: &mut Commands,
+        particles: &[Entity],
+        positions: &[Vector],
+        triangles: &[[usize; 3]],
+        compliance: Scalar,
+    ) -> Vec<Entity> {
+        // Find the vertices opposite to each edge
+        let mut opposite_vertices = HashMap::<(usize, usize), Vec<usize>>::new();
+        for triangle in triangles {
+            for i in 0..3 {
+                let (a, b, c) = (triangle[i], triangle[(i + 1) % 3], triangle[(i + 2) % 3]);
+                opposite_vertices
+                    .entry((a.min(b), a.max(b)))
+                    .or_default()
+                    .push(c);
+            }
+        }
+
+        // Sort the edges to keep entity allocation deterministic
+        let mut edges = opposite_vertices
+            .into_iter()
+            .filter(|(_, opposite)| opposite.len() == 2)
+            .collect::<Vec<_>>();
+        edges.sort();
+
+        edges
+            .into_iter()
+            .map(|((a, b), opposite)| {
+                let [c, d] = [opposite[0], opposite[1]];
+                match self {
+                    BendingModel::Isometric => commands.spawn(
+                        IsometricBendingConstraint::new(
+                            &particles[a],
+                            &positions[a],
+                            &particles[b],
+                            &positions[b],
+                            &particles[c],
+                            &positions[c],
+                            &particles[d],
+                            &positions[d],
+                        )
+                        .with_compliance(compliance),
+                    ),
+                    BendingModel::Dihedral => commands.spawn(
+                        DihedralBendingConstraint::new(
+                            &particles[a],
+                            &positions[a],
+                            &particles[b],
+                            &positions[b],
+                            &particles[c],
+                            &positions[c],
+                            &particles[d],
+                            &positions[d],
+                        )
+                        .with_compliance(compliance),
+                    ),
+                }
+                .id()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,187 @@
+//! Dihedral bending constraint.
+
+use crate::prelude::*;
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+/// Constrains the dihedral angle between two triangles that share an edge.
+///
+/// The particles are laid out in the same way as in [`IsometricBendingConstraint`]:
+/// `entity1` and `entity2` form the shared edge, and `entity3` and `entity4` are the opposite
+/// particles of the two triangles.
+///
+/// Unlike the [`IsometricBendingConstraint`], the constraint is evaluated from the current angle between
+/// the triangles, so it stays accurate when the edges stretch, and it supports non-flat rest shapes
+/// through the [rest angle](Self::rest_angle).
+///
+/// A compliance of 0.0 resembles a constraint with infinite stiffness.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(MapEntities)]
+pub struct DihedralBendingConstraint {
+    /// First particle of the shared edge.
+    pub entity1: Entity,
+    /// Second particle of the shared edge.
+    pub entity2: Entity,
+    /// Head of the first triangle.
+    pub entity3: Entity,
+    /// Head of the second triangle.
+    pub entity4: Entity,
+    /// The angle between the normals of the triangles in the rest pose, in radians.
+    /// Zero corresponds to a flat rest shape.
+    pub rest_angle: Scalar,
+    /// The constraint's compliance, the inverse of stiffness, has the unit radians / Newton.
+    pub compliance: Scalar,
+    /// The constraint's damping coefficient. It damps the rate at which the angle changes.
+    pub damping: Scalar,
+    /// Lagrange multiplier for the bending angle.
+    pub lagrange: Scalar,
+}
+
+impl XpbdConstraint<4> for DihedralBendingConstraint {
+    fn entities(&self) -> [Entity; 4] {
+        [self.entity1, self.entity2, self.entity3, self.entity4]
+    }
+
+    fn clear_lagrange_multipliers(&mut self) {
+        self.lagrange = 0.0;
+    }
+
+    /// Rotates the triangles around the shared edge towards the rest angle.
+    fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 4], dt: Scalar) {
+        let positions = bodies.each_ref().map(|body| body.current_position());
+        let Some((angle, gradients)) = Self::angle_and_gradients(&positions) else {
+            return;
+        };
+
+        // Take the shorter way around to the rest angle
+        let mut c = angle - self.rest_angle;
+        if c > PI {
+            c -= 2.0 * PI;
+        } else if c < -PI {
+            c += 2.0 * PI;
+        }
+
+        let inverse_masses = bodies.each_ref().map(|body| body.inverse_mass.0);
+        let displacements = bodies
+            .each_ref()
+            .map(|body| body.current_position() - body.previous_position.0);
+        let delta_lagrange = self.compute_damped_lagrange_update(
+            self.lagrange,
+            c,
+            &gradients,
+            &inverse_masses,
+            &displacements,
+            self.compliance,
+            self.damping,
+            dt,
+        );
+        self.lagrange += delta_lagrange;
+
+        for (body, (gradient, inverse_mass)) in bodies
+            .into_iter()
+            .zip(gradients.into_iter().zip(inverse_masses))
+        {
+            body.accumulated_translation.0 += delta_lagrange * inverse_mass * gradient;
+        }
+    }
+}
+
+impl DihedralBendingConstraint {
+    /// Creates a new [`DihedralBendingConstraint`] using the current angle between the triangles as the rest angle.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        entity1: &Entity,
+        position1: &Vector,
+        entity2: &Entity,
+        position2: &Vector,
+        entity3: &Entity,
+        position3: &Vector,
+        entity4: &Entity,
+        position4: &Vector,
+    ) -> Self {
+        let rest_angle =
+            Self::angle_and_gradients(&[*position1, *position2, *position3, *position4])
+                .map_or(0.0, |(angle, _)| angle);
+
+        Self {
+            entity1: *entity1,
+            entity2: *entity2,
+            entity3: *entity3,
+            entity4: *entity4,
+            rest_angle,
+            compliance: 0.0,
+            damping: 0.0,
+            lagrange: 0.0,
+        }
+    }
+
+    /// Sets the constraint's compliance (inverse of stiffness, radians / Newton).
+    pub fn with_compliance(mut self, compliance: Scalar) -> Self {
+        self.compliance = compliance;
+        self
+    }
+
+    /// Sets the constraint's damping coefficient.
+    pub fn with_damping(mut self, damping: Scalar) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Sets the angle between the normals of the triangles in the rest pose, in radians.
+    pub fn with_rest_angle(mut self, rest_angle: Scalar) -> Self {
+        self.rest_angle = rest_angle;
+        self
+    }
+
+    /// Computes the signed angle between the normals of the two triangles
+    /// and the gradients of the angle with respect to each particle.
+    ///
+    /// Returns `None` if the shared edge or either of the triangles is degenerate.
+    ///
+    /// The gradients are from Bridson et al., "Simulation of Clothing with Folds and Wrinkles".
+    pub fn angle_and_gradients(positions: &[Vector; 4]) -> Option<(Scalar, [Vector; 4])> {
+        let [p1, p2, p3, p4] = *positions;
+
+        let edge = p2 - p1;
+        let edge_length = edge.length();
+        if edge_length <= Scalar::EPSILON {
+            return None;
+        }
+
+        // Area-weighted normals of the two triangles
+        let n1 = (p1 - p3).cross(p2 - p3);
+        let n2 = (p2 - p4).cross(p1 - p4);
+        let (n1_length_squared, n2_length_squared) = (n1.length_squared(), n2.length_squared());
+        if n1_length_squared <= Scalar::EPSILON || n2_length_squared <= Scalar::EPSILON {
+            return None;
+        }
+
+        let u3 = edge_length * n1 / n1_length_squared;
+        let u4 = edge_length * n2 / n2_length_squared;
+        let u1 = (p3 - p2).dot(edge) / edge_length * n1 / n1_length_squared
+            + (p4 - p2).dot(edge) / edge_length * n2 / n2_length_squared;
+        let u2 = -(p3 - p1).dot(edge) / edge_length * n1 / n1_length_squared
+            - (p4 - p1).dot(edge) / edge_length * n2 / n2_length_squared;
+
+        let n1 = n1 / n1_length_squared.sqrt();
+        let n2 = n2 / n2_length_squared.sqrt();
+        let angle = n2.cross(n1).dot(edge / edge_length).atan2(n1.dot(n2));
+
+        Some((angle, [u1, u2, u3, u4]))
+    }
+}
+
+impl MapEntities for DihedralBendingConstraint {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        self.entity1 = entity_mapper.get_or_reserve(self.entity1);
+        self.entity2 = entity_mapper.get_or_reserve(self.entity2);
+        self.entity3 = entity_mapper.get_or_reserve(self.entity3);
+        self.entity4 = entity_mapper.get_or_reserve(self.entity4);
+    }
+}
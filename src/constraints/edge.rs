@@ -8,7 +8,6 @@ use bevy::{
     },
     prelude::*,
 };
-use itertools::Itertools;
 
 /// A compliance of 0.0 resembles a constraint with infinite stiffness, so the bodies should not have any overlap.
 #[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
@@ -23,6 +22,9 @@ pub struct EdgeConstraint {
     pub rest_length: Scalar,
     /// The constraint's compliance, the inverse of stiffness, has the unit meters / Newton.
     pub compliance: Scalar,
+    /// The constraint's damping coefficient, has the unit Newton-seconds / meter.
+    /// It damps the relative velocity of the particles along the edge.
+    pub damping: Scalar,
 }
 impl XpbdConstraint<2> for EdgeConstraint {
     fn entities(&self) -> [Entity; 2] {
@@ -36,13 +38,10 @@ impl XpbdConstraint<2> for EdgeConstraint {
 
     /// Solves overlap between two bodies.
     fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) {
-        let inv_mass1 = bodies[0].inverse_mass.0;
-        let inv_mass2 = bodies[1].inverse_mass.0;
-        let w = inv_mass1 + inv_mass2;
-        if w == 0.0 {
+        let inverse_masses = bodies.each_ref().map(|body| body.inverse_mass.0);
+        if inverse_masses[0] + inverse_masses[1] == 0.0 {
             return;
         }
-        let alpha = self.compliance / (dt * dt);
         let p1 = bodies[0].current_position();
         let p2 = bodies[1].current_position();
 
@@ -55,28 +54,26 @@ impl XpbdConstraint<2> for EdgeConstraint {
         } else {
             delta / distance
         };
-        let residual = -(distance - self.rest_length) / (w + alpha);
-        // println!("edge {}->{} residual: {}", self.entity1.index(), self.entity2.index(), residual);
-        bodies[0].accumulated_translation.0 -= direction * residual * inv_mass1;
-        bodies[1].accumulated_translation.0 += direction * residual * inv_mass2;
-    }
-}
-
-pub fn draw_debug_edge_constraints(
-    mut gizmos: Gizmos,
-    constraints: Query<&EdgeConstraint>,
-    transforms: Query<&GlobalTransform>,
-) {
-    for constraint in constraints.iter() {
-        let Some((p1, p2)) = [constraint.entity1, constraint.entity2]
-            .iter()
-            .filter_map(|entity| transforms.get(*entity).map(|t| t.translation()).ok())
-            .collect_tuple()
-        else {
-            return;
-        };
-
-        gizmos.line(p1, p2, Color::hex("#D95B66").unwrap());
+        let gradients = [-direction, direction];
+        let displacements = bodies
+            .each_ref()
+            .map(|body| body.current_position() - body.previous_position.0);
+        let delta_lagrange = self.compute_damped_lagrange_update(
+            0.0,
+            distance - self.rest_length,
+            &gradients,
+            &inverse_masses,
+            &displacements,
+            self.compliance,
+            self.damping,
+            dt,
+        );
+        for (body, (gradient, inverse_mass)) in bodies
+            .into_iter()
+            .zip(gradients.into_iter().zip(inverse_masses))
+        {
+            body.accumulated_translation.0 += delta_lagrange * inverse_mass * gradient;
+        }
     }
 }
 
@@ -90,6 +87,7 @@ impl EdgeConstraint {
             entity2: *entity2,
             rest_length,
             compliance: 0.1,
+            damping: 0.0,
         }
     }
 
@@ -99,6 +97,12 @@ impl EdgeConstraint {
         self
     }
 
+    /// Sets the constraint's damping coefficient (Newton-seconds / meter).
+    pub fn with_damping(mut self, damping: Scalar) -> Self {
+        self.damping = damping;
+        self
+    }
+
     pub fn with_rest_length(mut self, rest_length: Scalar) -> Self {
         self.rest_length = rest_length;
         self
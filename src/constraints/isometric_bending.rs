@@ -8,8 +8,8 @@ use bevy::{
     },
     prelude::*,
 };
-use itertools::Itertools;
 
+/// ```text
 ///              pb
 ///            /-^<\
 ///       e1/--  |  --\e4
@@ -19,8 +19,13 @@ use itertools::Itertools;
 ///       e2\--  |  --/e3
 ///            \>|-/
 ///              pa
+/// ```
 ///
 /// A compliance of 0.0 resembles a constraint with infinite stiffness, so the bodies should not have any overlap.
+///
+/// The bending energy is precomputed from the initial positions, so it is only accurate while the mesh
+/// deforms isometrically and its rest shape is flat. For cloth that stretches or shells with a curved rest shape,
+/// use a [`DihedralBendingConstraint`] instead.
 #[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[reflect(MapEntities)]
@@ -37,6 +42,8 @@ pub struct IsometricBendingConstraint {
     pub initial_bending_energy: Mat4,
     /// The constraint's compliance, the inverse of stiffness, has the unit meters / Newton.
     pub compliance: Scalar,
+    /// The constraint's damping coefficient. It damps the rate at which the bending energy changes.
+    pub damping: Scalar,
 }
 impl XpbdConstraint<4> for IsometricBendingConstraint {
     fn entities(&self) -> [Entity; 4] {
@@ -50,7 +57,6 @@ impl XpbdConstraint<4> for IsometricBendingConstraint {
 
     /// Change the particle's position to satisfy the constraint.
     fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 4], dt: Scalar) {
-        let alpha = self.compliance / (dt * dt);
         let pa = bodies[0].current_position();
         let pb = bodies[1].current_position();
         let pc = bodies[2].current_position();
@@ -67,51 +73,26 @@ impl XpbdConstraint<4> for IsometricBendingConstraint {
         }
 
         let grad = self.calculate_gradient(&pa, &pb, &pc, &pd);
-        let mut sum_normal_grad = 0.0;
-        sum_normal_grad += ima * grad[0].length_squared();
-        sum_normal_grad += imb * grad[1].length_squared();
-        sum_normal_grad += imc * grad[2].length_squared();
-        sum_normal_grad += imd * grad[3].length_squared();
-
-        if sum_normal_grad.abs() < 1e-9 {
-            return;
+        let inverse_masses = [ima, imb, imc, imd];
+        let displacements = bodies
+            .each_ref()
+            .map(|body| body.current_position() - body.previous_position.0);
+        let delta_lagrange = self.compute_damped_lagrange_update(
+            0.0,
+            energy,
+            &grad,
+            &inverse_masses,
+            &displacements,
+            self.compliance,
+            self.damping,
+            dt,
+        );
+
+        for (body, (gradient, inverse_mass)) in
+            bodies.into_iter().zip(grad.iter().zip(inverse_masses))
+        {
+            body.accumulated_translation.0 += delta_lagrange * inverse_mass * *gradient;
         }
-
-        // compute impulse-based scaling factor
-        let s = energy / sum_normal_grad;
-
-        bodies[0].accumulated_translation.0 += s * ima * grad[0] * alpha;
-        bodies[1].accumulated_translation.0 += s * imb * grad[1] * alpha;
-        bodies[2].accumulated_translation.0 += s * imc * grad[2] * alpha;
-        bodies[3].accumulated_translation.0 += s * imd * grad[3] * alpha;
-    }
-}
-
-pub fn draw_debug_isometric_bend_constraints(
-    mut gizmos: Gizmos,
-    constraints: Query<&IsometricBendingConstraint>,
-    transforms: Query<&GlobalTransform>,
-) {
-    for constraint in constraints.iter() {
-        let Some((p1, p2, p3, p4)) = [
-            constraint.entity1,
-            constraint.entity2,
-            constraint.entity3,
-            constraint.entity4,
-        ]
-        .iter()
-        .filter_map(|entity| transforms.get(*entity).map(|t| t.translation()).ok())
-        .collect_tuple() else {
-            return;
-        };
-
-        let color = Color::hex("#D98162").unwrap();
-        gizmos.line(p1, p2, color);
-        gizmos.line(p1, p3, color);
-        gizmos.line(p1, p4, color);
-        gizmos.line(p2, p3, color);
-        gizmos.line(p2, p4, color);
-        gizmos.line(p3, p4, color);
     }
 }
 
@@ -136,6 +117,7 @@ impl IsometricBendingConstraint {
                 position1, position2, position3, position4,
             ),
             compliance: 0.0,
+            damping: 0.0,
         }
     }
 
@@ -162,7 +144,7 @@ impl IsometricBendingConstraint {
         outer_product(k, k) * (3.0 / area)
     }
 
-    fn calculate_constraint_value(
+    pub(crate) fn calculate_constraint_value(
         &self,
         pa: &Vector,
         pb: &Vector,
@@ -195,6 +177,12 @@ impl IsometricBendingConstraint {
         self.compliance = compliance;
         self
     }
+
+    /// Sets the constraint's damping coefficient.
+    pub fn with_damping(mut self, damping: Scalar) -> Self {
+        self.damping = damping;
+        self
+    }
 }
 
 fn outer_product(v0: Vec4, v1: Vec4) -> Mat4 {
@@ -36,6 +36,14 @@ pub struct DistanceJoint {
     pub lagrange: Scalar,
     /// The joint's compliance, the inverse of stiffness, has the unit meters / Newton.
     pub compliance: Scalar,
+    /// The spring that makes the joint soft. If set, the `compliance` is not used.
+    pub spring: Option<JointSpring>,
+    /// The force at which the joint breaks. See [`Joint::with_break_force`].
+    pub break_force: Scalar,
+    /// The torque at which the joint breaks. See [`Joint::with_break_torque`].
+    pub break_torque: Scalar,
+    /// Whether the bodies connected by the joint can collide with each other. See [`Joint::with_collide_connected`].
+    pub collide_connected: bool,
     /// The force exerted by the joint.
     pub force: Vector,
 }
@@ -67,6 +75,10 @@ impl Joint for DistanceJoint {
             damping_angular: 0.0,
             lagrange: 0.0,
             compliance: 0.0,
+            spring: None,
+            break_force: Scalar::INFINITY,
+            break_torque: Scalar::INFINITY,
+            collide_connected: false,
             force: Vector::ZERO,
         }
     }
@@ -118,6 +130,48 @@ impl Joint for DistanceJoint {
     fn damping_angular(&self) -> Scalar {
         self.damping_angular
     }
+
+    fn with_break_force(self, force: Scalar) -> Self {
+        Self {
+            break_force: force,
+            ..self
+        }
+    }
+
+    fn with_break_torque(self, torque: Scalar) -> Self {
+        Self {
+            break_torque: torque,
+            ..self
+        }
+    }
+
+    fn break_force(&self) -> Scalar {
+        self.break_force
+    }
+
+    fn break_torque(&self) -> Scalar {
+        self.break_torque
+    }
+
+    fn with_collide_connected(self, collide_connected: bool) -> Self {
+        Self {
+            collide_connected,
+            ..self
+        }
+    }
+
+    fn collide_connected(&self) -> bool {
+        self.collide_connected
+    }
+
+    fn force_magnitude(&self) -> Scalar {
+        self.force.length()
+    }
+
+    fn torque_magnitude(&self) -> Scalar {
+        // Distance joints don't constrain the rotation of the bodies
+        0.0
+    }
 }
 
 impl DistanceJoint {
@@ -157,14 +211,27 @@ impl DistanceJoint {
         let gradients = [dir, -dir];
 
         // Compute Lagrange multiplier update, essentially the signed magnitude of the correction
-        let delta_lagrange = self.compute_lagrange_update(
-            self.lagrange,
-            distance,
-            &gradients,
-            &w,
-            self.compliance,
-            dt,
-        );
+        let delta_lagrange = match self.spring {
+            Some(spring) => spring.compute_positional_lagrange_update(
+                body1,
+                body2,
+                self.local_anchor1,
+                self.local_anchor2,
+                dir,
+                distance,
+                self.lagrange,
+                w1 + w2,
+                dt,
+            ),
+            None => self.compute_lagrange_update(
+                self.lagrange,
+                distance,
+                &gradients,
+                &w,
+                self.compliance,
+                dt,
+            ),
+        };
         self.lagrange += delta_lagrange;
 
         // Apply positional correction (method from PositionConstraint)
@@ -174,6 +241,14 @@ impl DistanceJoint {
         self.compute_force(self.lagrange, dir, dt)
     }
 
+    /// Sets the spring that makes the joint soft.
+    pub fn with_spring(self, spring: JointSpring) -> Self {
+        Self {
+            spring: Some(spring),
+            ..self
+        }
+    }
+
     /// Sets the minimum and maximum distances between the attached bodies.
     pub fn with_limits(self, min: Scalar, max: Scalar) -> Self {
         Self {
@@ -31,6 +31,14 @@ pub struct FixedJoint {
     pub align_lagrange: Scalar,
     /// The joint's compliance, the inverse of stiffness, has the unit meters / Newton.
     pub compliance: Scalar,
+    /// The spring that makes the positional part of the joint soft. If set, the `compliance` is not used for it.
+    pub spring: Option<JointSpring>,
+    /// The force at which the joint breaks. See [`Joint::with_break_force`].
+    pub break_force: Scalar,
+    /// The torque at which the joint breaks. See [`Joint::with_break_torque`].
+    pub break_torque: Scalar,
+    /// Whether the bodies connected by the joint can collide with each other. See [`Joint::with_collide_connected`].
+    pub collide_connected: bool,
     /// The force exerted by the joint.
     pub force: Vector,
     /// The torque exerted by the joint when aligning the bodies.
@@ -59,15 +67,26 @@ impl XpbdConstraint<2> for FixedJoint {
 
         // Align position of local attachment points
         let mut lagrange = self.position_lagrange;
-        self.force = self.align_position(
-            body1,
-            body2,
-            self.local_anchor1,
-            self.local_anchor2,
-            &mut lagrange,
-            compliance,
-            dt,
-        );
+        self.force = match self.spring {
+            Some(spring) => self.align_position_with_spring(
+                body1,
+                body2,
+                self.local_anchor1,
+                self.local_anchor2,
+                &mut lagrange,
+                spring,
+                dt,
+            ),
+            None => self.align_position(
+                body1,
+                body2,
+                self.local_anchor1,
+                self.local_anchor2,
+                &mut lagrange,
+                compliance,
+                dt,
+            ),
+        };
         self.position_lagrange = lagrange;
     }
 }
@@ -84,6 +103,10 @@ impl Joint for FixedJoint {
             position_lagrange: 0.0,
             align_lagrange: 0.0,
             compliance: 0.0,
+            spring: None,
+            break_force: Scalar::INFINITY,
+            break_torque: Scalar::INFINITY,
+            collide_connected: false,
             force: Vector::ZERO,
             #[cfg(feature = "2d")]
             align_torque: 0.0,
@@ -139,6 +162,47 @@ impl Joint for FixedJoint {
     fn damping_angular(&self) -> Scalar {
         self.damping_angular
     }
+
+    fn with_break_force(self, force: Scalar) -> Self {
+        Self {
+            break_force: force,
+            ..self
+        }
+    }
+
+    fn with_break_torque(self, torque: Scalar) -> Self {
+        Self {
+            break_torque: torque,
+            ..self
+        }
+    }
+
+    fn break_force(&self) -> Scalar {
+        self.break_force
+    }
+
+    fn break_torque(&self) -> Scalar {
+        self.break_torque
+    }
+
+    fn with_collide_connected(self, collide_connected: bool) -> Self {
+        Self {
+            collide_connected,
+            ..self
+        }
+    }
+
+    fn collide_connected(&self) -> bool {
+        self.collide_connected
+    }
+
+    fn force_magnitude(&self) -> Scalar {
+        self.force.length()
+    }
+
+    fn torque_magnitude(&self) -> Scalar {
+        torque_length(self.align_torque)
+    }
 }
 
 impl FixedJoint {
@@ -151,6 +215,14 @@ impl FixedJoint {
     fn get_delta_q(&self, rot1: &Rotation, rot2: &Rotation) -> Vector {
         2.0 * (rot1.0 * rot2.inverse().0).xyz()
     }
+
+    /// Sets the spring that makes the positional part of the joint soft.
+    pub fn with_spring(self, spring: JointSpring) -> Self {
+        Self {
+            spring: Some(spring),
+            ..self
+        }
+    }
 }
 
 impl PositionConstraint for FixedJoint {}
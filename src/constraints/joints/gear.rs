@@ -0,0 +1,268 @@
+//! [`GearJoint`] component.
+
+use crate::prelude::*;
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+/// A gear joint couples the rotation of the attached bodies around their axes with a given `ratio`,
+/// like two meshing gears.
+///
+/// When the first body rotates by an angle `θ` around its axis, the second body rotates by `-ratio * θ`
+/// around its own axis. For two meshing gears, the ratio is the radius (or number of teeth) of the first gear
+/// divided by the radius of the second gear. A negative ratio makes the bodies rotate in the same direction,
+/// like gears connected by a belt.
+///
+/// Gear joints only couple the rotation of the bodies, so they are usually used together with
+/// [revolute joints](RevoluteJoint) that attach the gears to a frame. The coupling is applied to the rotation
+/// of the bodies during each substep, so the joint doesn't correct drift that has already happened.
+///
+/// As gear joints only exert torques, they can only be broken with a [break torque](Joint::with_break_torque).
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(MapEntities)]
+pub struct GearJoint {
+    /// First entity constrained by the joint.
+    pub entity1: Entity,
+    /// Second entity constrained by the joint.
+    pub entity2: Entity,
+    /// Attachment point on the first body.
+    pub local_anchor1: Vector,
+    /// Attachment point on the second body.
+    pub local_anchor2: Vector,
+    /// The axis of rotation of the first body in its local space.
+    #[cfg(feature = "3d")]
+    pub local_axis1: Vector,
+    /// The axis of rotation of the second body in its local space.
+    #[cfg(feature = "3d")]
+    pub local_axis2: Vector,
+    /// The gear ratio. The second body rotates by `-ratio` times the rotation of the first body.
+    pub ratio: Scalar,
+    /// Linear damping applied by the joint.
+    pub damping_linear: Scalar,
+    /// Angular damping applied by the joint.
+    pub damping_angular: Scalar,
+    /// Lagrange multiplier for the angular correction.
+    pub lagrange: Scalar,
+    /// The joint's compliance, the inverse of stiffness, has the unit meters / Newton.
+    pub compliance: Scalar,
+    /// The force at which the joint breaks. See [`Joint::with_break_force`].
+    pub break_force: Scalar,
+    /// The torque at which the joint breaks. See [`Joint::with_break_torque`].
+    pub break_torque: Scalar,
+    /// Whether the bodies connected by the joint can collide with each other. See [`Joint::with_collide_connected`].
+    pub collide_connected: bool,
+    /// The torque exerted by the joint on the second body.
+    pub torque: Torque,
+}
+
+impl XpbdConstraint<2> for GearJoint {
+    fn entities(&self) -> [Entity; 2] {
+        [self.entity1, self.entity2]
+    }
+
+    fn clear_lagrange_multipliers(&mut self) {
+        self.lagrange = 0.0;
+    }
+
+    fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) {
+        self.torque = self.couple_rotations(bodies, dt);
+    }
+}
+
+impl Joint for GearJoint {
+    fn new(entity1: Entity, entity2: Entity) -> Self {
+        Self {
+            entity1,
+            entity2,
+            local_anchor1: Vector::ZERO,
+            local_anchor2: Vector::ZERO,
+            #[cfg(feature = "3d")]
+            local_axis1: Vector::Z,
+            #[cfg(feature = "3d")]
+            local_axis2: Vector::Z,
+            ratio: 1.0,
+            damping_linear: 0.0,
+            damping_angular: 0.0,
+            lagrange: 0.0,
+            compliance: 0.0,
+            break_force: Scalar::INFINITY,
+            break_torque: Scalar::INFINITY,
+            collide_connected: false,
+            #[cfg(feature = "2d")]
+            torque: 0.0,
+            #[cfg(feature = "3d")]
+            torque: Vector::ZERO,
+        }
+    }
+
+    fn with_compliance(self, compliance: Scalar) -> Self {
+        Self { compliance, ..self }
+    }
+
+    fn with_local_anchor_1(self, anchor: Vector) -> Self {
+        Self {
+            local_anchor1: anchor,
+            ..self
+        }
+    }
+
+    fn with_local_anchor_2(self, anchor: Vector) -> Self {
+        Self {
+            local_anchor2: anchor,
+            ..self
+        }
+    }
+
+    fn with_linear_velocity_damping(self, damping: Scalar) -> Self {
+        Self {
+            damping_linear: damping,
+            ..self
+        }
+    }
+
+    fn with_angular_velocity_damping(self, damping: Scalar) -> Self {
+        Self {
+            damping_angular: damping,
+            ..self
+        }
+    }
+
+    fn local_anchor_1(&self) -> Vector {
+        self.local_anchor1
+    }
+
+    fn local_anchor_2(&self) -> Vector {
+        self.local_anchor2
+    }
+
+    fn damping_linear(&self) -> Scalar {
+        self.damping_linear
+    }
+
+    fn damping_angular(&self) -> Scalar {
+        self.damping_angular
+    }
+
+    fn with_break_force(self, force: Scalar) -> Self {
+        Self {
+            break_force: force,
+            ..self
+        }
+    }
+
+    fn with_break_torque(self, torque: Scalar) -> Self {
+        Self {
+            break_torque: torque,
+            ..self
+        }
+    }
+
+    fn break_force(&self) -> Scalar {
+        self.break_force
+    }
+
+    fn break_torque(&self) -> Scalar {
+        self.break_torque
+    }
+
+    fn with_collide_connected(self, collide_connected: bool) -> Self {
+        Self {
+            collide_connected,
+            ..self
+        }
+    }
+
+    fn collide_connected(&self) -> bool {
+        self.collide_connected
+    }
+
+    fn force_magnitude(&self) -> Scalar {
+        // Gear joints only couple the rotation of the bodies
+        0.0
+    }
+
+    fn torque_magnitude(&self) -> Scalar {
+        // The torque exerted on the first body is `ratio` times the torque on the second body
+        torque_length(self.torque) * self.ratio.abs().max(1.0)
+    }
+}
+
+impl GearJoint {
+    /// Couples the rotation of the bodies during the current substep.
+    ///
+    /// Returns the torque exerted on the second body.
+    fn couple_rotations(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) -> Torque {
+        let [body1, body2] = bodies;
+
+        #[cfg(feature = "2d")]
+        let (axis1, axis2) = (Vector3::Z, Vector3::Z);
+        #[cfg(feature = "3d")]
+        let (axis1, axis2) = (
+            body1.rotation.rotate(self.local_axis1),
+            body2.rotation.rotate(self.local_axis2),
+        );
+
+        // The constraint is satisfied when ratio * Δθ1 + Δθ2 = 0
+        let angle1 = rotation_during_substep(body1, axis1);
+        let angle2 = rotation_during_substep(body2, axis2);
+        let c = self.ratio * angle1 + angle2;
+
+        let w1 = AngularConstraint::compute_generalized_inverse_mass(self, body1, axis1);
+        let w2 = AngularConstraint::compute_generalized_inverse_mass(self, body2, axis2);
+
+        // The gradients have a length of `ratio` and 1
+        let delta_lagrange = self.compute_lagrange_update(
+            self.lagrange,
+            c,
+            &[Vector::X * self.ratio, Vector::X],
+            &[w1, w2],
+            self.compliance,
+            dt,
+        );
+        self.lagrange += delta_lagrange;
+
+        apply_angular_impulse(body1, delta_lagrange * self.ratio, axis1);
+        apply_angular_impulse(body2, delta_lagrange, axis2);
+
+        self.compute_torque(self.lagrange, axis2, dt)
+    }
+
+    /// Sets the axis of rotation of the first body in its local space.
+    #[cfg(feature = "3d")]
+    pub fn with_local_axis_1(self, axis: Vector) -> Self {
+        Self {
+            local_axis1: axis,
+            ..self
+        }
+    }
+
+    /// Sets the axis of rotation of the second body in its local space.
+    #[cfg(feature = "3d")]
+    pub fn with_local_axis_2(self, axis: Vector) -> Self {
+        Self {
+            local_axis2: axis,
+            ..self
+        }
+    }
+
+    /// Sets the gear ratio. The second body rotates by `-ratio` times the rotation of the first body.
+    pub fn with_ratio(self, ratio: Scalar) -> Self {
+        Self { ratio, ..self }
+    }
+}
+
+impl PositionConstraint for GearJoint {}
+
+impl AngularConstraint for GearJoint {}
+
+impl MapEntities for GearJoint {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        self.entity1 = entity_mapper.get_or_reserve(self.entity1);
+        self.entity2 = entity_mapper.get_or_reserve(self.entity2);
+    }
+}
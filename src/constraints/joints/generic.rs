@@ -0,0 +1,725 @@
+//! [`GenericJoint`] component.
+
+use crate::prelude::*;
+use bevy::{
+    ecs::entity::{EntityMapper, MapEntities},
+    prelude::*,
+};
+
+/// The number of linear axes of a [`GenericJoint`].
+#[cfg(feature = "2d")]
+pub const LINEAR_AXES: usize = 2;
+/// The number of linear axes of a [`GenericJoint`].
+#[cfg(feature = "3d")]
+pub const LINEAR_AXES: usize = 3;
+
+/// The number of angular axes of a [`GenericJoint`].
+#[cfg(feature = "2d")]
+pub const ANGULAR_AXES: usize = 1;
+/// The number of angular axes of a [`GenericJoint`].
+#[cfg(feature = "3d")]
+pub const ANGULAR_AXES: usize = 3;
+
+/// A generic joint where the motion along each linear and angular axis can be configured separately.
+/// It is also known as a D6 joint.
+///
+/// Each axis of the joint can be [locked](AxisMotion::Locked), [free](AxisMotion::Free) or
+/// [limited](AxisMotion::Limited), and each axis can be driven by a [`JointMotor`].
+/// By default, all axes are locked, which makes the joint behave like a [`FixedJoint`].
+///
+/// The axes are defined by joint frames on both bodies. The frame of each body is given by its local anchor
+/// and its local basis, which is the rotation of the frame relative to the body. The linear axes are the axes
+/// of the frame on the first body, and the angular axes are measured as the rotation of the second frame
+/// relative to the first frame.
+///
+/// In 3D, the axes are indexed as `0` for X, `1` for Y and `2` for Z. In 2D, there are two linear axes,
+/// X and Y, and one angular axis that is indexed as `0`.
+///
+/// Angular axes measure the components of the relative rotation vector, so limits on multiple angular axes
+/// are most accurate when the angles are small or the rotation is mostly around a single axis.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+#[cfg_attr(feature = "2d", doc = "use bevy_xpbd_2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use bevy_xpbd_3d::prelude::*;")]
+///
+/// fn setup(mut commands: Commands) {
+///     let entity1 = commands.spawn(RigidBody::Static).id();
+///     let entity2 = commands.spawn(RigidBody::Dynamic).id();
+///
+///     // A slider along the X axis that can move between -1 and 1 and is driven towards 0.5,
+///     // and that can rotate freely around the Z axis
+///     commands.spawn(
+///         GenericJoint::new(entity1, entity2)
+///             .with_linear_motion(0, AxisMotion::limited(-1.0, 1.0))
+#[cfg_attr(
+    feature = "2d",
+    doc = "            .with_angular_motion(0, AxisMotion::Free)"
+)]
+#[cfg_attr(
+    feature = "3d",
+    doc = "            .with_angular_motion(2, AxisMotion::Free)"
+)]
+///             .with_linear_drive(0, JointMotor::position(0.5).with_compliance(0.01)),
+///     );
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericJoint {
+    /// First entity constrained by the joint.
+    pub entity1: Entity,
+    /// Second entity constrained by the joint.
+    pub entity2: Entity,
+    /// Attachment point on the first body.
+    pub local_anchor1: Vector,
+    /// Attachment point on the second body.
+    pub local_anchor2: Vector,
+    /// The rotation of the joint frame relative to the first body.
+    pub local_basis1: Rotation,
+    /// The rotation of the joint frame relative to the second body.
+    pub local_basis2: Rotation,
+    /// The motion allowed along each linear axis of the joint frame.
+    pub linear_motions: [AxisMotion; LINEAR_AXES],
+    /// The motion allowed around each angular axis of the joint frame.
+    pub angular_motions: [AxisMotion; ANGULAR_AXES],
+    /// The motors that drive the translation along each linear axis.
+    pub linear_drives: [Option<JointMotor>; LINEAR_AXES],
+    /// The motors that drive the rotation around each angular axis.
+    pub angular_drives: [Option<JointMotor>; ANGULAR_AXES],
+    /// The drive that rotates the second joint frame towards a target rotation relative to the first joint frame.
+    pub orientation_drive: Option<OrientationDrive>,
+    /// Linear damping applied by the joint.
+    pub damping_linear: Scalar,
+    /// Angular damping applied by the joint.
+    pub damping_angular: Scalar,
+    /// Lagrange multipliers for the positional corrections along each linear axis.
+    pub linear_lagrange: [Scalar; LINEAR_AXES],
+    /// Lagrange multipliers for the angular corrections around each angular axis.
+    pub angular_lagrange: [Scalar; ANGULAR_AXES],
+    /// Lagrange multipliers for the positional corrections caused by the linear drives.
+    pub linear_drive_lagrange: [Scalar; LINEAR_AXES],
+    /// Lagrange multipliers for the angular corrections caused by the angular drives.
+    pub angular_drive_lagrange: [Scalar; ANGULAR_AXES],
+    /// Lagrange multiplier for the angular correction caused by the orientation drive.
+    pub orientation_drive_lagrange: Scalar,
+    /// The compliance of the locked axes, the inverse of stiffness, has the unit meters / Newton.
+    pub compliance: Scalar,
+    /// The force at which the joint breaks. See [`Joint::with_break_force`].
+    pub break_force: Scalar,
+    /// The torque at which the joint breaks. See [`Joint::with_break_torque`].
+    pub break_torque: Scalar,
+    /// Whether the bodies connected by the joint can collide with each other. See [`Joint::with_collide_connected`].
+    pub collide_connected: bool,
+    /// The force exerted by the joint when locking and limiting the linear axes.
+    pub force: Vector,
+    /// The torque exerted by the joint when locking and limiting the angular axes.
+    pub torque: Torque,
+    /// The force exerted by the linear drives.
+    pub drive_force: Vector,
+    /// The torque exerted by the angular drives.
+    pub drive_torque: Torque,
+}
+
+/// The motion allowed along or around an axis of a [`GenericJoint`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum AxisMotion {
+    /// No motion is allowed along the axis.
+    #[default]
+    Locked,
+    /// The motion along the axis is unrestricted.
+    Free,
+    /// The motion along the axis is limited between `min` and `max`.
+    Limited {
+        /// The minimum translation or angle.
+        min: Scalar,
+        /// The maximum translation or angle.
+        max: Scalar,
+        /// The compliance of the limit, the inverse of stiffness.
+        compliance: Scalar,
+    },
+}
+
+impl AxisMotion {
+    /// Creates a rigid limit between `min` and `max`.
+    pub fn limited(min: Scalar, max: Scalar) -> Self {
+        Self::Limited {
+            min,
+            max,
+            compliance: 0.0,
+        }
+    }
+
+    /// Returns the constraint error for the given translation or angle along the axis,
+    /// or `None` if the value is allowed.
+    pub fn error(&self, value: Scalar) -> Option<Scalar> {
+        match *self {
+            Self::Locked => Some(value),
+            Self::Free => None,
+            Self::Limited { min, max, .. } => {
+                if value < min {
+                    Some(value - min)
+                } else if value > max {
+                    Some(value - max)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Returns the compliance used for the axis, given the compliance of the joint's locked axes.
+    fn compliance(&self, joint_compliance: Scalar) -> Scalar {
+        match *self {
+            Self::Limited { compliance, .. } => compliance,
+            _ => joint_compliance,
+        }
+    }
+}
+
+impl XpbdConstraint<2> for GenericJoint {
+    fn entities(&self) -> [Entity; 2] {
+        [self.entity1, self.entity2]
+    }
+
+    fn clear_lagrange_multipliers(&mut self) {
+        self.linear_lagrange = [0.0; LINEAR_AXES];
+        self.angular_lagrange = [0.0; ANGULAR_AXES];
+        self.linear_drive_lagrange = [0.0; LINEAR_AXES];
+        self.angular_drive_lagrange = [0.0; ANGULAR_AXES];
+        self.orientation_drive_lagrange = 0.0;
+    }
+
+    fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) {
+        let [body1, body2] = bodies;
+
+        // Drive the relative rotation of the bodies towards the target before the axes are limited
+        let orientation_drive_torque = self.drive_orientation(body1, body2, dt);
+
+        // Constrain the relative rotation of the bodies
+        let (torque, drive_torque) = self.constrain_angular_axes(body1, body2, dt);
+        self.torque = torque;
+        self.drive_torque = drive_torque + orientation_drive_torque;
+
+        // Constrain the relative translation of the bodies
+        let (force, drive_force) = self.constrain_linear_axes(body1, body2, dt);
+        self.force = force;
+        self.drive_force = drive_force;
+    }
+}
+
+impl Joint for GenericJoint {
+    fn new(entity1: Entity, entity2: Entity) -> Self {
+        Self {
+            entity1,
+            entity2,
+            local_anchor1: Vector::ZERO,
+            local_anchor2: Vector::ZERO,
+            local_basis1: Rotation::default(),
+            local_basis2: Rotation::default(),
+            linear_motions: [AxisMotion::Locked; LINEAR_AXES],
+            angular_motions: [AxisMotion::Locked; ANGULAR_AXES],
+            linear_drives: [None; LINEAR_AXES],
+            angular_drives: [None; ANGULAR_AXES],
+            orientation_drive: None,
+            damping_linear: 1.0,
+            damping_angular: 1.0,
+            linear_lagrange: [0.0; LINEAR_AXES],
+            angular_lagrange: [0.0; ANGULAR_AXES],
+            linear_drive_lagrange: [0.0; LINEAR_AXES],
+            angular_drive_lagrange: [0.0; ANGULAR_AXES],
+            orientation_drive_lagrange: 0.0,
+            compliance: 0.0,
+            break_force: Scalar::INFINITY,
+            break_torque: Scalar::INFINITY,
+            collide_connected: false,
+            force: Vector::ZERO,
+            #[cfg(feature = "2d")]
+            torque: 0.0,
+            #[cfg(feature = "3d")]
+            torque: Vector::ZERO,
+            drive_force: Vector::ZERO,
+            #[cfg(feature = "2d")]
+            drive_torque: 0.0,
+            #[cfg(feature = "3d")]
+            drive_torque: Vector::ZERO,
+        }
+    }
+
+    fn with_compliance(self, compliance: Scalar) -> Self {
+        Self { compliance, ..self }
+    }
+
+    fn with_local_anchor_1(self, anchor: Vector) -> Self {
+        Self {
+            local_anchor1: anchor,
+            ..self
+        }
+    }
+
+    fn with_local_anchor_2(self, anchor: Vector) -> Self {
+        Self {
+            local_anchor2: anchor,
+            ..self
+        }
+    }
+
+    fn with_linear_velocity_damping(self, damping: Scalar) -> Self {
+        Self {
+            damping_linear: damping,
+            ..self
+        }
+    }
+
+    fn with_angular_velocity_damping(self, damping: Scalar) -> Self {
+        Self {
+            damping_angular: damping,
+            ..self
+        }
+    }
+
+    fn local_anchor_1(&self) -> Vector {
+        self.local_anchor1
+    }
+
+    fn local_anchor_2(&self) -> Vector {
+        self.local_anchor2
+    }
+
+    fn damping_linear(&self) -> Scalar {
+        self.damping_linear
+    }
+
+    fn damping_angular(&self) -> Scalar {
+        self.damping_angular
+    }
+
+    fn with_break_force(self, force: Scalar) -> Self {
+        Self {
+            break_force: force,
+            ..self
+        }
+    }
+
+    fn with_break_torque(self, torque: Scalar) -> Self {
+        Self {
+            break_torque: torque,
+            ..self
+        }
+    }
+
+    fn break_force(&self) -> Scalar {
+        self.break_force
+    }
+
+    fn break_torque(&self) -> Scalar {
+        self.break_torque
+    }
+
+    fn with_collide_connected(self, collide_connected: bool) -> Self {
+        Self {
+            collide_connected,
+            ..self
+        }
+    }
+
+    fn collide_connected(&self) -> bool {
+        self.collide_connected
+    }
+
+    fn force_magnitude(&self) -> Scalar {
+        (self.force + self.drive_force).length()
+    }
+
+    fn torque_magnitude(&self) -> Scalar {
+        torque_length(self.torque + self.drive_torque)
+    }
+}
+
+impl GenericJoint {
+    /// Sets the rotation of the joint frame relative to the first body.
+    pub fn with_local_basis_1(self, basis: Rotation) -> Self {
+        Self {
+            local_basis1: basis,
+            ..self
+        }
+    }
+
+    /// Sets the rotation of the joint frame relative to the second body.
+    pub fn with_local_basis_2(self, basis: Rotation) -> Self {
+        Self {
+            local_basis2: basis,
+            ..self
+        }
+    }
+
+    /// Sets the motion allowed along the linear axis with the given index.
+    pub fn with_linear_motion(mut self, axis: usize, motion: AxisMotion) -> Self {
+        self.linear_motions[axis] = motion;
+        self
+    }
+
+    /// Sets the motion allowed around the angular axis with the given index.
+    pub fn with_angular_motion(mut self, axis: usize, motion: AxisMotion) -> Self {
+        self.angular_motions[axis] = motion;
+        self
+    }
+
+    /// Sets the motor that drives the translation along the linear axis with the given index.
+    ///
+    /// The axis should be free or limited, as locked axes can't be driven.
+    pub fn with_linear_drive(mut self, axis: usize, motor: JointMotor) -> Self {
+        self.linear_drives[axis] = Some(motor);
+        self
+    }
+
+    /// Sets the motor that drives the rotation around the angular axis with the given index.
+    ///
+    /// The axis should be free or limited, as locked axes can't be driven.
+    pub fn with_angular_drive(mut self, axis: usize, motor: JointMotor) -> Self {
+        self.angular_drives[axis] = Some(motor);
+        self
+    }
+
+    /// Sets the drive that rotates the second joint frame towards a target rotation
+    /// relative to the first joint frame.
+    pub fn with_orientation_drive(self, drive: OrientationDrive) -> Self {
+        Self {
+            orientation_drive: Some(drive),
+            ..self
+        }
+    }
+
+    /// Returns `true` if any of the axes of the joint is driven by a [`JointMotor`]
+    /// or the joint has an [`OrientationDrive`].
+    pub fn has_drives(&self) -> bool {
+        self.orientation_drive.is_some()
+            || self
+                .linear_drives
+                .iter()
+                .chain(&self.angular_drives)
+                .any(Option::is_some)
+    }
+
+    /// Returns the world-space direction of the linear axis with the given index for the given rotation
+    /// of the first body.
+    fn linear_axis(&self, rot1: &Rotation, axis: usize) -> Vector {
+        #[cfg(feature = "2d")]
+        let local_axis = [Vector::X, Vector::Y][axis];
+        #[cfg(feature = "3d")]
+        let local_axis = [Vector::X, Vector::Y, Vector::Z][axis];
+
+        rot1.rotate(self.local_basis1.rotate(local_axis))
+    }
+
+    /// Returns the translation of the anchor of the second body relative to the anchor of the first body
+    /// along the linear axis with the given index.
+    fn linear_offset(
+        &self,
+        pos1: Vector,
+        rot1: &Rotation,
+        pos2: Vector,
+        rot2: &Rotation,
+        axis: usize,
+    ) -> Scalar {
+        let offset =
+            pos2 + rot2.rotate(self.local_anchor2) - pos1 - rot1.rotate(self.local_anchor1);
+        offset.dot(self.linear_axis(rot1, axis))
+    }
+
+    /// Returns the world-space direction of the angular axis with the given index
+    /// for the given rotation of the first body.
+    #[cfg(feature = "2d")]
+    fn angular_axis(&self, _rot1: &Rotation, _axis: usize) -> Vector3 {
+        Vector3::Z
+    }
+
+    /// Returns the world-space direction of the angular axis with the given index
+    /// for the given rotation of the first body.
+    #[cfg(feature = "3d")]
+    fn angular_axis(&self, rot1: &Rotation, axis: usize) -> Vector {
+        rot1.rotate(
+            self.local_basis1
+                .rotate([Vector::X, Vector::Y, Vector::Z][axis]),
+        )
+    }
+
+    /// Returns the angle of the second joint frame relative to the first joint frame around the angular axis
+    /// with the given index.
+    #[cfg(feature = "2d")]
+    fn relative_angle(&self, rot1: &Rotation, rot2: &Rotation, _axis: usize) -> Scalar {
+        (*rot2 + self.local_basis2 - (*rot1 + self.local_basis1)).as_radians()
+    }
+
+    /// Returns the angle of the second joint frame relative to the first joint frame around the angular axis
+    /// with the given index.
+    ///
+    /// The angle is the component of the relative rotation vector along the axis.
+    #[cfg(feature = "3d")]
+    fn relative_angle(&self, rot1: &Rotation, rot2: &Rotation, axis: usize) -> Scalar {
+        let frame1 = rot1.0 * self.local_basis1.0;
+        let frame2 = rot2.0 * self.local_basis2.0;
+        let mut relative = frame1.inverse() * frame2;
+
+        // Use the shortest rotation
+        if relative.w < 0.0 {
+            relative = -relative;
+        }
+
+        let imaginary = relative.xyz();
+        let sin_half_angle = imaginary.length();
+        let rotation_vector = if sin_half_angle <= Scalar::EPSILON {
+            2.0 * imaginary
+        } else {
+            imaginary / sin_half_angle * 2.0 * sin_half_angle.atan2(relative.w)
+        };
+
+        rotation_vector[axis]
+    }
+
+    /// Returns the rotation vector that rotates the second joint frame to the target of the orientation drive.
+    #[cfg(feature = "2d")]
+    fn orientation_error(&self, rot1: &Rotation, rot2: &Rotation, target: Rotation) -> Vector3 {
+        let target_rot2 = *rot1 + self.local_basis1 + target - self.local_basis2;
+        (*rot2 - target_rot2).as_radians() * Vector3::Z
+    }
+
+    /// Returns the rotation vector that rotates the second joint frame to the target of the orientation drive.
+    #[cfg(feature = "3d")]
+    fn orientation_error(&self, rot1: &Rotation, rot2: &Rotation, target: Rotation) -> Vector {
+        // The rotation of the second body at which the drive reaches its target
+        let target_rot2 = rot1.0 * self.local_basis1.0 * target.0 * self.local_basis2.inverse().0;
+        let mut error = target_rot2 * rot2.inverse().0;
+
+        // Use the shortest rotation
+        if error.w < 0.0 {
+            error = -error;
+        }
+
+        2.0 * error.xyz()
+    }
+
+    /// Drives the relative rotation of the joint frames towards the target of the [`OrientationDrive`].
+    ///
+    /// Returns the torque exerted by the drive.
+    fn drive_orientation(
+        &mut self,
+        body1: &mut RigidBodyQueryItem,
+        body2: &mut RigidBodyQueryItem,
+        dt: Scalar,
+    ) -> Torque {
+        let Some(drive) = self.orientation_drive else {
+            return Torque::ZERO;
+        };
+
+        let error = self.orientation_error(&body1.rotation, &body2.rotation, drive.target);
+        let angle = error.length();
+
+        if angle <= Scalar::EPSILON {
+            return Torque::ZERO;
+        }
+
+        let axis = error / angle;
+        let previous_error = self.orientation_error(
+            &body1.previous_rotation.0,
+            &body2.previous_rotation.0,
+            drive.target,
+        );
+
+        let w1 = AngularConstraint::compute_generalized_inverse_mass(self, body1, axis);
+        let w2 = AngularConstraint::compute_generalized_inverse_mass(self, body2, axis);
+
+        let delta_lagrange = drive.compute_lagrange_update(
+            self.orientation_drive_lagrange,
+            angle,
+            previous_error.dot(axis),
+            w1 + w2,
+            dt,
+        );
+        self.orientation_drive_lagrange += delta_lagrange;
+        self.apply_angular_correction(body1, body2, delta_lagrange, axis);
+
+        self.compute_torque(self.orientation_drive_lagrange, axis, dt)
+    }
+
+    /// Locks, limits and drives the angular axes of the joint.
+    ///
+    /// Returns the torques exerted by the limits and the drives.
+    fn constrain_angular_axes(
+        &mut self,
+        body1: &mut RigidBodyQueryItem,
+        body2: &mut RigidBodyQueryItem,
+        dt: Scalar,
+    ) -> (Torque, Torque) {
+        let mut torque = Torque::ZERO;
+        let mut drive_torque = Torque::ZERO;
+
+        for i in 0..ANGULAR_AXES {
+            let motion = self.angular_motions[i];
+            let drive = self.angular_drives[i];
+
+            if motion == AxisMotion::Free && drive.is_none() {
+                continue;
+            }
+
+            let axis = self.angular_axis(&body1.rotation, i);
+            let w1 = AngularConstraint::compute_generalized_inverse_mass(self, body1, axis);
+            let w2 = AngularConstraint::compute_generalized_inverse_mass(self, body2, axis);
+
+            // Drive the axis before the limits so that the limits take priority
+            if let Some(motor) = drive {
+                let angle = self.relative_angle(&body1.rotation, &body2.rotation, i);
+                let previous_angle =
+                    self.relative_angle(&body1.previous_rotation.0, &body2.previous_rotation.0, i);
+                let delta_lagrange = motor.compute_lagrange_update(
+                    self.angular_drive_lagrange[i],
+                    angle,
+                    previous_angle,
+                    w1 + w2,
+                    true,
+                    dt,
+                );
+                self.angular_drive_lagrange[i] += delta_lagrange;
+                self.apply_angular_correction(body1, body2, delta_lagrange, axis);
+                drive_torque += self.compute_torque(self.angular_drive_lagrange[i], axis, dt);
+            }
+
+            // Lock or limit the axis
+            let angle = self.relative_angle(&body1.rotation, &body2.rotation, i);
+            if let Some(c) = motion.error(angle) {
+                #[cfg(feature = "2d")]
+                let gradients = [Vector::Y, Vector::NEG_Y];
+                #[cfg(feature = "3d")]
+                let gradients = [axis, -axis];
+
+                let delta_lagrange = self.compute_lagrange_update(
+                    self.angular_lagrange[i],
+                    c,
+                    &gradients,
+                    &[w1, w2],
+                    motion.compliance(self.compliance),
+                    dt,
+                );
+                self.angular_lagrange[i] += delta_lagrange;
+                self.apply_angular_correction(body1, body2, delta_lagrange, axis);
+                torque += self.compute_torque(self.angular_lagrange[i], axis, dt);
+            }
+        }
+
+        (torque, drive_torque)
+    }
+
+    /// Locks, limits and drives the linear axes of the joint.
+    ///
+    /// Returns the forces exerted by the limits and the drives.
+    fn constrain_linear_axes(
+        &mut self,
+        body1: &mut RigidBodyQueryItem,
+        body2: &mut RigidBodyQueryItem,
+        dt: Scalar,
+    ) -> (Vector, Vector) {
+        let mut force = Vector::ZERO;
+        let mut drive_force = Vector::ZERO;
+
+        for i in 0..LINEAR_AXES {
+            let motion = self.linear_motions[i];
+            let drive = self.linear_drives[i];
+
+            if motion == AxisMotion::Free && drive.is_none() {
+                continue;
+            }
+
+            let world_r1 = body1.rotation.rotate(self.local_anchor1);
+            let world_r2 = body2.rotation.rotate(self.local_anchor2);
+
+            // The correction is applied in the opposite direction of the axis,
+            // so a positive Lagrange multiplier update increases the translation along the axis.
+            let dir = -self.linear_axis(&body1.rotation, i);
+            let w1 =
+                PositionConstraint::compute_generalized_inverse_mass(self, body1, world_r1, dir);
+            let w2 =
+                PositionConstraint::compute_generalized_inverse_mass(self, body2, world_r2, dir);
+
+            // Drive the axis before the limits so that the limits take priority
+            if let Some(motor) = drive {
+                let offset = self.linear_offset(
+                    body1.current_position(),
+                    &body1.rotation,
+                    body2.current_position(),
+                    &body2.rotation,
+                    i,
+                );
+                let previous_offset = self.linear_offset(
+                    body1.previous_position.0,
+                    &body1.previous_rotation.0,
+                    body2.previous_position.0,
+                    &body2.previous_rotation.0,
+                    i,
+                );
+                let delta_lagrange = motor.compute_lagrange_update(
+                    self.linear_drive_lagrange[i],
+                    offset,
+                    previous_offset,
+                    w1 + w2,
+                    false,
+                    dt,
+                );
+                self.linear_drive_lagrange[i] += delta_lagrange;
+                self.apply_positional_correction(
+                    body1,
+                    body2,
+                    delta_lagrange,
+                    dir,
+                    world_r1,
+                    world_r2,
+                );
+                drive_force += self.compute_force(self.linear_drive_lagrange[i], dir, dt);
+            }
+
+            // Lock or limit the axis
+            let offset = self.linear_offset(
+                body1.current_position(),
+                &body1.rotation,
+                body2.current_position(),
+                &body2.rotation,
+                i,
+            );
+            if let Some(c) = motion.error(offset) {
+                let delta_lagrange = self.compute_lagrange_update(
+                    self.linear_lagrange[i],
+                    c,
+                    &[dir, -dir],
+                    &[w1, w2],
+                    motion.compliance(self.compliance),
+                    dt,
+                );
+                self.linear_lagrange[i] += delta_lagrange;
+                self.apply_positional_correction(
+                    body1,
+                    body2,
+                    delta_lagrange,
+                    dir,
+                    world_r1,
+                    world_r2,
+                );
+                force += self.compute_force(self.linear_lagrange[i], dir, dt);
+            }
+        }
+
+        (force, drive_force)
+    }
+}
+
+impl PositionConstraint for GenericJoint {}
+
+impl AngularConstraint for GenericJoint {}
+
+impl MapEntities for GenericJoint {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        self.entity1 = entity_mapper.get_or_reserve(self.entity1);
+        self.entity2 = entity_mapper.get_or_reserve(self.entity2);
+    }
+}
@@ -18,6 +18,7 @@
 //! | [`PrismaticJoint`] | 1 Translation             | 1 Translation               |
 //! | [`RevoluteJoint`]  | 1 Rotation                | 1 Rotation                  |
 //! | [`SphericalJoint`] | 1 Rotation                | 3 Rotations                 |
+//! | [`GenericJoint`]   | Configurable              | Configurable                |
 //!
 //! ## Using joints
 //!
@@ -39,12 +40,44 @@
 //! }
 //! ```
 //!
+//! ### Attaching to the world
+//!
+//! Joints can also attach an entity to a fixed point in the world without a separate static body.
+//! Use `new_to_world` with the [`WorldBodyEntity`] resource, the entity and a world-space anchor point.
+//! The world is a static body with infinite mass located at the origin.
+//!
+//! ```
+//! use bevy::prelude::*;
+#![cfg_attr(feature = "2d", doc = "use bevy_xpbd_2d::prelude::*;")]
+#![cfg_attr(feature = "3d", doc = "use bevy_xpbd_3d::prelude::*;")]
+//!
+//! # #[cfg(feature = "f32")]
+//! fn setup(mut commands: Commands, world_body: Res<WorldBodyEntity>) {
+//!     let entity = commands.spawn(RigidBody::Dynamic).id();
+//!
+//!     // Hang the body from a point above the origin
+#![cfg_attr(
+    feature = "2d",
+    doc = "    commands.spawn(RevoluteJoint::new_to_world(&world_body, entity, Vec2::Y * 5.0));"
+)]
+#![cfg_attr(
+    feature = "3d",
+    doc = "    commands.spawn(SphericalJoint::new_to_world(&world_body, entity, Vec3::Y * 5.0));"
+)]
+//! }
+//! ```
+//!
 //! ### Stiffness
 //!
 //! You can control the stiffness of a joint with the `with_compliance` method.
 //! *Compliance* refers to the inverse of stiffness, so using a compliance of 0 corresponds to
 //! infinite stiffness.
 //!
+//! How stiff a given compliance feels depends on the masses of the attached bodies. To configure a joint
+//! like a spring instead, you can use `with_spring` with a [`JointSpring`] that has a natural frequency
+//! and a damping ratio. This is supported by [distance joints](DistanceJoint) and the positional part of
+//! [fixed](FixedJoint), [revolute](RevoluteJoint), [spherical](SphericalJoint) and [prismatic](PrismaticJoint) joints.
+//!
 //! ### Attachment positions
 //!
 //! By default, joints are connected to the centers of entities, but attachment positions can be used to change this.
@@ -58,6 +91,38 @@
 //! `with_angular_velocity_damping` methods. Increasing the damping values will cause the velocities
 //! of the connected entities to decrease faster.
 //!
+//! ### Motors
+//!
+//! [Revolute joints](RevoluteJoint), [prismatic joints](PrismaticJoint) and the axes of [generic joints](GenericJoint) can be powered by a [`JointMotor`]
+//! that drives the bodies towards a target velocity or a target angle or position along the free axis.
+//! Motors are solved together with the other constraints, and the maximum force or torque they can apply can be limited.
+//!
+//! [Generic joints](GenericJoint) can also be driven towards a target rotation around all angular axes at once
+//! with an [`OrientationDrive`].
+//!
+//! ### Coupling joints
+//!
+//! Some joints don't restrict the DOF of the attached bodies, but couple their motion instead.
+//! [Gear joints](GearJoint) couple the rotation of two bodies with a ratio, [rack-and-pinion joints](RackAndPinionJoint)
+//! couple the rotation of one body to the translation of another, and [pulley joints](PulleyJoint) connect two bodies
+//! with a rope that runs over two fixed ground anchors.
+//!
+//! ### Collisions between connected bodies
+//!
+//! By default, bodies connected by a joint don't collide with each other, as the contacts would fight the joint.
+//! Use [`Joint::with_collide_connected`] to allow the connected bodies to collide.
+//! See [`CollisionExclusions`] for excluding collisions between bodies that aren't connected by joints.
+//!
+//! ### Breaking
+//!
+//! Joints can be made breakable using the `with_break_force` and `with_break_torque` methods.
+//! When the force or torque exerted by a joint exceeds the given threshold during a substep,
+//! the joint component is removed and a [`JointBroken`] event is sent. This can be used for things
+//! like bridges and destructible structures that fail under load.
+//!
+//! Not every joint exerts both forces and torques. For example, [gear joints](GearJoint) only exert torques
+//! and [distance joints](DistanceJoint) only exert forces, so only the matching threshold has an effect.
+//!
 //! ### Other configuration
 //!
 //! Different joints may have different configuration options. Many joints allow you to change the axis of allowed
@@ -81,13 +146,21 @@
 
 mod distance;
 mod fixed;
+mod gear;
+mod generic;
 mod prismatic;
+mod pulley;
+mod rack_and_pinion;
 mod revolute;
 mod spherical;
 
 pub use distance::*;
 pub use fixed::*;
+pub use gear::*;
+pub use generic::*;
 pub use prismatic::*;
+pub use pulley::*;
+pub use rack_and_pinion::*;
 pub use revolute::*;
 pub use spherical::*;
 
@@ -99,6 +172,17 @@ pub trait Joint: Component + PositionConstraint + AngularConstraint {
     /// Creates a new joint between two entities.
     fn new(entity1: Entity, entity2: Entity) -> Self;
 
+    /// Creates a new joint that attaches an entity to a fixed point in the world given by `world_anchor`.
+    ///
+    /// The [`WorldBody`] is used as the first entity, and the world anchor is used as the local anchor
+    /// on the first body.
+    fn new_to_world(world_body: &WorldBodyEntity, entity: Entity, world_anchor: Vector) -> Self
+    where
+        Self: Sized,
+    {
+        Self::new(world_body.0, entity).with_local_anchor_1(world_anchor)
+    }
+
     /// Sets the joint's compliance (inverse of stiffness, meters / Newton).
     fn with_compliance(self, compliance: Scalar) -> Self;
 
@@ -126,6 +210,40 @@ pub trait Joint: Component + PositionConstraint + AngularConstraint {
     /// Returns the angular velocity damping of the joint.
     fn damping_angular(&self) -> Scalar;
 
+    /// Sets the force at which the joint breaks. If the magnitude of the force exerted by the joint
+    /// exceeds this value during a substep, the joint is removed and a [`JointBroken`] event is sent.
+    fn with_break_force(self, force: Scalar) -> Self;
+
+    /// Sets the torque at which the joint breaks. If the magnitude of the torque exerted by the joint
+    /// exceeds this value during a substep, the joint is removed and a [`JointBroken`] event is sent.
+    fn with_break_torque(self, torque: Scalar) -> Self;
+
+    /// Returns the force at which the joint breaks.
+    fn break_force(&self) -> Scalar;
+
+    /// Returns the torque at which the joint breaks.
+    fn break_torque(&self) -> Scalar;
+
+    /// Sets whether the bodies connected by the joint can collide with each other.
+    /// By default, they don't collide, as the contacts would fight the joint.
+    fn with_collide_connected(self, collide_connected: bool) -> Self;
+
+    /// Returns `true` if the bodies connected by the joint can collide with each other.
+    fn collide_connected(&self) -> bool;
+
+    /// Returns the magnitude of the largest force exerted by the joint on either body during the last substep.
+    ///
+    /// Joints that only apply torques, like [gear joints](GearJoint), return zero,
+    /// so they can't be broken with a break force.
+    fn force_magnitude(&self) -> Scalar;
+
+    /// Returns the magnitude of the largest torque exerted by the joint on either body during the last substep.
+    ///
+    /// Only the torque of the angular corrections is included, not the torque caused by forces applied
+    /// at the anchors. Joints without angular corrections, like [distance joints](DistanceJoint), return zero,
+    /// so they can't be broken with a break torque.
+    fn torque_magnitude(&self) -> Scalar;
+
     /// Applies a positional correction that aligns the positions of the local attachment points `r1` and `r2`.
     ///
     /// Returns the force exerted by the alignment.
@@ -172,6 +290,56 @@ pub trait Joint: Component + PositionConstraint + AngularConstraint {
         self.compute_force(*lagrange, dir, dt)
     }
 
+    /// Applies a soft positional correction that pulls the local attachment points `r1` and `r2` together
+    /// like a damped [`JointSpring`].
+    ///
+    /// Returns the force exerted by the spring.
+    #[allow(clippy::too_many_arguments)]
+    fn align_position_with_spring(
+        &self,
+        body1: &mut RigidBodyQueryItem,
+        body2: &mut RigidBodyQueryItem,
+        r1: Vector,
+        r2: Vector,
+        lagrange: &mut Scalar,
+        spring: JointSpring,
+        dt: Scalar,
+    ) -> Vector {
+        let world_r1 = body1.rotation.rotate(r1);
+        let world_r2 = body2.rotation.rotate(r2);
+
+        let offset = body2.current_position() + world_r2 - body1.current_position() - world_r1;
+        let (dir, magnitude) = DistanceLimit::ZERO.compute_correction(Vector::ZERO, offset);
+
+        if magnitude <= Scalar::EPSILON {
+            return Vector::ZERO;
+        }
+
+        // Compute generalized inverse masses
+        let w1 = PositionConstraint::compute_generalized_inverse_mass(self, body1, world_r1, dir);
+        let w2 = PositionConstraint::compute_generalized_inverse_mass(self, body2, world_r2, dir);
+
+        // Compute Lagrange multiplier update
+        let delta_lagrange = spring.compute_positional_lagrange_update(
+            body1,
+            body2,
+            r1,
+            r2,
+            dir,
+            magnitude,
+            *lagrange,
+            w1 + w2,
+            dt,
+        );
+        *lagrange += delta_lagrange;
+
+        // Apply positional correction to pull the positions of the bodies together
+        self.apply_positional_correction(body1, body2, delta_lagrange, dir, world_r1, world_r2);
+
+        // Return constraint force
+        self.compute_force(*lagrange, dir, dt)
+    }
+
     /// Applies an angular correction that aligns the orientation of the bodies.
     ///
     /// Returns the torque exerted by the alignment.
@@ -348,3 +516,386 @@ impl AngleLimit {
         None
     }
 }
+
+/// A motor that drives the free axis of a [joint](joints) towards a target velocity or position.
+///
+/// Motors can be used with [revolute joints](RevoluteJoint) to drive their relative rotation around
+/// the aligned axis, and with [prismatic joints](PrismaticJoint) to drive their relative translation
+/// along the free axis. Angles are given in radians and wrap around at `±π`.
+///
+/// The motor is solved as a constraint in the XPBD loop. The maximum force or torque that the motor can apply
+/// is given by `max_force`, and the force or torque that was applied is stored in the joint.
+/// Bodies attached to a joint with a motor are kept awake and never marked as [`Sleeping`].
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+#[cfg_attr(feature = "2d", doc = "use bevy_xpbd_2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use bevy_xpbd_3d::prelude::*;")]
+///
+/// fn setup(mut commands: Commands) {
+///     let chassis = commands.spawn(RigidBody::Dynamic).id();
+///     let wheel = commands.spawn(RigidBody::Dynamic).id();
+///
+///     // Spin the wheel at 10 radians per second with a maximum torque of 50
+///     commands.spawn(
+///         RevoluteJoint::new(chassis, wheel)
+///             .with_motor(JointMotor::velocity(10.0).with_max_force(50.0)),
+///     );
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct JointMotor {
+    /// The target that the motor drives the joint towards.
+    pub target: MotorTarget,
+    /// The maximum force or torque that the motor can apply.
+    pub max_force: Scalar,
+    /// The compliance of the motor, the inverse of stiffness. With a compliance of zero,
+    /// the motor reaches its target as fast as allowed by `max_force`.
+    pub compliance: Scalar,
+    /// The damping of the motor that resists deviations from the target velocity.
+    /// The damping is scaled by the compliance, so it has no effect on motors with zero compliance.
+    pub damping: Scalar,
+}
+
+/// The target of a [`JointMotor`].
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum MotorTarget {
+    /// Drives the joint towards a target velocity, in radians or meters per second.
+    Velocity(Scalar),
+    /// Drives the joint towards a target angle or position, in radians or meters.
+    Position(Scalar),
+}
+
+impl JointMotor {
+    /// Creates a motor that drives the joint towards the given target velocity.
+    pub fn velocity(target_velocity: Scalar) -> Self {
+        Self {
+            target: MotorTarget::Velocity(target_velocity),
+            max_force: Scalar::INFINITY,
+            compliance: 0.0,
+            damping: 0.0,
+        }
+    }
+
+    /// Creates a motor that drives the joint towards the given target angle or position.
+    pub fn position(target_position: Scalar) -> Self {
+        Self {
+            target: MotorTarget::Position(target_position),
+            ..Self::velocity(0.0)
+        }
+    }
+
+    /// Sets the maximum force or torque that the motor can apply.
+    pub fn with_max_force(self, max_force: Scalar) -> Self {
+        Self { max_force, ..self }
+    }
+
+    /// Sets the compliance of the motor (inverse of stiffness).
+    pub fn with_compliance(self, compliance: Scalar) -> Self {
+        Self { compliance, ..self }
+    }
+
+    /// Sets the damping of the motor.
+    pub fn with_damping(self, damping: Scalar) -> Self {
+        Self { damping, ..self }
+    }
+
+    /// Computes the update of the motor's Lagrange multiplier, given the current `position` and
+    /// the `previous_position` at the start of the substep along the free axis of the joint.
+    ///
+    /// `w_sum` is the sum of the generalized inverse masses of the bodies, and `wrap_angle`
+    /// determines if the position is an angle that should wrap around at `±π`.
+    /// The total Lagrange multiplier is clamped based on `max_force`.
+    pub(crate) fn compute_lagrange_update(
+        &self,
+        lagrange: Scalar,
+        position: Scalar,
+        previous_position: Scalar,
+        w_sum: Scalar,
+        wrap_angle: bool,
+        dt: Scalar,
+    ) -> Scalar {
+        if w_sum <= Scalar::EPSILON {
+            return 0.0;
+        }
+
+        let wrap = |value: Scalar| {
+            if wrap_angle {
+                (value + PI).rem_euclid(2.0 * PI) - PI
+            } else {
+                value
+            }
+        };
+
+        // The deviation from the target velocity multiplied by the time step
+        let displacement = wrap(position - previous_position);
+        let (c, velocity_error) = match self.target {
+            MotorTarget::Velocity(velocity) => {
+                let error = displacement - velocity * dt;
+                (error, error)
+            }
+            MotorTarget::Position(target) => (wrap(position - target), displacement),
+        };
+
+        let tilde_compliance = self.compliance / dt.powi(2);
+        let gamma = self.compliance * self.damping / dt;
+
+        let delta_lagrange = (-c - tilde_compliance * lagrange - gamma * velocity_error)
+            / ((1.0 + gamma) * w_sum + tilde_compliance);
+
+        // Limit the force of the motor
+        let max_lagrange = self.max_force * dt.powi(2);
+        (lagrange + delta_lagrange).clamp(-max_lagrange, max_lagrange) - lagrange
+    }
+}
+
+/// A drive that rotates the second joint frame of a [`GenericJoint`] towards a target rotation
+/// relative to the first joint frame.
+///
+/// Unlike the [`JointMotor`]s of the angular axes, an orientation drive drives all angular axes at once
+/// along the shortest rotation to the target. This is useful for things like active ragdolls that try
+/// to match an animated pose.
+///
+/// The drive is solved before the limits of the joint, so the limits take priority.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrientationDrive {
+    /// The target rotation of the second joint frame relative to the first joint frame.
+    pub target: Rotation,
+    /// The maximum torque that the drive can apply.
+    pub max_torque: Scalar,
+    /// The compliance of the drive, the inverse of stiffness. With a compliance of zero,
+    /// the drive reaches its target as fast as allowed by `max_torque`.
+    pub compliance: Scalar,
+    /// The damping of the drive that resists changes in the rotation relative to the target.
+    /// The damping is scaled by the compliance, so it has no effect on drives with zero compliance.
+    pub damping: Scalar,
+}
+
+impl Default for OrientationDrive {
+    fn default() -> Self {
+        Self::new(Rotation::default())
+    }
+}
+
+impl OrientationDrive {
+    /// Creates a drive that rotates the second joint frame towards the given `target`
+    /// relative to the first joint frame.
+    pub fn new(target: Rotation) -> Self {
+        Self {
+            target,
+            max_torque: Scalar::INFINITY,
+            compliance: 0.0,
+            damping: 0.0,
+        }
+    }
+
+    /// Sets the target rotation of the second joint frame relative to the first joint frame.
+    pub fn with_target(self, target: Rotation) -> Self {
+        Self { target, ..self }
+    }
+
+    /// Sets the maximum torque that the drive can apply.
+    pub fn with_max_torque(self, max_torque: Scalar) -> Self {
+        Self { max_torque, ..self }
+    }
+
+    /// Sets the compliance of the drive (inverse of stiffness).
+    pub fn with_compliance(self, compliance: Scalar) -> Self {
+        Self { compliance, ..self }
+    }
+
+    /// Sets the damping of the drive.
+    pub fn with_damping(self, damping: Scalar) -> Self {
+        Self { damping, ..self }
+    }
+
+    /// Computes the update of the drive's Lagrange multiplier, given the `angle` between the current rotation
+    /// and the target and the `previous_angle` at the start of the substep around the same axis.
+    ///
+    /// The total Lagrange multiplier is clamped based on `max_torque`.
+    pub(crate) fn compute_lagrange_update(
+        &self,
+        lagrange: Scalar,
+        angle: Scalar,
+        previous_angle: Scalar,
+        w_sum: Scalar,
+        dt: Scalar,
+    ) -> Scalar {
+        // The drive behaves like a motor with a target angle of zero around the axis of the error
+        JointMotor::position(0.0)
+            .with_max_force(self.max_torque)
+            .with_compliance(self.compliance)
+            .with_damping(self.damping)
+            .compute_lagrange_update(lagrange, angle, previous_angle, w_sum, false, dt)
+    }
+}
+
+/// A spring that makes a [joint](joints) soft, given by a natural frequency and a damping ratio.
+///
+/// Springs can be used with [distance joints](DistanceJoint) and for the positional part of
+/// [fixed](FixedJoint), [revolute](RevoluteJoint), [spherical](SphericalJoint) and [prismatic](PrismaticJoint) joints.
+/// Instead of a fixed compliance, the spring computes the compliance and damping from the effective mass of the
+/// attached bodies each substep, so the joint oscillates at the same frequency regardless of the masses
+/// of the bodies and the [`SubstepCount`]. This makes springs useful for things like suspensions and bungee cords.
+///
+/// When a joint has a spring, its compliance is not used for the positional part of the joint.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct JointSpring {
+    /// The natural frequency of the spring in Hz.
+    pub frequency: Scalar,
+    /// The damping ratio of the spring. A value of 0 means no damping, and 1 is critical damping,
+    /// where the spring returns to its rest position as fast as possible without oscillating.
+    pub damping_ratio: Scalar,
+}
+
+impl JointSpring {
+    /// Creates a new spring with the given natural `frequency` in Hz and `damping_ratio`.
+    pub fn new(frequency: Scalar, damping_ratio: Scalar) -> Self {
+        Self {
+            frequency,
+            damping_ratio,
+        }
+    }
+
+    /// Returns the compliance and damping coefficient of the spring for bodies with the given
+    /// effective `inverse_mass`.
+    pub fn compliance_and_damping(&self, inverse_mass: Scalar) -> (Scalar, Scalar) {
+        let angular_frequency = 2.0 * PI * self.frequency;
+        let compliance = inverse_mass / angular_frequency.powi(2);
+        let damping = 2.0 * self.damping_ratio * angular_frequency / inverse_mass;
+        (compliance, damping)
+    }
+
+    /// Computes the Lagrange multiplier update for the spring, given the constraint error `c`,
+    /// the error `previous_c` at the start of the substep and the sum of the generalized inverse masses `w_sum`.
+    pub(crate) fn compute_lagrange_update(
+        &self,
+        lagrange: Scalar,
+        c: Scalar,
+        previous_c: Scalar,
+        w_sum: Scalar,
+        dt: Scalar,
+    ) -> Scalar {
+        // Avoid division by zero
+        if w_sum <= Scalar::EPSILON || self.frequency <= 0.0 {
+            return 0.0;
+        }
+
+        let (compliance, damping) = self.compliance_and_damping(w_sum);
+        let tilde_compliance = compliance / dt.powi(2);
+        let gamma = compliance * damping / dt;
+
+        (-c - tilde_compliance * lagrange - gamma * (c - previous_c))
+            / ((1.0 + gamma) * w_sum + tilde_compliance)
+    }
+
+    /// Computes the Lagrange multiplier update for the spring pulling the local attachment points `r1` and `r2`
+    /// of the bodies together, given the direction `dir` and `magnitude` of the positional correction
+    /// and the sum of the generalized inverse masses `w_sum`.
+    ///
+    /// The error along `dir` at the start of the substep is used for damping.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn compute_positional_lagrange_update(
+        &self,
+        body1: &RigidBodyQueryItem,
+        body2: &RigidBodyQueryItem,
+        r1: Vector,
+        r2: Vector,
+        dir: Vector,
+        magnitude: Scalar,
+        lagrange: Scalar,
+        w_sum: Scalar,
+        dt: Scalar,
+    ) -> Scalar {
+        let offset = body2.current_position() + body2.rotation.rotate(r2)
+            - body1.current_position()
+            - body1.rotation.rotate(r1);
+        let previous_offset =
+            previous_anchor_position(body2, r2) - previous_anchor_position(body1, r1);
+        let previous_magnitude = magnitude - (previous_offset - offset).dot(dir);
+
+        self.compute_lagrange_update(lagrange, magnitude, previous_magnitude, w_sum, dt)
+    }
+}
+
+/// A [`Joint`] that broke because the force or torque exerted by it exceeded its
+/// [break force](Joint::with_break_force) or [break torque](Joint::with_break_torque).
+///
+/// The joint component has been removed from the entity when this event is sent.
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct JointBroken {
+    /// The entity of the joint.
+    pub entity: Entity,
+    /// The first entity that was constrained by the joint.
+    pub entity1: Entity,
+    /// The second entity that was constrained by the joint.
+    pub entity2: Entity,
+    /// The magnitude of the force exerted by the joint when it broke.
+    pub force: Scalar,
+    /// The magnitude of the torque exerted by the joint when it broke.
+    pub torque: Scalar,
+}
+
+/// Returns the magnitude of the given torque.
+pub(crate) fn torque_length(torque: Torque) -> Scalar {
+    #[cfg(feature = "2d")]
+    {
+        torque.abs()
+    }
+    #[cfg(feature = "3d")]
+    {
+        torque.length()
+    }
+}
+
+/// Returns the world-space position of the given local anchor of a body at the start of the current substep.
+pub(crate) fn previous_anchor_position(body: &RigidBodyQueryItem, local_anchor: Vector) -> Vector {
+    body.previous_position.0 + body.previous_rotation.0.rotate(local_anchor)
+}
+
+/// Returns the angle that the body has rotated around the given world-space `axis` during the current substep.
+#[cfg(feature = "2d")]
+pub(crate) fn rotation_during_substep(body: &RigidBodyQueryItem, _axis: Vector3) -> Scalar {
+    (*body.rotation - body.previous_rotation.0).as_radians()
+}
+
+/// Returns the angle that the body has rotated around the given world-space `axis` during the current substep.
+#[cfg(feature = "3d")]
+pub(crate) fn rotation_during_substep(body: &RigidBodyQueryItem, axis: Vector) -> Scalar {
+    let mut delta_rot = body.rotation.0 * body.previous_rotation.0.inverse().0;
+    if delta_rot.w < 0.0 {
+        delta_rot = -delta_rot;
+    }
+    2.0 * delta_rot.xyz().dot(axis)
+}
+
+/// Rotates a dynamic body by an angular impulse of magnitude `impulse` around the world-space `axis`.
+///
+/// This is used by coupling joints, where the bodies are rotated around different axes.
+#[cfg(feature = "2d")]
+pub(crate) fn apply_angular_impulse(body: &mut RigidBodyQueryItem, impulse: Scalar, axis: Vector3) {
+    if body.rb.is_dynamic() && impulse.abs() > Scalar::EPSILON {
+        let inv_inertia = body.effective_world_inv_inertia();
+        *body.rotation += Rotation::from_radians(inv_inertia * impulse * axis.z);
+    }
+}
+
+/// Rotates a dynamic body by an angular impulse of magnitude `impulse` around the world-space `axis`.
+///
+/// This is used by coupling joints, where the bodies are rotated around different axes.
+#[cfg(feature = "3d")]
+pub(crate) fn apply_angular_impulse(body: &mut RigidBodyQueryItem, impulse: Scalar, axis: Vector) {
+    if body.rb.is_dynamic() && impulse.abs() > Scalar::EPSILON {
+        let inv_inertia = body.effective_world_inv_inertia();
+        let rot = *body.rotation;
+        *body.rotation += Rotation(
+            Quaternion::from_vec4(0.5 * (inv_inertia * impulse * axis).extend(0.0)) * rot.0,
+        );
+    }
+}
@@ -9,6 +9,8 @@ use bevy::{
 /// A prismatic joint prevents relative movement of the attached bodies, except for translation along one `free_axis`.
 ///
 /// Prismatic joints can be useful for things like elevators, pistons, sliding doors and moving platforms.
+///
+/// The translation along the `free_axis` can be driven by a [`JointMotor`] set using [`PrismaticJoint::with_motor`].
 #[derive(Component, Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrismaticJoint {
@@ -24,6 +26,8 @@ pub struct PrismaticJoint {
     pub free_axis: Vector,
     /// The extents of the allowed relative translation along the free axis.
     pub free_axis_limits: Option<DistanceLimit>,
+    /// The motor that drives the relative translation of the bodies along the free axis.
+    pub motor: Option<JointMotor>,
     /// Linear damping applied by the joint.
     pub damping_linear: Scalar,
     /// Angular damping applied by the joint.
@@ -32,12 +36,24 @@ pub struct PrismaticJoint {
     pub position_lagrange: Scalar,
     /// Lagrange multiplier for the angular correction caused by the alignment of the bodies.
     pub align_lagrange: Scalar,
+    /// Lagrange multiplier for the positional correction caused by the motor.
+    pub motor_lagrange: Scalar,
     /// The joint's compliance, the inverse of stiffness, has the unit meters / Newton.
     pub compliance: Scalar,
+    /// The spring that makes the positional part of the joint soft. If set, the `compliance` is not used for it.
+    pub spring: Option<JointSpring>,
+    /// The force at which the joint breaks. See [`Joint::with_break_force`].
+    pub break_force: Scalar,
+    /// The torque at which the joint breaks. See [`Joint::with_break_torque`].
+    pub break_torque: Scalar,
+    /// Whether the bodies connected by the joint can collide with each other. See [`Joint::with_collide_connected`].
+    pub collide_connected: bool,
     /// The force exerted by the joint.
     pub force: Vector,
     /// The torque exerted by the joint when aligning the bodies.
     pub align_torque: Torque,
+    /// The force exerted by the motor along the free axis.
+    pub motor_force: Vector,
 }
 
 impl XpbdConstraint<2> for PrismaticJoint {
@@ -48,6 +64,7 @@ impl XpbdConstraint<2> for PrismaticJoint {
     fn clear_lagrange_multipliers(&mut self) {
         self.position_lagrange = 0.0;
         self.align_lagrange = 0.0;
+        self.motor_lagrange = 0.0;
     }
 
     fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) {
@@ -60,6 +77,9 @@ impl XpbdConstraint<2> for PrismaticJoint {
         self.align_torque = self.align_orientation(body1, body2, dq, &mut lagrange, compliance, dt);
         self.align_lagrange = lagrange;
 
+        // Drive the translation along the free axis before the limits so that the limits take priority
+        self.motor_force = self.apply_motor(body1, body2, dt);
+
         // Constrain the relative positions of the bodies, only allowing translation along one free axis
         self.force = self.constrain_positions(body1, body2, dt);
     }
@@ -74,16 +94,23 @@ impl Joint for PrismaticJoint {
             local_anchor2: Vector::ZERO,
             free_axis: Vector::X,
             free_axis_limits: None,
+            motor: None,
             damping_linear: 1.0,
             damping_angular: 1.0,
             position_lagrange: 0.0,
             align_lagrange: 0.0,
+            motor_lagrange: 0.0,
             compliance: 0.0,
+            spring: None,
+            break_force: Scalar::INFINITY,
+            break_torque: Scalar::INFINITY,
+            collide_connected: false,
             force: Vector::ZERO,
             #[cfg(feature = "2d")]
             align_torque: 0.0,
             #[cfg(feature = "3d")]
             align_torque: Vector::ZERO,
+            motor_force: Vector::ZERO,
         }
     }
 
@@ -134,6 +161,47 @@ impl Joint for PrismaticJoint {
     fn damping_angular(&self) -> Scalar {
         self.damping_angular
     }
+
+    fn with_break_force(self, force: Scalar) -> Self {
+        Self {
+            break_force: force,
+            ..self
+        }
+    }
+
+    fn with_break_torque(self, torque: Scalar) -> Self {
+        Self {
+            break_torque: torque,
+            ..self
+        }
+    }
+
+    fn break_force(&self) -> Scalar {
+        self.break_force
+    }
+
+    fn break_torque(&self) -> Scalar {
+        self.break_torque
+    }
+
+    fn with_collide_connected(self, collide_connected: bool) -> Self {
+        Self {
+            collide_connected,
+            ..self
+        }
+    }
+
+    fn collide_connected(&self) -> bool {
+        self.collide_connected
+    }
+
+    fn force_magnitude(&self) -> Scalar {
+        (self.force + self.motor_force).length()
+    }
+
+    fn torque_magnitude(&self) -> Scalar {
+        torque_length(self.align_torque)
+    }
 }
 
 impl PrismaticJoint {
@@ -205,14 +273,27 @@ impl PrismaticJoint {
         let w = [w1, w2];
 
         // Compute Lagrange multiplier update
-        let delta_lagrange = self.compute_lagrange_update(
-            self.position_lagrange,
-            magnitude,
-            &gradients,
-            &w,
-            self.compliance,
-            dt,
-        );
+        let delta_lagrange = match self.spring {
+            Some(spring) => spring.compute_positional_lagrange_update(
+                body1,
+                body2,
+                self.local_anchor1,
+                self.local_anchor2,
+                dir,
+                magnitude,
+                self.position_lagrange,
+                w1 + w2,
+                dt,
+            ),
+            None => self.compute_lagrange_update(
+                self.position_lagrange,
+                magnitude,
+                &gradients,
+                &w,
+                self.compliance,
+                dt,
+            ),
+        };
         self.position_lagrange += delta_lagrange;
 
         // Apply positional correction to align the positions of the bodies
@@ -222,6 +303,71 @@ impl PrismaticJoint {
         self.compute_force(self.position_lagrange, dir, dt)
     }
 
+    /// Applies the [`JointMotor`] to drive the relative translation of the bodies along the free axis.
+    ///
+    /// Returns the force exerted by the motor.
+    fn apply_motor(
+        &mut self,
+        body1: &mut RigidBodyQueryItem,
+        body2: &mut RigidBodyQueryItem,
+        dt: Scalar,
+    ) -> Vector {
+        let Some(motor) = self.motor else {
+            return Vector::ZERO;
+        };
+
+        let world_r1 = body1.rotation.rotate(self.local_anchor1);
+        let world_r2 = body2.rotation.rotate(self.local_anchor2);
+        let axis1 = body1.rotation.rotate(self.free_axis);
+
+        let position =
+            (body2.current_position() + world_r2 - body1.current_position() - world_r1).dot(axis1);
+        let previous_position = {
+            let rot1 = body1.previous_rotation.0;
+            let rot2 = body2.previous_rotation.0;
+            (body2.previous_position.0 + rot2.rotate(self.local_anchor2)
+                - body1.previous_position.0
+                - rot1.rotate(self.local_anchor1))
+            .dot(rot1.rotate(self.free_axis))
+        };
+
+        // The correction is applied in the opposite direction of the free axis,
+        // so a positive Lagrange multiplier update increases the translation along the axis.
+        let dir = -axis1;
+        let w1 = PositionConstraint::compute_generalized_inverse_mass(self, body1, world_r1, dir);
+        let w2 = PositionConstraint::compute_generalized_inverse_mass(self, body2, world_r2, dir);
+
+        let delta_lagrange = motor.compute_lagrange_update(
+            self.motor_lagrange,
+            position,
+            previous_position,
+            w1 + w2,
+            false,
+            dt,
+        );
+        self.motor_lagrange += delta_lagrange;
+
+        self.apply_positional_correction(body1, body2, delta_lagrange, dir, world_r1, world_r2);
+
+        self.compute_force(self.motor_lagrange, dir, dt)
+    }
+
+    /// Sets the spring that makes the positional part of the joint soft.
+    pub fn with_spring(self, spring: JointSpring) -> Self {
+        Self {
+            spring: Some(spring),
+            ..self
+        }
+    }
+
+    /// Sets the motor that drives the relative translation along the free axis.
+    pub fn with_motor(self, motor: JointMotor) -> Self {
+        Self {
+            motor: Some(motor),
+            ..self
+        }
+    }
+
     /// Sets the joint's free axis. Relative translations are allowed along this free axis.
     pub fn with_free_axis(self, axis: Vector) -> Self {
         Self {
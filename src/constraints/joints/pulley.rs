@@ -0,0 +1,285 @@
+//! [`PulleyJoint`] component.
+
+use crate::prelude::*;
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+/// A pulley joint connects two bodies with a rope that runs over two fixed ground anchors,
+/// like a rope running over a pulley.
+///
+/// The rope goes from the anchor on the first body to the first ground anchor, and from the second ground anchor
+/// to the anchor on the second body. The joint keeps `length1 + ratio * length2` from exceeding the total length
+/// of the rope, where `length1` and `length2` are the lengths of the two segments. A ratio other than 1 can be
+/// used to model a block and tackle, where one of the segments is shortened more slowly than the other is lengthened.
+///
+/// Like a real rope, the joint only pulls the bodies towards the ground anchors and lets the rope go slack.
+/// If no [length](PulleyJoint::length) is given, the total length is computed from the positions of the bodies
+/// when the joint is first solved.
+///
+/// As pulley joints only exert forces, they can only be broken with a [break force](Joint::with_break_force).
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(MapEntities)]
+pub struct PulleyJoint {
+    /// First entity constrained by the joint.
+    pub entity1: Entity,
+    /// Second entity constrained by the joint.
+    pub entity2: Entity,
+    /// Attachment point on the first body.
+    pub local_anchor1: Vector,
+    /// Attachment point on the second body.
+    pub local_anchor2: Vector,
+    /// The fixed world-space point that the first segment of the rope runs over.
+    pub ground_anchor1: Vector,
+    /// The fixed world-space point that the second segment of the rope runs over.
+    pub ground_anchor2: Vector,
+    /// The multiplier for the length of the second segment of the rope.
+    pub ratio: Scalar,
+    /// The total length of the rope, `length1 + ratio * length2`.
+    /// If `None`, it is computed from the positions of the bodies when the joint is first solved.
+    pub length: Option<Scalar>,
+    /// Linear damping applied by the joint.
+    pub damping_linear: Scalar,
+    /// Angular damping applied by the joint.
+    pub damping_angular: Scalar,
+    /// Lagrange multiplier for the positional correction.
+    pub lagrange: Scalar,
+    /// The joint's compliance, the inverse of stiffness, has the unit meters / Newton.
+    pub compliance: Scalar,
+    /// The force at which the joint breaks. See [`Joint::with_break_force`].
+    pub break_force: Scalar,
+    /// The torque at which the joint breaks. See [`Joint::with_break_torque`].
+    pub break_torque: Scalar,
+    /// Whether the bodies connected by the joint can collide with each other. See [`Joint::with_collide_connected`].
+    pub collide_connected: bool,
+    /// The tension of the rope, the magnitude of the force exerted on the first body.
+    pub tension: Scalar,
+}
+
+impl XpbdConstraint<2> for PulleyJoint {
+    fn entities(&self) -> [Entity; 2] {
+        [self.entity1, self.entity2]
+    }
+
+    fn clear_lagrange_multipliers(&mut self) {
+        self.lagrange = 0.0;
+    }
+
+    fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) {
+        self.tension = self.constrain_length(bodies, dt);
+    }
+}
+
+impl Joint for PulleyJoint {
+    fn new(entity1: Entity, entity2: Entity) -> Self {
+        Self {
+            entity1,
+            entity2,
+            local_anchor1: Vector::ZERO,
+            local_anchor2: Vector::ZERO,
+            ground_anchor1: Vector::ZERO,
+            ground_anchor2: Vector::ZERO,
+            ratio: 1.0,
+            length: None,
+            damping_linear: 0.0,
+            damping_angular: 0.0,
+            lagrange: 0.0,
+            compliance: 0.0,
+            break_force: Scalar::INFINITY,
+            break_torque: Scalar::INFINITY,
+            collide_connected: false,
+            tension: 0.0,
+        }
+    }
+
+    fn with_compliance(self, compliance: Scalar) -> Self {
+        Self { compliance, ..self }
+    }
+
+    fn with_local_anchor_1(self, anchor: Vector) -> Self {
+        Self {
+            local_anchor1: anchor,
+            ..self
+        }
+    }
+
+    fn with_local_anchor_2(self, anchor: Vector) -> Self {
+        Self {
+            local_anchor2: anchor,
+            ..self
+        }
+    }
+
+    fn with_linear_velocity_damping(self, damping: Scalar) -> Self {
+        Self {
+            damping_linear: damping,
+            ..self
+        }
+    }
+
+    fn with_angular_velocity_damping(self, damping: Scalar) -> Self {
+        Self {
+            damping_angular: damping,
+            ..self
+        }
+    }
+
+    fn local_anchor_1(&self) -> Vector {
+        self.local_anchor1
+    }
+
+    fn local_anchor_2(&self) -> Vector {
+        self.local_anchor2
+    }
+
+    fn damping_linear(&self) -> Scalar {
+        self.damping_linear
+    }
+
+    fn damping_angular(&self) -> Scalar {
+        self.damping_angular
+    }
+
+    fn with_break_force(self, force: Scalar) -> Self {
+        Self {
+            break_force: force,
+            ..self
+        }
+    }
+
+    fn with_break_torque(self, torque: Scalar) -> Self {
+        Self {
+            break_torque: torque,
+            ..self
+        }
+    }
+
+    fn break_force(&self) -> Scalar {
+        self.break_force
+    }
+
+    fn break_torque(&self) -> Scalar {
+        self.break_torque
+    }
+
+    fn with_collide_connected(self, collide_connected: bool) -> Self {
+        Self {
+            collide_connected,
+            ..self
+        }
+    }
+
+    fn collide_connected(&self) -> bool {
+        self.collide_connected
+    }
+
+    fn force_magnitude(&self) -> Scalar {
+        // The force exerted on the second body is `ratio` times the tension of the rope
+        self.tension * self.ratio.abs().max(1.0)
+    }
+
+    fn torque_magnitude(&self) -> Scalar {
+        // Pulley joints don't constrain the rotation of the bodies
+        0.0
+    }
+}
+
+impl PulleyJoint {
+    /// Keeps the total length of the rope from exceeding the length of the joint.
+    ///
+    /// Returns the tension of the rope.
+    fn constrain_length(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) -> Scalar {
+        let [body1, body2] = bodies;
+        let world_r1 = body1.rotation.rotate(self.local_anchor1);
+        let world_r2 = body2.rotation.rotate(self.local_anchor2);
+
+        // The segments of the rope point from the ground anchors to the bodies
+        let segment1 = body1.current_position() + world_r1 - self.ground_anchor1;
+        let segment2 = body2.current_position() + world_r2 - self.ground_anchor2;
+        let (length1, length2) = (segment1.length(), segment2.length());
+        let total_length = length1 + self.ratio * length2;
+        let max_length = *self.length.get_or_insert(total_length);
+
+        // The rope is slack
+        let c = total_length - max_length;
+        if c <= Scalar::EPSILON {
+            return 0.0;
+        }
+
+        let dir1 = segment1.normalize_or_zero();
+        let dir2 = segment2.normalize_or_zero();
+
+        let w1 = PositionConstraint::compute_generalized_inverse_mass(self, body1, world_r1, dir1);
+        let w2 = PositionConstraint::compute_generalized_inverse_mass(self, body2, world_r2, dir2);
+        let gradients = [dir1, self.ratio * dir2];
+
+        let delta_lagrange = self.compute_lagrange_update(
+            self.lagrange,
+            c,
+            &gradients,
+            &[w1, w2],
+            self.compliance,
+            dt,
+        );
+        self.lagrange += delta_lagrange;
+
+        // Unlike with other joints, the bodies are moved along different directions,
+        // so the positional correction is applied to each body separately
+        if delta_lagrange.abs() > Scalar::EPSILON {
+            for (body, r, gradient) in [
+                (body1, world_r1, gradients[0]),
+                (body2, world_r2, gradients[1]),
+            ] {
+                if body.rb.is_dynamic() {
+                    let p = delta_lagrange * gradient;
+                    let rot = *body.rotation;
+                    let inv_mass = body.effective_inv_mass();
+                    let inv_inertia = body.effective_world_inv_inertia();
+                    body.accumulated_translation.0 += p * inv_mass;
+                    *body.rotation +=
+                        <Self as PositionConstraint>::get_delta_rot(rot, inv_inertia, r, p);
+                }
+            }
+        }
+
+        -self.lagrange / dt.powi(2)
+    }
+
+    /// Sets the fixed world-space points that the two segments of the rope run over.
+    pub fn with_ground_anchors(self, ground_anchor1: Vector, ground_anchor2: Vector) -> Self {
+        Self {
+            ground_anchor1,
+            ground_anchor2,
+            ..self
+        }
+    }
+
+    /// Sets the multiplier for the length of the second segment of the rope.
+    pub fn with_ratio(self, ratio: Scalar) -> Self {
+        Self { ratio, ..self }
+    }
+
+    /// Sets the total length of the rope, `length1 + ratio * length2`.
+    pub fn with_length(self, length: Scalar) -> Self {
+        Self {
+            length: Some(length),
+            ..self
+        }
+    }
+}
+
+impl PositionConstraint for PulleyJoint {}
+
+impl AngularConstraint for PulleyJoint {}
+
+impl MapEntities for PulleyJoint {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        self.entity1 = entity_mapper.get_or_reserve(self.entity1);
+        self.entity2 = entity_mapper.get_or_reserve(self.entity2);
+    }
+}
@@ -0,0 +1,288 @@
+//! [`RackAndPinionJoint`] component.
+
+use crate::prelude::*;
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+/// A rack-and-pinion joint couples the rotation of a pinion to the translation of a rack,
+/// like a gear that moves a toothed bar.
+///
+/// The first body is the pinion and the second body is the rack. When the pinion rotates by an angle `θ`
+/// around its axis, the rack translates by `ratio * θ` along its axis relative to the pinion.
+/// The ratio is usually the radius of the pinion. Flipping the sign of the ratio or the rack axis
+/// reverses the direction of the coupling.
+///
+/// Rack-and-pinion joints only couple the motion of the bodies, so they are usually used together with
+/// [revolute](RevoluteJoint) and [prismatic](PrismaticJoint) joints. The coupling is applied to the motion
+/// of the bodies during each substep, so the joint doesn't correct drift that has already happened.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(MapEntities)]
+pub struct RackAndPinionJoint {
+    /// The pinion entity.
+    pub entity1: Entity,
+    /// The rack entity.
+    pub entity2: Entity,
+    /// Attachment point on the pinion.
+    pub local_anchor1: Vector,
+    /// Attachment point on the rack.
+    pub local_anchor2: Vector,
+    /// The axis of rotation of the pinion in its local space.
+    #[cfg(feature = "3d")]
+    pub pinion_axis: Vector,
+    /// The axis of translation of the rack in its local space.
+    pub rack_axis: Vector,
+    /// The distance that the rack translates for each radian of rotation of the pinion.
+    pub ratio: Scalar,
+    /// Linear damping applied by the joint.
+    pub damping_linear: Scalar,
+    /// Angular damping applied by the joint.
+    pub damping_angular: Scalar,
+    /// Lagrange multiplier for the correction.
+    pub lagrange: Scalar,
+    /// The joint's compliance, the inverse of stiffness, has the unit meters / Newton.
+    pub compliance: Scalar,
+    /// The force at which the joint breaks. See [`Joint::with_break_force`].
+    pub break_force: Scalar,
+    /// The torque at which the joint breaks. See [`Joint::with_break_torque`].
+    pub break_torque: Scalar,
+    /// Whether the bodies connected by the joint can collide with each other. See [`Joint::with_collide_connected`].
+    pub collide_connected: bool,
+    /// The force exerted by the joint on the rack.
+    pub force: Vector,
+    /// The torque exerted by the joint on the pinion.
+    pub torque: Torque,
+}
+
+impl XpbdConstraint<2> for RackAndPinionJoint {
+    fn entities(&self) -> [Entity; 2] {
+        [self.entity1, self.entity2]
+    }
+
+    fn clear_lagrange_multipliers(&mut self) {
+        self.lagrange = 0.0;
+    }
+
+    fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) {
+        (self.force, self.torque) = self.couple_motion(bodies, dt);
+    }
+}
+
+impl Joint for RackAndPinionJoint {
+    fn new(entity1: Entity, entity2: Entity) -> Self {
+        Self {
+            entity1,
+            entity2,
+            local_anchor1: Vector::ZERO,
+            local_anchor2: Vector::ZERO,
+            #[cfg(feature = "3d")]
+            pinion_axis: Vector::Z,
+            rack_axis: Vector::X,
+            ratio: 1.0,
+            damping_linear: 0.0,
+            damping_angular: 0.0,
+            lagrange: 0.0,
+            compliance: 0.0,
+            break_force: Scalar::INFINITY,
+            break_torque: Scalar::INFINITY,
+            collide_connected: false,
+            force: Vector::ZERO,
+            #[cfg(feature = "2d")]
+            torque: 0.0,
+            #[cfg(feature = "3d")]
+            torque: Vector::ZERO,
+        }
+    }
+
+    fn with_compliance(self, compliance: Scalar) -> Self {
+        Self { compliance, ..self }
+    }
+
+    fn with_local_anchor_1(self, anchor: Vector) -> Self {
+        Self {
+            local_anchor1: anchor,
+            ..self
+        }
+    }
+
+    fn with_local_anchor_2(self, anchor: Vector) -> Self {
+        Self {
+            local_anchor2: anchor,
+            ..self
+        }
+    }
+
+    fn with_linear_velocity_damping(self, damping: Scalar) -> Self {
+        Self {
+            damping_linear: damping,
+            ..self
+        }
+    }
+
+    fn with_angular_velocity_damping(self, damping: Scalar) -> Self {
+        Self {
+            damping_angular: damping,
+            ..self
+        }
+    }
+
+    fn local_anchor_1(&self) -> Vector {
+        self.local_anchor1
+    }
+
+    fn local_anchor_2(&self) -> Vector {
+        self.local_anchor2
+    }
+
+    fn damping_linear(&self) -> Scalar {
+        self.damping_linear
+    }
+
+    fn damping_angular(&self) -> Scalar {
+        self.damping_angular
+    }
+
+    fn with_break_force(self, force: Scalar) -> Self {
+        Self {
+            break_force: force,
+            ..self
+        }
+    }
+
+    fn with_break_torque(self, torque: Scalar) -> Self {
+        Self {
+            break_torque: torque,
+            ..self
+        }
+    }
+
+    fn break_force(&self) -> Scalar {
+        self.break_force
+    }
+
+    fn break_torque(&self) -> Scalar {
+        self.break_torque
+    }
+
+    fn with_collide_connected(self, collide_connected: bool) -> Self {
+        Self {
+            collide_connected,
+            ..self
+        }
+    }
+
+    fn collide_connected(&self) -> bool {
+        self.collide_connected
+    }
+
+    fn force_magnitude(&self) -> Scalar {
+        self.force.length()
+    }
+
+    fn torque_magnitude(&self) -> Scalar {
+        torque_length(self.torque)
+    }
+}
+
+impl RackAndPinionJoint {
+    /// Couples the rotation of the pinion and the translation of the rack during the current substep.
+    ///
+    /// Returns the force exerted on the rack and the torque exerted on the pinion.
+    fn couple_motion(
+        &mut self,
+        bodies: [&mut RigidBodyQueryItem; 2],
+        dt: Scalar,
+    ) -> (Vector, Torque) {
+        let [body1, body2] = bodies;
+
+        #[cfg(feature = "2d")]
+        let pinion_axis = Vector3::Z;
+        #[cfg(feature = "3d")]
+        let pinion_axis = body1.rotation.rotate(self.pinion_axis);
+        let rack_axis = body2.rotation.rotate(self.rack_axis);
+
+        // The constraint is satisfied when the rack has translated by ratio * Δθ relative to the pinion
+        let translation =
+            (body2.accumulated_translation.0 - body1.accumulated_translation.0).dot(rack_axis);
+        let angle = rotation_during_substep(body1, pinion_axis);
+        let c = translation - self.ratio * angle;
+
+        let inv_mass1 = body1.effective_inv_mass();
+        let inv_mass2 = body2.effective_inv_mass();
+        let w_lin1 = if body1.rb.is_dynamic() {
+            rack_axis.dot(inv_mass1 * rack_axis)
+        } else {
+            0.0
+        };
+        let w_lin2 = if body2.rb.is_dynamic() {
+            rack_axis.dot(inv_mass2 * rack_axis)
+        } else {
+            0.0
+        };
+        let w_ang1 = AngularConstraint::compute_generalized_inverse_mass(self, body1, pinion_axis);
+
+        let delta_lagrange = self.compute_lagrange_update(
+            self.lagrange,
+            c,
+            &[rack_axis, rack_axis, Vector::X * self.ratio],
+            &[w_lin1, w_lin2, w_ang1],
+            self.compliance,
+            dt,
+        );
+        self.lagrange += delta_lagrange;
+
+        if delta_lagrange.abs() > Scalar::EPSILON {
+            let p = delta_lagrange * rack_axis;
+            if body1.rb.is_dynamic() {
+                body1.accumulated_translation.0 -= p * inv_mass1;
+            }
+            if body2.rb.is_dynamic() {
+                body2.accumulated_translation.0 += p * inv_mass2;
+            }
+        }
+        apply_angular_impulse(body1, -delta_lagrange * self.ratio, pinion_axis);
+
+        (
+            self.compute_force(self.lagrange, rack_axis, dt),
+            self.compute_torque(-self.lagrange * self.ratio, pinion_axis, dt),
+        )
+    }
+
+    /// Sets the axis of rotation of the pinion in its local space.
+    #[cfg(feature = "3d")]
+    pub fn with_pinion_axis(self, axis: Vector) -> Self {
+        Self {
+            pinion_axis: axis,
+            ..self
+        }
+    }
+
+    /// Sets the axis of translation of the rack in its local space.
+    pub fn with_rack_axis(self, axis: Vector) -> Self {
+        Self {
+            rack_axis: axis,
+            ..self
+        }
+    }
+
+    /// Sets the distance that the rack translates for each radian of rotation of the pinion.
+    pub fn with_ratio(self, ratio: Scalar) -> Self {
+        Self { ratio, ..self }
+    }
+}
+
+impl PositionConstraint for RackAndPinionJoint {}
+
+impl AngularConstraint for RackAndPinionJoint {}
+
+impl MapEntities for RackAndPinionJoint {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        self.entity1 = entity_mapper.get_or_reserve(self.entity1);
+        self.entity2 = entity_mapper.get_or_reserve(self.entity2);
+    }
+}
@@ -9,6 +9,8 @@ use bevy::{
 /// A revolute joint prevents relative movement of the attached bodies, except for rotation around one `aligned_axis`.
 ///
 /// Revolute joints can be useful for things like wheels, fans, revolving doors etc.
+///
+/// The rotation around the `aligned_axis` can be driven by a [`JointMotor`] set using [`RevoluteJoint::with_motor`].
 #[derive(Component, Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct RevoluteJoint {
@@ -30,6 +32,8 @@ pub struct RevoluteJoint {
     pub aligned_axis: Vector,
     /// The extents of the allowed relative rotation of the bodies around the `aligned_axis`.
     pub angle_limit: Option<AngleLimit>,
+    /// The motor that drives the relative rotation of the bodies around the `aligned_axis`.
+    pub motor: Option<JointMotor>,
     /// Linear damping applied by the joint.
     pub damping_linear: Scalar,
     /// Angular damping applied by the joint.
@@ -40,14 +44,26 @@ pub struct RevoluteJoint {
     pub align_lagrange: Scalar,
     /// Lagrange multiplier for the angular correction caused by the angle limits.
     pub angle_limit_lagrange: Scalar,
+    /// Lagrange multiplier for the angular correction caused by the motor.
+    pub motor_lagrange: Scalar,
     /// The joint's compliance, the inverse of stiffness, has the unit meters / Newton.
     pub compliance: Scalar,
+    /// The spring that makes the positional part of the joint soft. If set, the `compliance` is not used for it.
+    pub spring: Option<JointSpring>,
+    /// The force at which the joint breaks. See [`Joint::with_break_force`].
+    pub break_force: Scalar,
+    /// The torque at which the joint breaks. See [`Joint::with_break_torque`].
+    pub break_torque: Scalar,
+    /// Whether the bodies connected by the joint can collide with each other. See [`Joint::with_collide_connected`].
+    pub collide_connected: bool,
     /// The force exerted by the joint.
     pub force: Vector,
     /// The torque exerted by the joint when aligning the bodies.
     pub align_torque: Torque,
     /// The torque exerted by the joint when limiting the relative rotation of the bodies around the `aligned_axis`.
     pub angle_limit_torque: Torque,
+    /// The torque exerted by the motor around the `aligned_axis`.
+    pub motor_torque: Torque,
 }
 
 impl XpbdConstraint<2> for RevoluteJoint {
@@ -59,6 +75,7 @@ impl XpbdConstraint<2> for RevoluteJoint {
         self.position_lagrange = 0.0;
         self.align_lagrange = 0.0;
         self.angle_limit_lagrange = 0.0;
+        self.motor_lagrange = 0.0;
     }
 
     fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) {
@@ -73,17 +90,31 @@ impl XpbdConstraint<2> for RevoluteJoint {
 
         // Align positions
         let mut lagrange = self.position_lagrange;
-        self.force = self.align_position(
-            body1,
-            body2,
-            self.local_anchor1,
-            self.local_anchor2,
-            &mut lagrange,
-            compliance,
-            dt,
-        );
+        self.force = match self.spring {
+            Some(spring) => self.align_position_with_spring(
+                body1,
+                body2,
+                self.local_anchor1,
+                self.local_anchor2,
+                &mut lagrange,
+                spring,
+                dt,
+            ),
+            None => self.align_position(
+                body1,
+                body2,
+                self.local_anchor1,
+                self.local_anchor2,
+                &mut lagrange,
+                compliance,
+                dt,
+            ),
+        };
         self.position_lagrange = lagrange;
 
+        // Drive the rotation around the free axis before the limits so that the limits take priority
+        self.motor_torque = self.apply_motor(body1, body2, dt);
+
         // Apply angle limits when rotating around the free axis
         self.angle_limit_torque = self.apply_angle_limits(body1, body2, dt);
     }
@@ -98,12 +129,18 @@ impl Joint for RevoluteJoint {
             local_anchor2: Vector::ZERO,
             aligned_axis: Vector3::Z,
             angle_limit: None,
+            motor: None,
             damping_linear: 1.0,
             damping_angular: 1.0,
             position_lagrange: 0.0,
             align_lagrange: 0.0,
             angle_limit_lagrange: 0.0,
+            motor_lagrange: 0.0,
             compliance: 0.0,
+            spring: None,
+            break_force: Scalar::INFINITY,
+            break_torque: Scalar::INFINITY,
+            collide_connected: false,
             force: Vector::ZERO,
             #[cfg(feature = "2d")]
             align_torque: 0.0,
@@ -113,6 +150,10 @@ impl Joint for RevoluteJoint {
             angle_limit_torque: 0.0,
             #[cfg(feature = "3d")]
             angle_limit_torque: Vector::ZERO,
+            #[cfg(feature = "2d")]
+            motor_torque: 0.0,
+            #[cfg(feature = "3d")]
+            motor_torque: Vector::ZERO,
         }
     }
 
@@ -163,9 +204,58 @@ impl Joint for RevoluteJoint {
     fn damping_angular(&self) -> Scalar {
         self.damping_angular
     }
+
+    fn with_break_force(self, force: Scalar) -> Self {
+        Self {
+            break_force: force,
+            ..self
+        }
+    }
+
+    fn with_break_torque(self, torque: Scalar) -> Self {
+        Self {
+            break_torque: torque,
+            ..self
+        }
+    }
+
+    fn break_force(&self) -> Scalar {
+        self.break_force
+    }
+
+    fn break_torque(&self) -> Scalar {
+        self.break_torque
+    }
+
+    fn with_collide_connected(self, collide_connected: bool) -> Self {
+        Self {
+            collide_connected,
+            ..self
+        }
+    }
+
+    fn collide_connected(&self) -> bool {
+        self.collide_connected
+    }
+
+    fn force_magnitude(&self) -> Scalar {
+        self.force.length()
+    }
+
+    fn torque_magnitude(&self) -> Scalar {
+        torque_length(self.align_torque + self.angle_limit_torque + self.motor_torque)
+    }
 }
 
 impl RevoluteJoint {
+    /// Sets the spring that makes the positional part of the joint soft.
+    pub fn with_spring(self, spring: JointSpring) -> Self {
+        Self {
+            spring: Some(spring),
+            ..self
+        }
+    }
+
     /// Sets the axis that the bodies should be aligned on.
     #[cfg(feature = "3d")]
     pub fn with_aligned_axis(self, axis: Vector) -> Self {
@@ -183,6 +273,30 @@ impl RevoluteJoint {
         }
     }
 
+    /// Sets the motor that drives the relative rotation around the `aligned_axis`.
+    pub fn with_motor(self, motor: JointMotor) -> Self {
+        Self {
+            motor: Some(motor),
+            ..self
+        }
+    }
+
+    /// Returns the angle of the second body relative to the first body around the `aligned_axis`.
+    #[cfg(feature = "2d")]
+    pub fn relative_angle(&self, rot1: &Rotation, rot2: &Rotation) -> Scalar {
+        (*rot2 - *rot1).as_radians()
+    }
+
+    /// Returns the angle of the second body relative to the first body around the `aligned_axis`.
+    #[cfg(feature = "3d")]
+    pub fn relative_angle(&self, rot1: &Rotation, rot2: &Rotation) -> Scalar {
+        let reference_axis = self.aligned_axis.any_orthonormal_vector();
+        let n = rot1.rotate(self.aligned_axis);
+        let b1 = rot1.rotate(reference_axis);
+        let b2 = rot2.rotate(reference_axis);
+        b1.cross(b2).dot(n).atan2(b1.dot(b2))
+    }
+
     fn get_delta_q(&self, rot1: &Rotation, rot2: &Rotation) -> Vector3 {
         let a1 = rot1.rotate_vec3(self.aligned_axis);
         let a2 = rot2.rotate_vec3(self.aligned_axis);
@@ -198,11 +312,7 @@ impl RevoluteJoint {
         dt: Scalar,
     ) -> Torque {
         if let Some(angle_limit) = self.angle_limit {
-            let limit_axis = Vector3::new(
-                self.aligned_axis.z,
-                self.aligned_axis.x,
-                self.aligned_axis.y,
-            );
+            let limit_axis = self.aligned_axis.any_orthonormal_vector();
             let a1 = body1.rotation.rotate_vec3(limit_axis);
             let a2 = body2.rotation.rotate_vec3(limit_axis);
             let n = a1.cross(a2).normalize();
@@ -217,6 +327,40 @@ impl RevoluteJoint {
         }
         Torque::ZERO
     }
+
+    /// Applies the [`JointMotor`] to drive the relative rotation of the bodies around the `aligned_axis`.
+    fn apply_motor(
+        &mut self,
+        body1: &mut RigidBodyQueryItem,
+        body2: &mut RigidBodyQueryItem,
+        dt: Scalar,
+    ) -> Torque {
+        let Some(motor) = self.motor else {
+            return Torque::ZERO;
+        };
+
+        let axis = body1.rotation.rotate_vec3(self.aligned_axis);
+        let w1 = AngularConstraint::compute_generalized_inverse_mass(self, body1, axis);
+        let w2 = AngularConstraint::compute_generalized_inverse_mass(self, body2, axis);
+
+        let angle = self.relative_angle(&body1.rotation, &body2.rotation);
+        let previous_angle =
+            self.relative_angle(&body1.previous_rotation.0, &body2.previous_rotation.0);
+
+        let delta_lagrange = motor.compute_lagrange_update(
+            self.motor_lagrange,
+            angle,
+            previous_angle,
+            w1 + w2,
+            true,
+            dt,
+        );
+        self.motor_lagrange += delta_lagrange;
+
+        self.apply_angular_correction(body1, body2, delta_lagrange, axis);
+
+        self.compute_torque(self.motor_lagrange, axis, dt)
+    }
 }
 
 impl PositionConstraint for RevoluteJoint {}
@@ -9,6 +9,23 @@ use bevy::{
 /// A spherical joint prevents relative translation of the attached bodies while allowing rotation around all axes.
 ///
 /// Spherical joints can be useful for things like pendula, chains, ragdolls etc.
+///
+/// ## Limits
+///
+/// - [`with_swing_limits`](SphericalJoint::with_swing_limits) limits the relative rotation around the `swing_axis`.
+#[cfg_attr(
+    feature = "3d",
+    doc = "- [`with_swing_cone`](SphericalJoint::with_swing_cone) limits the tilt of the `twist_axis` to an elliptical [`SwingCone`] with separate limits around the `swing_axis` and the axis perpendicular to the swing and twist axes, for example for the limbs of a ragdoll."
+)]
+#[cfg_attr(
+    feature = "3d",
+    doc = "- [`with_twist_limits`](SphericalJoint::with_twist_limits) limits the twist around the `twist_axis`."
+)]
+///
+/// In 2D, the swing limits limit the relative rotation of the bodies.
+///
+/// By default, the limits use the same compliance as the rest of the joint, but they can be made softer using
+/// [`with_limit_compliance`](SphericalJoint::with_limit_compliance).
 #[derive(Component, Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct SphericalJoint {
@@ -26,8 +43,13 @@ pub struct SphericalJoint {
     pub twist_axis: Vector3,
     /// The extents of the allowed relative rotation of the bodies around the `swing_axis`.
     pub swing_limit: Option<AngleLimit>,
+    /// The elliptical cone that limits the swing of the `twist_axis`.
+    #[cfg(feature = "3d")]
+    pub swing_cone: Option<SwingCone>,
     /// The extents of the allowed relative rotation of the bodies around the `twist_axis`.
     pub twist_limit: Option<AngleLimit>,
+    /// The compliance of the swing and twist limits. If `None`, the joint's `compliance` is used.
+    pub limit_compliance: Option<Scalar>,
     /// Linear damping applied by the joint.
     pub damping_linear: Scalar,
     /// Angular damping applied by the joint.
@@ -36,14 +58,28 @@ pub struct SphericalJoint {
     pub position_lagrange: Scalar,
     /// Lagrange multiplier for the angular correction caused by the swing limits.
     pub swing_lagrange: Scalar,
+    /// Lagrange multiplier for the angular correction caused by the swing cone.
+    #[cfg(feature = "3d")]
+    pub swing_cone_lagrange: Scalar,
     /// Lagrange multiplier for the angular correction caused by the twist limits.
     pub twist_lagrange: Scalar,
     /// The joint's compliance, the inverse of stiffness, has the unit meters / Newton.
     pub compliance: Scalar,
+    /// The spring that makes the positional part of the joint soft. If set, the `compliance` is not used for it.
+    pub spring: Option<JointSpring>,
+    /// The force at which the joint breaks. See [`Joint::with_break_force`].
+    pub break_force: Scalar,
+    /// The torque at which the joint breaks. See [`Joint::with_break_torque`].
+    pub break_torque: Scalar,
+    /// Whether the bodies connected by the joint can collide with each other. See [`Joint::with_collide_connected`].
+    pub collide_connected: bool,
     /// The force exerted by the joint.
     pub force: Vector,
     /// The torque exerted by the joint when limiting the relative rotation of the bodies around the `swing_axis`.
     pub swing_torque: Torque,
+    /// The torque exerted by the joint when limiting the swing of the bodies to the swing cone.
+    #[cfg(feature = "3d")]
+    pub swing_cone_torque: Torque,
     /// The torque exerted by the joint when limiting the relative rotation of the bodies around the `twist_axis`.
     pub twist_torque: Torque,
 }
@@ -56,6 +92,10 @@ impl XpbdConstraint<2> for SphericalJoint {
     fn clear_lagrange_multipliers(&mut self) {
         self.position_lagrange = 0.0;
         self.swing_lagrange = 0.0;
+        #[cfg(feature = "3d")]
+        {
+            self.swing_cone_lagrange = 0.0;
+        }
         self.twist_lagrange = 0.0;
     }
 
@@ -65,20 +105,37 @@ impl XpbdConstraint<2> for SphericalJoint {
 
         // Align positions
         let mut lagrange = self.position_lagrange;
-        self.force = self.align_position(
-            body1,
-            body2,
-            self.local_anchor1,
-            self.local_anchor2,
-            &mut lagrange,
-            compliance,
-            dt,
-        );
+        self.force = match self.spring {
+            Some(spring) => self.align_position_with_spring(
+                body1,
+                body2,
+                self.local_anchor1,
+                self.local_anchor2,
+                &mut lagrange,
+                spring,
+                dt,
+            ),
+            None => self.align_position(
+                body1,
+                body2,
+                self.local_anchor1,
+                self.local_anchor2,
+                &mut lagrange,
+                compliance,
+                dt,
+            ),
+        };
         self.position_lagrange = lagrange;
 
         // Apply swing limits
         self.swing_torque = self.apply_swing_limits(body1, body2, dt);
 
+        // Apply the swing cone
+        #[cfg(feature = "3d")]
+        {
+            self.swing_cone_torque = self.apply_swing_cone(body1, body2, dt);
+        }
+
         // Apply twist limits
         self.twist_torque = self.apply_twist_limits(body1, body2, dt);
     }
@@ -94,18 +151,29 @@ impl Joint for SphericalJoint {
             swing_axis: Vector3::X,
             twist_axis: Vector3::Y,
             swing_limit: None,
+            #[cfg(feature = "3d")]
+            swing_cone: None,
             twist_limit: None,
+            limit_compliance: None,
             damping_linear: 1.0,
             damping_angular: 1.0,
             position_lagrange: 0.0,
             swing_lagrange: 0.0,
+            #[cfg(feature = "3d")]
+            swing_cone_lagrange: 0.0,
             twist_lagrange: 0.0,
             compliance: 0.0,
+            spring: None,
+            break_force: Scalar::INFINITY,
+            break_torque: Scalar::INFINITY,
+            collide_connected: false,
             force: Vector::ZERO,
             #[cfg(feature = "2d")]
             swing_torque: 0.0,
             #[cfg(feature = "3d")]
             swing_torque: Vector::ZERO,
+            #[cfg(feature = "3d")]
+            swing_cone_torque: Vector::ZERO,
             #[cfg(feature = "2d")]
             twist_torque: 0.0,
             #[cfg(feature = "3d")]
@@ -160,9 +228,65 @@ impl Joint for SphericalJoint {
     fn damping_angular(&self) -> Scalar {
         self.damping_angular
     }
+
+    fn with_break_force(self, force: Scalar) -> Self {
+        Self {
+            break_force: force,
+            ..self
+        }
+    }
+
+    fn with_break_torque(self, torque: Scalar) -> Self {
+        Self {
+            break_torque: torque,
+            ..self
+        }
+    }
+
+    fn break_force(&self) -> Scalar {
+        self.break_force
+    }
+
+    fn break_torque(&self) -> Scalar {
+        self.break_torque
+    }
+
+    fn with_collide_connected(self, collide_connected: bool) -> Self {
+        Self {
+            collide_connected,
+            ..self
+        }
+    }
+
+    fn collide_connected(&self) -> bool {
+        self.collide_connected
+    }
+
+    fn force_magnitude(&self) -> Scalar {
+        self.force.length()
+    }
+
+    fn torque_magnitude(&self) -> Scalar {
+        #[cfg(feature = "2d")]
+        {
+            torque_length(self.swing_torque + self.twist_torque)
+        }
+        #[cfg(feature = "3d")]
+        {
+            torque_length(self.swing_torque + self.swing_cone_torque + self.twist_torque)
+        }
+    }
 }
 
 impl SphericalJoint {
+    /// Sets the spring that makes the positional part of the joint soft.
+    pub fn with_spring(self, spring: JointSpring) -> Self {
+        Self {
+            spring: Some(spring),
+            ..self
+        }
+    }
+
     /// Sets the limits of the allowed relative rotation around the `swing_axis`.
     pub fn with_swing_limits(self, min: Scalar, max: Scalar) -> Self {
         Self {
@@ -171,6 +295,35 @@ impl SphericalJoint {
         }
     }
 
+    /// Limits the swing of the `twist_axis` to an elliptical cone with the given maximum angles
+    /// around the `swing_axis` and around the axis perpendicular to the swing and twist axes.
+    #[cfg(feature = "3d")]
+    pub fn with_swing_cone(
+        self,
+        swing_axis_angle: Scalar,
+        perpendicular_axis_angle: Scalar,
+    ) -> Self {
+        Self {
+            swing_cone: Some(SwingCone::new(swing_axis_angle, perpendicular_axis_angle)),
+            ..self
+        }
+    }
+
+    /// Sets the compliance of the swing and twist limits (inverse of stiffness).
+    ///
+    /// Soft limits allow the joint to exceed its limits and push it back like a spring.
+    pub fn with_limit_compliance(self, compliance: Scalar) -> Self {
+        Self {
+            limit_compliance: Some(compliance),
+            ..self
+        }
+    }
+
+    /// Returns the compliance used by the swing and twist limits.
+    fn effective_limit_compliance(&self) -> Scalar {
+        self.limit_compliance.unwrap_or(self.compliance)
+    }
+
     /// Sets the limits of the allowed relative rotation around the `twist_axis`.
     #[cfg(feature = "3d")]
     pub fn with_twist_limits(self, min: Scalar, max: Scalar) -> Self {
@@ -202,8 +355,14 @@ impl SphericalJoint {
 
             if let Some(dq) = joint_limit.compute_correction(n, a1, a2, PI) {
                 let mut lagrange = self.swing_lagrange;
-                let torque =
-                    self.align_orientation(body1, body2, dq, &mut lagrange, self.compliance, dt);
+                let torque = self.align_orientation(
+                    body1,
+                    body2,
+                    dq,
+                    &mut lagrange,
+                    self.effective_limit_compliance(),
+                    dt,
+                );
                 self.swing_lagrange = lagrange;
                 return torque;
             }
@@ -250,14 +409,116 @@ impl SphericalJoint {
 
             if let Some(dq) = joint_limit.compute_correction(n, n1, n2, max_correction) {
                 let mut lagrange = self.twist_lagrange;
-                let torque =
-                    self.align_orientation(body1, body2, dq, &mut lagrange, self.compliance, dt);
+                let torque = self.align_orientation(
+                    body1,
+                    body2,
+                    dq,
+                    &mut lagrange,
+                    self.effective_limit_compliance(),
+                    dt,
+                );
                 self.twist_lagrange = lagrange;
                 return torque;
             }
         }
         Torque::ZERO
     }
+
+    /// Limits the swing of the `twist_axis` to the [`SwingCone`].
+    #[cfg(feature = "3d")]
+    fn apply_swing_cone(
+        &mut self,
+        body1: &mut RigidBodyQueryItem,
+        body2: &mut RigidBodyQueryItem,
+        dt: Scalar,
+    ) -> Torque {
+        let Some(cone) = self.swing_cone else {
+            return Torque::ZERO;
+        };
+
+        let a1 = body1.rotation.rotate(self.twist_axis);
+        let a2 = body2.rotation.rotate(self.twist_axis);
+
+        let n = a1.cross(a2);
+        let n_magnitude = n.length();
+
+        if n_magnitude <= Scalar::EPSILON {
+            return Torque::ZERO;
+        }
+
+        let n = n / n_magnitude;
+        let angle = n_magnitude.atan2(a1.dot(a2));
+
+        // The swing direction in the local space of the first body
+        let local_n = body1.rotation.inverse().rotate(n);
+        let perpendicular_axis = self.twist_axis.cross(self.swing_axis);
+        let max_angle = cone.max_angle(
+            local_n.dot(self.swing_axis),
+            local_n.dot(perpendicular_axis),
+        );
+
+        if angle <= max_angle {
+            return Torque::ZERO;
+        }
+
+        let dq = n * (angle - max_angle);
+        let mut lagrange = self.swing_cone_lagrange;
+        let torque = self.align_orientation(
+            body1,
+            body2,
+            dq,
+            &mut lagrange,
+            self.effective_limit_compliance(),
+            dt,
+        );
+        self.swing_cone_lagrange = lagrange;
+        torque
+    }
+}
+
+/// An elliptical cone that limits the swing of a [`SphericalJoint`].
+///
+/// The cone is centered on the `twist_axis` of the joint. Its extents are given by the maximum swing angles
+/// around the `swing_axis` and around the axis perpendicular to the swing and twist axes. When the angles
+/// are equal, the cone is circular.
+#[cfg(feature = "3d")]
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwingCone {
+    /// The maximum swing angle around the `swing_axis`, in radians.
+    pub swing_axis_angle: Scalar,
+    /// The maximum swing angle around the axis perpendicular to the swing and twist axes, in radians.
+    pub perpendicular_axis_angle: Scalar,
+}
+
+#[cfg(feature = "3d")]
+impl SwingCone {
+    /// Creates a new `SwingCone` with the given maximum swing angles.
+    pub fn new(swing_axis_angle: Scalar, perpendicular_axis_angle: Scalar) -> Self {
+        Self {
+            swing_axis_angle,
+            perpendicular_axis_angle,
+        }
+    }
+
+    /// Returns the maximum swing angle for a swing around an axis with the given components
+    /// along the `swing_axis` and the perpendicular axis.
+    pub fn max_angle(
+        &self,
+        swing_axis_component: Scalar,
+        perpendicular_axis_component: Scalar,
+    ) -> Scalar {
+        let x = swing_axis_component / self.swing_axis_angle.max(Scalar::EPSILON);
+        let y = perpendicular_axis_component / self.perpendicular_axis_angle.max(Scalar::EPSILON);
+        let length = (x * x + y * y).sqrt();
+
+        if length <= Scalar::EPSILON {
+            return PI;
+        }
+
+        // The swing is limited to an ellipse in the space of rotation vectors
+        (1.0 / length).min(PI)
+    }
 }
 
 impl PositionConstraint for SphericalJoint {}
@@ -21,6 +21,15 @@
 //!     - [`SphericalJoint`]
 //!     - [`RevoluteJoint`]
 //!     - [`PrismaticJoint`]
+//!     - [`GenericJoint`]
+//!     - [`GearJoint`]
+//!     - [`RackAndPinionJoint`]
+//!     - [`PulleyJoint`]
+#![cfg_attr(feature = "3d", doc = "- Soft bodies")]
+#![cfg_attr(feature = "3d", doc = "    - [`EdgeConstraint`]")]
+#![cfg_attr(feature = "3d", doc = "    - [`VolumeConstraint`]")]
+#![cfg_attr(feature = "3d", doc = "    - [`IsometricBendingConstraint`]")]
+#![cfg_attr(feature = "3d", doc = "    - [`DihedralBendingConstraint`]")]
 //!
 //! More constraint types will be added in future releases. If you need more constraints now, consider
 //! [creating your own constraints](#custom-constraints).
@@ -88,6 +97,10 @@
 //! Now just spawn an instance of the constraint, give it the participating entities, and the constraint should be getting
 //! solved automatically according to the `solve` method!
 //!
+//! To attach a constraint to a fixed point in space instead of a body, for example to pin a body to a point or a path,
+//! you can use the entity stored in the [`WorldBodyEntity`] resource as one of the entities.
+//! It is a static body at the origin, so local anchors on it are world-space points.
+//!
 //! You can find a working example of a custom constraint
 //! [here](https://github.com/Jondolf/bevy_xpbd/blob/main/crates/bevy_xpbd_3d/examples/custom_constraint.rs).
 //!
@@ -200,6 +213,10 @@
 //! where `q_i` is the [rotation](Rotation) of body `i` and `r_i` is a vector pointing from the body's center of mass to some
 //! attachment position.
 
+#[cfg(feature = "3d")]
+pub mod bending;
+#[cfg(feature = "3d")]
+pub mod dihedral_bending;
 #[cfg(feature = "3d")]
 pub mod edge;
 #[cfg(feature = "3d")]
@@ -214,6 +231,10 @@ mod position_constraint;
 
 pub use angular_constraint::AngularConstraint;
 #[cfg(feature = "3d")]
+pub use bending::*;
+#[cfg(feature = "3d")]
+pub use dihedral_bending::*;
+#[cfg(feature = "3d")]
 pub use edge::*;
 #[cfg(feature = "3d")]
 pub use isometric_bending::*;
@@ -287,6 +308,56 @@ pub trait XpbdConstraint<const ENTITY_COUNT: usize>: MapEntities {
         (-c - tilde_compliance * lagrange) / (w_sum + tilde_compliance)
     }
 
+    /// Computes how much a constraint's [Lagrange multiplier](constraints#lagrange-multipliers) changes
+    /// like [`compute_lagrange_update`](XpbdConstraint::compute_lagrange_update), but also damps the motion
+    /// of the particles along the [gradients](constraints#constraint-gradients).
+    ///
+    /// Each particle should have a corresponding displacement in `displacements`, which is how much
+    /// the particle has moved during the current substep. `damping` is the damping coefficient of the constraint.
+    ///
+    /// This uses the damped XPBD formulation, where the damping is scaled by the compliance.
+    /// This means that damping has no effect on constraints with zero compliance.
+    #[allow(clippy::too_many_arguments)]
+    fn compute_damped_lagrange_update(
+        &self,
+        lagrange: Scalar,
+        c: Scalar,
+        gradients: &[Vector],
+        inverse_masses: &[Scalar],
+        displacements: &[Vector],
+        compliance: Scalar,
+        damping: Scalar,
+        dt: Scalar,
+    ) -> Scalar {
+        // Compute the sum of all inverse masses multiplied by the squared lengths of the corresponding gradients.
+        let w_sum = inverse_masses
+            .iter()
+            .enumerate()
+            .fold(0.0, |acc, (i, w)| acc + *w * gradients[i].length_squared());
+
+        // Avoid division by zero
+        if w_sum <= Scalar::EPSILON {
+            return 0.0;
+        }
+
+        // tilde_a = a/h^2
+        let tilde_compliance = compliance / dt.powi(2);
+
+        // gamma = a * b / h
+        let gamma = compliance * damping / dt;
+
+        // How fast the constraint is changing, multiplied by the time step
+        let c_change = gradients
+            .iter()
+            .zip(displacements)
+            .fold(0.0, |acc, (gradient, displacement)| {
+                acc + gradient.dot(*displacement)
+            });
+
+        (-c - tilde_compliance * lagrange - gamma * c_change)
+            / ((1.0 + gamma) * w_sum + tilde_compliance)
+    }
+
     /// Sets the constraint's [Lagrange multipliers](constraints#lagrange-multipliers) to 0.
     fn clear_lagrange_multipliers(&mut self);
 }
@@ -16,6 +16,12 @@ pub struct PenetrationConstraint {
     pub entity1: Entity,
     /// Second entity in the constraint.
     pub entity2: Entity,
+    /// The collider entity attached to the first body. This is the same as `entity1` for colliders
+    /// that aren't children of the body.
+    pub collider_entity1: Entity,
+    /// The collider entity attached to the second body. This is the same as `entity2` for colliders
+    /// that aren't children of the body.
+    pub collider_entity2: Entity,
     /// Data associated with the contact.
     pub contact: ContactData,
     /// Vector from the first entity's center of mass to the contact point in local coordinates.
@@ -34,10 +40,21 @@ pub struct PenetrationConstraint {
     pub static_friction_coefficient: Scalar,
     /// The coefficient of [restitution](Restitution) in this contact.
     pub restitution_coefficient: Scalar,
+    /// The coefficient of [rolling friction](RollingFriction) in this contact.
+    pub rolling_friction_coefficient: Scalar,
+    /// The coefficient of [torsional friction](TorsionalFriction) in this contact.
+    #[cfg(feature = "3d")]
+    pub torsional_friction_coefficient: Scalar,
     /// Normal force acting along the constraint.
     pub normal_force: Vector,
     /// Static friction force acting along this constraint.
     pub static_friction_force: Vector,
+    /// The world-space [surface velocity](SurfaceVelocity) of the first collider at the contact point
+    /// relative to the surface velocity of the second collider.
+    pub surface_velocity: Vector,
+    /// Whether static friction kept the [contact anchors](ContactData::anchor1) together during the substep.
+    /// If `false`, the contact is sliding and the anchors are reset.
+    pub sticking: bool,
 }
 
 impl XpbdConstraint<2> for PenetrationConstraint {
@@ -81,6 +98,8 @@ impl PenetrationConstraint {
         Self {
             entity1: body1.entity,
             entity2: body2.entity,
+            collider_entity1: body1.entity,
+            collider_entity2: body2.entity,
             contact,
             r1,
             r2,
@@ -90,8 +109,13 @@ impl PenetrationConstraint {
             dynamic_friction_coefficient: 0.0,
             static_friction_coefficient: 0.0,
             restitution_coefficient: 0.0,
+            rolling_friction_coefficient: 0.0,
+            #[cfg(feature = "3d")]
+            torsional_friction_coefficient: 0.0,
             normal_force: Vector::ZERO,
             static_friction_force: Vector::ZERO,
+            surface_velocity: Vector::ZERO,
+            sticking: false,
         }
     }
 
@@ -139,24 +163,21 @@ impl PenetrationConstraint {
         // Shorter aliases
         let compliance = self.compliance;
         let lagrange = self.tangent_lagrange;
-        let penetration = self.contact.penetration;
         let normal = self.contact.global_normal1(&body1.rotation);
         let r1 = body1.rotation.rotate(self.r1);
         let r2 = body2.rotation.rotate(self.r2);
 
-        // Compute relative motion of the contact points and get the tangential component
-        let delta_p1 = body1.current_position() - body1.previous_position.0
-            + body1.rotation.rotate(self.contact.point1)
-            - body1.previous_rotation.rotate(self.contact.point1);
-        let delta_p2 = body2.current_position() - body2.previous_position.0
-            + body2.rotation.rotate(self.contact.point2)
-            - body2.previous_rotation.rotate(self.contact.point2);
-        let delta_p = delta_p1 - delta_p2;
+        // Compute relative motion of the static friction anchors and get the tangential component.
+        // The anchors persist while the contact is sticking, so drift doesn't accumulate over substeps.
+        let p1 = body1.current_position() + body1.rotation.rotate(self.contact.anchor1);
+        let p2 = body2.current_position() + body2.rotation.rotate(self.contact.anchor2);
+        let delta_p = p1 - p2;
         let delta_p_tangent = delta_p - delta_p.dot(normal) * normal;
 
         // Compute magnitude of relative tangential movement and get normalized tangent vector
         let sliding_len = delta_p_tangent.length();
         if sliding_len <= Scalar::EPSILON {
+            self.sticking = true;
             return;
         }
         let tangent = delta_p_tangent / sliding_len;
@@ -169,12 +190,20 @@ impl PenetrationConstraint {
         let gradients = [tangent, -tangent];
         let w = [w1, w2];
 
-        // Apply static friction if |delta_x_perp| < mu_s * d
-        if sliding_len < self.static_friction_coefficient * penetration {
-            // Compute Lagrange multiplier update for static friction
-            let delta_lagrange =
-                self.compute_lagrange_update(lagrange, sliding_len, &gradients, &w, compliance, dt);
+        // Compute Lagrange multiplier update for static friction
+        let delta_lagrange =
+            self.compute_lagrange_update(lagrange, sliding_len, &gradients, &w, compliance, dt);
+
+        // Apply static friction if |lambda_t| < mu_s * lambda_n.
+        // The normal Lagrange multiplier starts from zero every substep, and resting contacts barely
+        // need a normal correction, so the multiplier from the previous substep bounds it from below.
+        let normal_lagrange = self
+            .normal_lagrange
+            .abs()
+            .max(self.contact.normal_lagrange.abs());
+        if (lagrange + delta_lagrange).abs() < self.static_friction_coefficient * normal_lagrange {
             self.tangent_lagrange += delta_lagrange;
+            self.sticking = true;
 
             // Apply positional correction to handle static friction
             self.apply_positional_correction(body1, body2, delta_lagrange, tangent, r1, r2);
@@ -191,5 +220,7 @@ impl MapEntities for PenetrationConstraint {
     fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
         self.entity1 = entity_mapper.get_or_reserve(self.entity1);
         self.entity2 = entity_mapper.get_or_reserve(self.entity2);
+        self.collider_entity1 = entity_mapper.get_or_reserve(self.collider_entity1);
+        self.collider_entity2 = entity_mapper.get_or_reserve(self.collider_entity2);
     }
 }
@@ -8,7 +8,6 @@ use bevy::{
     },
     prelude::*,
 };
-use itertools::Itertools;
 
 /// A compliance of 0.0 resembles a constraint with infinite stiffness, so the bodies should not have any overlap.
 #[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
@@ -27,6 +26,8 @@ pub struct VolumeConstraint {
     pub rest_volume: Scalar,
     /// The constraint's compliance, the inverse of stiffness, has the unit meters / Newton.
     pub compliance: Scalar,
+    /// The constraint's damping coefficient. It damps the rate at which the volume changes.
+    pub damping: Scalar,
 }
 impl XpbdConstraint<4> for VolumeConstraint {
     fn entities(&self) -> [Entity; 4] {
@@ -40,85 +41,38 @@ impl XpbdConstraint<4> for VolumeConstraint {
 
     /// Change the particle's position to satisfy the constraint.
     fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 4], dt: Scalar) {
-        let alpha = self.compliance / (dt * dt);
-        let p1 = bodies[0].current_position();
-        let p2 = bodies[1].current_position();
-        let p3 = bodies[2].current_position();
-        let p4 = bodies[3].current_position();
-        let mut w = 0.0;
-        // all combinations of [body.inverse_mass, ...positions of opposite bodies]
-        // TODO: I think the order doesnt matter because ||AB x AC||^2 is the same regardless of the order, but i should check if this is true
-        let id_views = [
-            (bodies[0].inverse_mass.0, p2, p3, p4),
-            (bodies[1].inverse_mass.0, p1, p4, p3),
-            (bodies[3].inverse_mass.0, p1, p3, p2),
-            (bodies[2].inverse_mass.0, p1, p2, p4),
+        let [p1, p2, p3, p4] = bodies.each_ref().map(|body| body.current_position());
+        // The gradient of each particle is the area-weighted normal of the opposite face
+        let gradients = [
+            (p4 - p2).cross(p3 - p2) / 6.0,
+            (p3 - p1).cross(p4 - p1) / 6.0,
+            (p4 - p1).cross(p2 - p1) / 6.0,
+            (p2 - p1).cross(p3 - p1) / 6.0,
         ];
-        let gradients = id_views
-            .iter()
-            .map(|(inverse_mass, pa, pb, pc)| {
-                let gradient = (*pb - *pa).cross(*pc - *pa) / 6.0;
-                w += inverse_mass * gradient.length_squared();
-                gradient
-            })
-            .collect_vec();
-        if w == 0.0 {
-            return;
-        }
+        let inverse_masses = bodies.each_ref().map(|body| body.inverse_mass.0);
+        let displacements = bodies
+            .each_ref()
+            .map(|body| body.current_position() - body.previous_position.0);
         let volume = Self::volume(&p1, &p2, &p3, &p4);
-        // println!("p1 {}, p2 {}, p3 {}, p4 {}", p1, p2, p3, p4);
-        let residual = -(volume - self.rest_volume) / (w + alpha);
-        // println!(
-        //     "volume {}, rest_volume, {}, residual {}",
-        //     volume, self.rest_volume, residual
-        // );
-        for (index, gradient) in gradients.into_iter().enumerate() {
-            let inverse_mass = bodies[index].inverse_mass.0;
-            let push = gradient * residual * inverse_mass;
-            // println!("push {}", push);
-            if push.is_nan() {
-                panic!("push is nan");
-            }
-            bodies[index].accumulated_translation.0 += push;
+        let delta_lagrange = self.compute_damped_lagrange_update(
+            0.0,
+            volume - self.rest_volume,
+            &gradients,
+            &inverse_masses,
+            &displacements,
+            self.compliance,
+            self.damping,
+            dt,
+        );
+        for (body, (gradient, inverse_mass)) in bodies
+            .into_iter()
+            .zip(gradients.into_iter().zip(inverse_masses))
+        {
+            body.accumulated_translation.0 += delta_lagrange * inverse_mass * gradient;
         }
     }
 }
 
-pub fn draw_debug_volume_constraints(
-    mut gizmos: Gizmos,
-    constraints: Query<&VolumeConstraint>,
-    transforms: Query<&GlobalTransform>,
-) {
-    for constraint in constraints.iter() {
-        let Some((p1, p2, p3, p4)) = [
-            constraint.entity1,
-            constraint.entity2,
-            constraint.entity3,
-            constraint.entity4,
-        ]
-        .iter()
-        .filter_map(|entity| transforms.get(*entity).map(|t| t.translation()).ok())
-        .collect_tuple() else {
-            return;
-        };
-        let center = (p1 + p2 + p3 + p4) / 4.0;
-        // Shrink the tetrahedron to make it easier to see
-        let scale = 0.9;
-        let p1 = center + (p1 - center) * scale;
-        let p2 = center + (p2 - center) * scale;
-        let p3 = center + (p3 - center) * scale;
-        let p4 = center + (p4 - center) * scale;
-
-        let color = Color::hex("#F2A2E5").unwrap();
-        gizmos.line(p1, p2, color);
-        gizmos.line(p1, p3, color);
-        gizmos.line(p1, p4, color);
-        gizmos.line(p2, p3, color);
-        gizmos.line(p2, p4, color);
-        gizmos.line(p3, p4, color);
-    }
-}
-
 impl VolumeConstraint {
     /// Creates a new [`VolumeConstraint`] with the given bodies and contact data.
     pub fn new(
@@ -151,6 +105,7 @@ impl VolumeConstraint {
             entity4: *entity4,
             rest_volume,
             compliance: 0.0,
+            damping: 0.0,
         }
     }
 
@@ -167,6 +122,12 @@ impl VolumeConstraint {
         self
     }
 
+    /// Sets the constraint's damping coefficient.
+    pub fn with_damping(mut self, damping: Scalar) -> Self {
+        self.damping = damping;
+        self
+    }
+
     pub fn with_rest_volume(mut self, rest_volume: Scalar) -> Self {
         self.rest_volume = rest_volume;
         self
@@ -116,7 +116,8 @@
 //! - [Movement](RigidBody#movement)
 //!     - [Linear](LinearVelocity) and [angular](AngularVelocity) velocity
 //!     - [Forces](ExternalForce), [torque](ExternalTorque), and [linear](ExternalImpulse) and [angular](ExternalAngularImpulse) impulses
-//! - [Gravity] and [gravity scale](GravityScale)
+//! - [Gravity], [gravity scale](GravityScale) and [gravity fields](GravityField)
+//! - [Force fields](ForceField) like wind, attraction, vortices and drag
 //! - [Mass properties](RigidBody#mass-properties)
 //! - [Linear](LinearDamping) and [angular](AngularDamping) velocity damping
 //! - [Lock translational and rotational axes](LockedAxes)
@@ -129,8 +130,11 @@
 //!     - [Creation](Collider#creation)
 //!     - [Density](ColliderDensity)
 //!     - [Friction] and [restitution](Restitution) (bounciness)
+//!     - [Rolling friction](RollingFriction) and, in 3D, [torsional friction](TorsionalFriction)
+//!     - [Surface velocity](SurfaceVelocity) for conveyor belts
 //!     - [Collision layers](CollisionLayers)
 //!     - [Sensors](Sensor)
+//!     - [One-way colliders](OneWayCollider)
 #![cfg_attr(
     feature = "3d",
     doc = "    - Creating colliders from meshes with [`AsyncCollider`] and [`AsyncSceneCollider`]"
@@ -138,6 +142,7 @@
 //! - [Get colliding entities](CollidingEntities)
 //! - [Collision events](ContactReportingPlugin#collision-events)
 //! - [Accessing, filtering and modifying collisions](Collisions)
+//!     - [Collision hooks](CollisionHook)
 //! - [Manual contact queries](contact_query)
 //!
 //! ### Constraints and joints
@@ -149,8 +154,26 @@
 //!     - [Prismatic joint](PrismaticJoint)
 //!     - [Revolute joint](RevoluteJoint)
 //!     - [Spherical joint](SphericalJoint)
-//!
-//! Joint motors and articulations are not supported yet, but they will be implemented in a future release.
+//!     - [Generic joint](GenericJoint)
+//!     - [Gear joint](GearJoint)
+//!     - [Rack-and-pinion joint](RackAndPinionJoint)
+//!     - [Pulley joint](PulleyJoint)
+#![cfg_attr(
+    all(feature = "3d", feature = "async-collider"),
+    doc = "- [Ragdolls built from skinned meshes](Ragdoll)"
+)]
+#![cfg_attr(
+    feature = "3d",
+    doc = "- [Articulations for robot arms and long chains](Articulation)"
+)]
+#![cfg_attr(
+    feature = "3d",
+    doc = "- [Cutting and fracturing soft bodies](CuttingPlugin)"
+)]
+#![cfg_attr(
+    feature = "3d",
+    doc = "- [Aerodynamic drag and lift on cloth](Aerodynamics)"
+)]
 //!
 //! ### Spatial queries
 //!
@@ -524,21 +547,34 @@ pub mod resources;
 
 /// Re-exports common components, bundles, resources, plugins and types.
 pub mod prelude {
+    #[cfg(feature = "3d")]
+    pub use crate::plugins::aerodynamics::*;
+    #[cfg(feature = "3d")]
+    pub use crate::plugins::articulation::*;
+    #[cfg(feature = "3d")]
+    pub use crate::plugins::cutting::*;
     #[cfg(feature = "debug-plugin")]
     pub use crate::plugins::debug::*;
+    pub use crate::plugins::force_fields::*;
+    #[cfg(all(feature = "3d", feature = "async-collider"))]
+    pub use crate::plugins::ragdoll::*;
     pub use crate::{
         components::*,
         constraints::{joints::*, *},
         plugins::{
             collision::{
-                broad_phase::BroadCollisionPairs,
-                contact_reporting::{Collision, CollisionEnded, CollisionStarted},
+                broad_phase::{BroadCollisionPairs, ConnectedBodies},
+                contact_modification::{
+                    CollisionHook, CollisionHookPlugin, CollisionHookSet, OneWayCollider,
+                    PassThroughOneWayColliders,
+                },
+                contact_reporting::{Collision, CollisionEnded, CollisionImpact, CollisionStarted},
                 narrow_phase::NarrowPhaseConfig,
                 *,
             },
             prepare::*,
             setup::*,
-            solver::solve_constraint,
+            solver::{solve_constraint, SolverSet},
             spatial_query::*,
             *,
         },
@@ -579,7 +615,8 @@ pub struct SubstepSchedule;
 /// A schedule where you can add systems to filter or modify collisions
 /// using the [`Collisions`] resource.
 ///
-/// The schedule is empty by default and runs in [`SubstepSet::PostProcessCollisions`].
+/// The schedule is empty by default and runs in [`SubstepSet::PostProcessCollisions`],
+/// after [`OneWayCollider`]s and [`CollisionHook`]s have been handled.
 ///
 /// ## Example
 ///
@@ -0,0 +1,264 @@
+//! Applies aerodynamic drag and lift to cloth and other surfaces made of particles.
+//!
+//! See [`AerodynamicsPlugin`].
+
+use crate::{
+    plugins::{force_fields, integrator::SubstepForces},
+    prelude::*,
+};
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+    utils::HashMap,
+};
+
+/// Applies aerodynamic drag and lift to the triangles of surfaces made of particles, like cloth and flags.
+///
+/// The forces are computed for each triangle of an [`Aerodynamics`] component based on
+/// the velocity of the triangle relative to the [`Wind`], and on the normal and area of the triangle.
+/// A third of the force of each triangle is applied to each of its particles.
+///
+/// The forces are computed before [`SubstepSet::Integrate`] in each substep and integrated
+/// together with the [`ExternalForce`]s of the particles, without modifying them.
+pub struct AerodynamicsPlugin;
+
+impl Plugin for AerodynamicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Wind>()
+            .init_resource::<SubstepForces>()
+            .register_type::<Wind>()
+            .register_type::<Aerodynamics>();
+
+        let substep_schedule = app
+            .get_schedule_mut(SubstepSchedule)
+            .expect("add SubstepSchedule first");
+
+        substep_schedule.configure_sets(
+            AerodynamicsSet::ApplyForces
+                .after(force_fields::apply_force_fields)
+                .before(SubstepSet::Integrate),
+        );
+
+        substep_schedule.add_systems(apply_aerodynamic_forces.in_set(AerodynamicsSet::ApplyForces));
+    }
+}
+
+/// The system sets that the [`AerodynamicsPlugin`] runs in the [`SubstepSchedule`].
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AerodynamicsSet {
+    /// Computes the aerodynamic forces of the particles before [`SubstepSet::Integrate`].
+    ApplyForces,
+}
+
+/// Applies aerodynamic drag and lift to the given triangles of particles.
+///
+/// For a triangle with the area `A` and the unit normal `n` moving with the velocity `v`
+/// relative to the [`Wind`], the forces are computed as follows:
+///
+/// - Drag: `-drag_coefficient * |v|² * A * (n · v̂) * v̂`
+/// - Lift: `lift_coefficient * |v|² * A * (n · v̂) * ((n × v̂) × v̂)`
+///
+/// where the normal is flipped to face the direction of the relative velocity.
+/// The drag force resists the relative motion, and the lift force acts perpendicular to it.
+///
+/// The coefficients include the density of the air and the factor of one half found in the usual
+/// drag and lift equations. A coefficient of zero disables the corresponding force.
+///
+/// The particles are typically connected by [`EdgeConstraint`]s and bending constraints,
+/// and the component can be added to any entity, for example a parent entity of the cloth.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// # #[cfg(feature = "f32")]
+/// fn setup(mut commands: Commands) {
+///     let particles = [Vec3::ZERO, Vec3::X, Vec3::Y].map(|position| {
+///         commands
+///             .spawn((
+///                 RigidBody::Dynamic,
+///                 Position(position),
+///                 MassPropertiesBundle::new_computed(&Collider::ball(0.05), 1.0),
+///             ))
+///             .id()
+///     });
+///
+///     commands.spawn(Aerodynamics::new(vec![particles]).with_drag_coefficient(0.8));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, MapEntities)]
+pub struct Aerodynamics {
+    /// The triangles of particles that the forces are applied to.
+    pub triangles: Vec<[Entity; 3]>,
+    /// The coefficient of the drag force that resists the relative motion of the triangles.
+    pub drag_coefficient: Scalar,
+    /// The coefficient of the lift force that acts perpendicular to the relative motion of the triangles.
+    pub lift_coefficient: Scalar,
+}
+
+impl Default for Aerodynamics {
+    fn default() -> Self {
+        Self {
+            triangles: vec![],
+            drag_coefficient: 0.5,
+            lift_coefficient: 0.25,
+        }
+    }
+}
+
+impl MapEntities for Aerodynamics {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        for triangle in self.triangles.iter_mut() {
+            for entity in triangle.iter_mut() {
+                *entity = entity_mapper.get_or_reserve(*entity);
+            }
+        }
+    }
+}
+
+impl Aerodynamics {
+    /// Creates a new [`Aerodynamics`] component for the given triangles of particles
+    /// with the default coefficients.
+    pub fn new(triangles: Vec<[Entity; 3]>) -> Self {
+        Self {
+            triangles,
+            ..default()
+        }
+    }
+
+    /// Sets the coefficient of the drag force.
+    pub fn with_drag_coefficient(self, drag_coefficient: Scalar) -> Self {
+        Self {
+            drag_coefficient,
+            ..self
+        }
+    }
+
+    /// Sets the coefficient of the lift force.
+    pub fn with_lift_coefficient(self, lift_coefficient: Scalar) -> Self {
+        Self {
+            lift_coefficient,
+            ..self
+        }
+    }
+
+    /// Computes the aerodynamic force acting on a triangle with the given vertices,
+    /// moving with the given velocity relative to the air.
+    ///
+    /// The force is the total force of the triangle, not the force applied to each particle.
+    pub fn triangle_force(&self, vertices: [Vector; 3], relative_velocity: Vector) -> Vector {
+        let speed_squared = relative_velocity.length_squared();
+        let cross = (vertices[1] - vertices[0]).cross(vertices[2] - vertices[0]);
+        let double_area = cross.length();
+
+        if speed_squared <= Scalar::EPSILON || double_area <= Scalar::EPSILON {
+            return Vector::ZERO;
+        }
+
+        let direction = relative_velocity / speed_squared.sqrt();
+        let mut normal = cross / double_area;
+        let mut cos_angle = normal.dot(direction);
+
+        // Make the normal face the direction of the relative velocity
+        if cos_angle < 0.0 {
+            normal = -normal;
+            cos_angle = -cos_angle;
+        }
+
+        // The dynamic pressure multiplied by the area of the triangle projected onto the flow
+        let pressure = speed_squared * 0.5 * double_area * cos_angle;
+
+        let drag = -self.drag_coefficient * pressure * direction;
+        let lift = self.lift_coefficient * pressure * normal.cross(direction).cross(direction);
+
+        drag + lift
+    }
+}
+
+/// Computes the aerodynamic forces of the triangles of each [`Aerodynamics`] component
+/// and adds them to the [`SubstepForces`] of the particles.
+///
+/// The force applied to each particle is clamped so that it can't reverse the velocity
+/// of the particle relative to the wind within a single substep. This keeps light particles
+/// in strong wind stable.
+///
+/// Sleeping particles are woken up when the [`Wind`] changes or when they receive a force.
+fn apply_aerodynamic_forces(
+    mut commands: Commands,
+    surfaces: Query<&Aerodynamics>,
+    mut particles: Query<(
+        &Position,
+        &LinearVelocity,
+        &Mass,
+        &mut TimeSleeping,
+        Has<Sleeping>,
+    )>,
+    wind: Res<Wind>,
+    time: Res<Time>,
+    mut particle_forces: Local<HashMap<Entity, Vector>>,
+    mut forces: ResMut<SubstepForces>,
+) {
+    let delta_secs = time.delta_seconds_adjusted();
+
+    if delta_secs == 0.0 {
+        return;
+    }
+
+    let mut wake_up = |entity: Entity, mut time_sleeping: Mut<TimeSleeping>, is_sleeping: bool| {
+        if is_sleeping {
+            commands.entity(entity).remove::<Sleeping>();
+        }
+        time_sleeping.0 = 0.0;
+    };
+
+    if wind.is_changed() {
+        for &entity in surfaces
+            .iter()
+            .flat_map(|aerodynamics| aerodynamics.triangles.iter().flatten())
+        {
+            if let Ok((.., time_sleeping, is_sleeping)) = particles.get_mut(entity) {
+                wake_up(entity, time_sleeping, is_sleeping);
+            }
+        }
+    }
+
+    for aerodynamics in &surfaces {
+        for &triangle in aerodynamics.triangles.iter() {
+            let Ok(bodies) = particles.get_many(triangle) else {
+                continue;
+            };
+
+            let vertices = bodies.map(|(position, ..)| position.0);
+            let velocity = bodies
+                .iter()
+                .fold(Vector::ZERO, |sum, (_, lin_vel, ..)| sum + lin_vel.0)
+                / 3.0;
+            let force = aerodynamics.triangle_force(vertices, velocity - wind.0) / 3.0;
+
+            if force == Vector::ZERO {
+                continue;
+            }
+
+            for entity in triangle {
+                *particle_forces.entry(entity).or_default() += force;
+            }
+        }
+    }
+
+    for (entity, force) in particle_forces.drain() {
+        let Ok((_, lin_vel, mass, time_sleeping, is_sleeping)) = particles.get_mut(entity) else {
+            continue;
+        };
+
+        let max_force = mass.0 * (lin_vel.0 - wind.0).length() / delta_secs;
+        forces.add(entity, force.clamp_length_max(max_force));
+        wake_up(entity, time_sleeping, is_sleeping);
+    }
+}
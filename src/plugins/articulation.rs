@@ -0,0 +1,1073 @@
+//! Simulates trees of rigid bodies in reduced coordinates.
+//!
+//! See [`ArticulationPlugin`].
+
+use crate::{
+    plugins::{integrator::SubstepForces, sync},
+    prelude::*,
+};
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+    utils::HashMap,
+};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+/// Simulates [`Articulation`]s, trees of rigid bodies connected by joints that are described by
+/// joint coordinates instead of constraints.
+///
+/// Long chains of [joints] stretch and jitter unless a high [`SubstepCount`] is used,
+/// because each joint is only solved locally. Articulations are instead simulated in reduced coordinates
+/// with the Featherstone articulated-body algorithm, so the links can never drift apart, and the
+/// joint torques account for the inertia of the whole tree.
+///
+/// ## Coupling with the solver
+///
+/// The links are regular [dynamic](RigidBody::Dynamic) rigid bodies, so they collide with other bodies
+/// and are affected by contacts. Each substep runs as follows:
+///
+/// 1. After [`SubstepSet::Integrate`], the joint coordinates are advanced using the articulated-body algorithm,
+///    taking gravity, [`ExternalForce`], [`ExternalTorque`], [`ForceField`]s, aerodynamic forces
+///    and [`ArticulationDrive`]s into account.
+///    The links are then moved to the poses given by the joint coordinates.
+/// 2. The contact solver moves the links like any other bodies.
+/// 3. After [`SubstepSet::ApplyTranslation`], the poses and velocities of the links are projected back to
+///    joint coordinates, and the links are moved to the poses given by the projected coordinates.
+///
+/// Links don't collide with their parents.
+///
+/// Only [static](RigidBody::Static) and [kinematic](RigidBody::Kinematic) roots are supported.
+/// The root acts as a moving base: the links follow it, but they don't apply forces back on it,
+/// so a [dynamic](RigidBody::Dynamic) root wouldn't react to the motion of its links.
+/// A warning is logged for articulations with a dynamic root.
+///
+/// Articulations are currently only supported in 3D.
+pub struct ArticulationPlugin;
+
+impl Plugin for ArticulationPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Articulation>()
+            .register_type::<ArticulationLink>()
+            .register_type::<ArticulationJoint>()
+            .register_type::<ArticulationDrive>();
+
+        let physics_schedule = app
+            .get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first");
+
+        physics_schedule.configure_sets(
+            ArticulationSet::Prepare
+                .after(SolverSet::UpdateConnectedBodies)
+                .before(PhysicsStepSet::BroadPhase),
+        );
+
+        physics_schedule.add_systems(
+            (
+                warn_dynamic_articulation_roots,
+                init_articulation_links,
+                connect_articulation_links,
+            )
+                .chain()
+                .in_set(ArticulationSet::Prepare),
+        );
+
+        let substeps = app
+            .get_schedule_mut(SubstepSchedule)
+            .expect("add SubstepSchedule first");
+
+        substeps.configure_sets((
+            ArticulationSet::Integrate
+                .after(SubstepSet::Integrate)
+                .before(sync::update_child_collider_position)
+                .before(SubstepSet::NarrowPhase),
+            ArticulationSet::Project.after(SubstepSet::ApplyTranslation),
+        ));
+
+        substeps.add_systems((
+            integrate_articulations.in_set(ArticulationSet::Integrate),
+            project_articulations.in_set(ArticulationSet::Project),
+        ));
+    }
+}
+
+/// The system sets that the [`ArticulationPlugin`] runs in.
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ArticulationSet {
+    /// Attaches new [`ArticulationLink`]s to their articulations and stores the links and their parents
+    /// in [`ConnectedBodies`]. Runs in the [`PhysicsSchedule`] after [`SolverSet::UpdateConnectedBodies`]
+    /// and before [`PhysicsStepSet::BroadPhase`].
+    Prepare,
+    /// Advances the joint coordinates and moves the links. Runs in the [`SubstepSchedule`]
+    /// after [`SubstepSet::Integrate`] and before [`SubstepSet::NarrowPhase`].
+    Integrate,
+    /// Projects the poses and velocities of the links back to joint coordinates. Runs in the
+    /// [`SubstepSchedule`] after [`SubstepSet::ApplyTranslation`].
+    Project,
+}
+
+/// The root of an articulation, a tree of rigid bodies with [`ArticulationLink`]s.
+///
+/// The root must be a [static](RigidBody::Static) or [kinematic](RigidBody::Kinematic) rigid body,
+/// as the links don't apply forces back on it. The links are added to the articulation
+/// automatically based on their [parents](ArticulationLink::parent).
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     let base = commands
+///         .spawn((RigidBody::Static, Articulation::default()))
+///         .id();
+///
+///     // A robot arm with two segments
+///     let upper_arm = commands
+///         .spawn((
+///             RigidBody::Dynamic,
+///             Position(Vec3::Y * 1.5),
+///             Collider::cuboid(0.2, 1.0, 0.2),
+///             ArticulationLink::revolute(base, Vec3::Z)
+///                 .with_local_anchor_1(Vec3::Y)
+///                 .with_local_anchor_2(Vec3::NEG_Y * 0.5)
+///                 .with_drive(ArticulationDrive::new(500.0, 50.0).with_target_position(0.5)),
+///         ))
+///         .id();
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         Position(Vec3::Y * 2.5),
+///         Collider::cuboid(0.2, 1.0, 0.2),
+///         ArticulationLink::revolute(upper_arm, Vec3::Z)
+///             .with_local_anchor_1(Vec3::Y * 0.5)
+///             .with_local_anchor_2(Vec3::NEG_Y * 0.5)
+///             .with_limits(-1.0, 1.0),
+///     ));
+/// }
+/// ```
+#[derive(Reflect, Clone, Component, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct Articulation {
+    /// The links of the articulation ordered so that each link comes after its parent.
+    #[reflect(ignore)]
+    links: Vec<LinkIndex>,
+}
+
+impl Articulation {
+    /// Returns the link entities of the articulation, ordered so that each link comes after its parent.
+    pub fn links(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.links.iter().map(|link| link.entity)
+    }
+}
+
+/// A link entity and the index of its parent link in [`Articulation::links`].
+/// A parent index of `None` means that the parent is the root.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct LinkIndex {
+    entity: Entity,
+    parent: Option<usize>,
+}
+
+/// The type of an [`ArticulationLink`]'s joint. The axes are in the local space of the parent.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArticulationJoint {
+    /// Attaches the link rigidly to its parent.
+    Fixed,
+    /// Allows the link to rotate around the given axis. The joint coordinate is an angle in radians.
+    Revolute(Vector),
+    /// Allows the link to slide along the given axis. The joint coordinate is a distance.
+    Prismatic(Vector),
+}
+
+/// A rigid body that is attached to a parent in an [`Articulation`] by an [`ArticulationJoint`].
+///
+/// The joint coordinates are measured relative to the pose of the link when it is added,
+/// so an angle or distance of zero corresponds to the initial pose.
+/// They can be read using [`position`](Self::position) and [`velocity`](Self::velocity).
+///
+/// Links shouldn't be connected by other [joints].
+#[derive(Reflect, Clone, Copy, Component, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(MapEntities)]
+pub struct ArticulationLink {
+    /// The parent of the link. This is either the root of the [`Articulation`] or another link.
+    pub parent: Entity,
+    /// The joint that connects the link to its parent.
+    pub joint: ArticulationJoint,
+    /// The attachment point on the parent in its local space.
+    pub local_anchor1: Vector,
+    /// The attachment point on the link in its local space.
+    pub local_anchor2: Vector,
+    /// The minimum joint coordinate.
+    pub lower_limit: Scalar,
+    /// The maximum joint coordinate.
+    pub upper_limit: Scalar,
+    /// The drive that moves the joint towards a target position and velocity.
+    pub drive: Option<ArticulationDrive>,
+    /// The joint coordinate, an angle or a distance.
+    position: Scalar,
+    /// The rate of change of the joint coordinate.
+    velocity: Scalar,
+    /// The rotation of the link relative to its parent when the joint coordinate is zero.
+    rest_rotation: Rotation,
+    /// The root of the articulation that the link belongs to.
+    articulation: Option<Entity>,
+}
+
+impl ArticulationLink {
+    /// Creates a new link with the given joint.
+    pub fn new(parent: Entity, joint: ArticulationJoint) -> Self {
+        Self {
+            parent,
+            joint,
+            local_anchor1: Vector::ZERO,
+            local_anchor2: Vector::ZERO,
+            lower_limit: Scalar::NEG_INFINITY,
+            upper_limit: Scalar::INFINITY,
+            drive: None,
+            position: 0.0,
+            velocity: 0.0,
+            rest_rotation: Rotation::default(),
+            articulation: None,
+        }
+    }
+
+    /// Creates a new link that is rigidly attached to its parent.
+    pub fn fixed(parent: Entity) -> Self {
+        Self::new(parent, ArticulationJoint::Fixed)
+    }
+
+    /// Creates a new link that rotates around the given axis in the local space of the parent.
+    pub fn revolute(parent: Entity, axis: Vector) -> Self {
+        Self::new(parent, ArticulationJoint::Revolute(axis.normalize()))
+    }
+
+    /// Creates a new link that slides along the given axis in the local space of the parent.
+    pub fn prismatic(parent: Entity, axis: Vector) -> Self {
+        Self::new(parent, ArticulationJoint::Prismatic(axis.normalize()))
+    }
+
+    /// Sets the attachment point on the parent.
+    pub fn with_local_anchor_1(self, anchor: Vector) -> Self {
+        Self {
+            local_anchor1: anchor,
+            ..self
+        }
+    }
+
+    /// Sets the attachment point on the link.
+    pub fn with_local_anchor_2(self, anchor: Vector) -> Self {
+        Self {
+            local_anchor2: anchor,
+            ..self
+        }
+    }
+
+    /// Sets the minimum and maximum joint coordinates.
+    pub fn with_limits(self, min: Scalar, max: Scalar) -> Self {
+        Self {
+            lower_limit: min,
+            upper_limit: max,
+            ..self
+        }
+    }
+
+    /// Sets the drive of the joint.
+    pub fn with_drive(self, drive: ArticulationDrive) -> Self {
+        Self {
+            drive: Some(drive),
+            ..self
+        }
+    }
+
+    /// Returns the joint coordinate, an angle in radians for revolute joints and a distance for prismatic joints.
+    pub fn position(&self) -> Scalar {
+        self.position
+    }
+
+    /// Returns the rate of change of the joint coordinate.
+    pub fn velocity(&self) -> Scalar {
+        self.velocity
+    }
+
+    /// Returns the root of the [`Articulation`] that the link belongs to, if it has been found.
+    pub fn articulation(&self) -> Option<Entity> {
+        self.articulation
+    }
+
+    /// Returns `true` if the joint has a degree of freedom.
+    fn has_dof(&self) -> bool {
+        !matches!(self.joint, ArticulationJoint::Fixed)
+    }
+}
+
+impl MapEntities for ArticulationLink {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        self.parent = entity_mapper.get_or_reserve(self.parent);
+        self.articulation = self
+            .articulation
+            .map(|entity| entity_mapper.get_or_reserve(entity));
+    }
+}
+
+/// Drives an [`ArticulationLink`]'s joint towards a target position and velocity like a spring.
+///
+/// The drive applies the force or torque
+/// `stiffness * (target_position - position) + damping * (target_velocity - velocity)`, limited by `max_force`.
+/// It is integrated implicitly, so stiff drives stay stable at low [substep counts](SubstepCount).
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArticulationDrive {
+    /// The target angle or position of the joint.
+    pub target_position: Scalar,
+    /// The target angular or linear velocity of the joint.
+    pub target_velocity: Scalar,
+    /// The stiffness of the drive that pulls the joint towards the target position.
+    pub stiffness: Scalar,
+    /// The damping of the drive that pulls the joint towards the target velocity.
+    pub damping: Scalar,
+    /// The maximum force or torque that the drive can apply.
+    pub max_force: Scalar,
+}
+
+impl ArticulationDrive {
+    /// Creates a new drive with the given stiffness and damping, targeting a position and velocity of zero.
+    pub fn new(stiffness: Scalar, damping: Scalar) -> Self {
+        Self {
+            target_position: 0.0,
+            target_velocity: 0.0,
+            stiffness,
+            damping,
+            max_force: Scalar::INFINITY,
+        }
+    }
+
+    /// Sets the target angle or position of the joint.
+    pub fn with_target_position(self, target_position: Scalar) -> Self {
+        Self {
+            target_position,
+            ..self
+        }
+    }
+
+    /// Sets the target angular or linear velocity of the joint.
+    pub fn with_target_velocity(self, target_velocity: Scalar) -> Self {
+        Self {
+            target_velocity,
+            ..self
+        }
+    }
+
+    /// Sets the maximum force or torque that the drive can apply.
+    pub fn with_max_force(self, max_force: Scalar) -> Self {
+        Self { max_force, ..self }
+    }
+}
+
+/// A spatial motion or force vector expressed at the world origin.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct SpatialVector {
+    angular: Vector,
+    linear: Vector,
+}
+
+impl SpatialVector {
+    const ZERO: Self = Self {
+        angular: Vector::ZERO,
+        linear: Vector::ZERO,
+    };
+
+    /// The velocity of a rigid body with the given center of mass and velocities.
+    fn from_body_velocity(center_of_mass: Vector, lin_vel: Vector, ang_vel: Vector) -> Self {
+        Self {
+            angular: ang_vel,
+            linear: lin_vel - ang_vel.cross(center_of_mass),
+        }
+    }
+
+    /// The linear velocity of the given point moving with this spatial velocity.
+    fn velocity_at_point(&self, point: Vector) -> Vector {
+        self.linear + self.angular.cross(point)
+    }
+
+    fn dot(&self, other: &Self) -> Scalar {
+        self.angular.dot(other.angular) + self.linear.dot(other.linear)
+    }
+
+    /// The spatial cross product for motion vectors.
+    fn cross_motion(&self, motion: &Self) -> Self {
+        Self {
+            angular: self.angular.cross(motion.angular),
+            linear: self.angular.cross(motion.linear) + self.linear.cross(motion.angular),
+        }
+    }
+
+    /// The spatial cross product for force vectors.
+    fn cross_force(&self, force: &Self) -> Self {
+        Self {
+            angular: self.angular.cross(force.angular) + self.linear.cross(force.linear),
+            linear: self.angular.cross(force.linear),
+        }
+    }
+}
+
+impl Add for SpatialVector {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            angular: self.angular + other.angular,
+            linear: self.linear + other.linear,
+        }
+    }
+}
+
+impl AddAssign for SpatialVector {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for SpatialVector {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            angular: self.angular - other.angular,
+            linear: self.linear - other.linear,
+        }
+    }
+}
+
+impl Neg for SpatialVector {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            angular: -self.angular,
+            linear: -self.linear,
+        }
+    }
+}
+
+impl Mul<Scalar> for SpatialVector {
+    type Output = Self;
+
+    fn mul(self, scalar: Scalar) -> Self {
+        Self {
+            angular: self.angular * scalar,
+            linear: self.linear * scalar,
+        }
+    }
+}
+
+/// A spatial inertia expressed at the world origin, stored as 3x3 blocks mapping
+/// motion vectors to force vectors.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct SpatialInertia {
+    angular_angular: Matrix3,
+    angular_linear: Matrix3,
+    linear_angular: Matrix3,
+    linear_linear: Matrix3,
+}
+
+impl SpatialInertia {
+    /// The spatial inertia of a rigid body with the given mass, center of mass and world-space inertia tensor.
+    fn from_body(mass: Scalar, center_of_mass: Vector, inertia: Matrix3) -> Self {
+        let c = skew_symmetric(center_of_mass);
+        Self {
+            angular_angular: inertia - c * c * mass,
+            angular_linear: c * mass,
+            linear_angular: -c * mass,
+            linear_linear: Matrix3::IDENTITY * mass,
+        }
+    }
+
+    /// The outer product `a * b^T` scaled by `scale`.
+    fn outer(a: &SpatialVector, b: &SpatialVector, scale: Scalar) -> Self {
+        Self {
+            angular_angular: outer(a.angular, b.angular * scale),
+            angular_linear: outer(a.angular, b.linear * scale),
+            linear_angular: outer(a.linear, b.angular * scale),
+            linear_linear: outer(a.linear, b.linear * scale),
+        }
+    }
+}
+
+impl Mul<SpatialVector> for SpatialInertia {
+    type Output = SpatialVector;
+
+    fn mul(self, motion: SpatialVector) -> SpatialVector {
+        SpatialVector {
+            angular: self.angular_angular * motion.angular + self.angular_linear * motion.linear,
+            linear: self.linear_angular * motion.angular + self.linear_linear * motion.linear,
+        }
+    }
+}
+
+impl Add for SpatialInertia {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            angular_angular: self.angular_angular + other.angular_angular,
+            angular_linear: self.angular_linear + other.angular_linear,
+            linear_angular: self.linear_angular + other.linear_angular,
+            linear_linear: self.linear_linear + other.linear_linear,
+        }
+    }
+}
+
+impl Sub for SpatialInertia {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            angular_angular: self.angular_angular - other.angular_angular,
+            angular_linear: self.angular_linear - other.angular_linear,
+            linear_angular: self.linear_angular - other.linear_angular,
+            linear_linear: self.linear_linear - other.linear_linear,
+        }
+    }
+}
+
+fn skew_symmetric(v: Vector) -> Matrix3 {
+    Matrix3::from_cols(
+        Vector::new(0.0, v.z, -v.y),
+        Vector::new(-v.z, 0.0, v.x),
+        Vector::new(v.y, -v.x, 0.0),
+    )
+}
+
+fn outer(a: Vector, b: Vector) -> Matrix3 {
+    Matrix3::from_cols(a * b.x, a * b.y, a * b.z)
+}
+
+/// Wraps an angle to the range `[-PI, PI]`.
+fn wrap_angle(angle: Scalar) -> Scalar {
+    (angle + PI).rem_euclid(2.0 * PI) - PI
+}
+
+/// The world-space pose and spatial velocity of a body in an articulation.
+#[derive(Clone, Copy, Debug)]
+struct BodyState {
+    position: Vector,
+    rotation: Rotation,
+    velocity: SpatialVector,
+}
+
+/// The world-space state of a link computed from its joint coordinates.
+#[derive(Clone, Copy, Debug)]
+struct LinkKinematics {
+    state: BodyState,
+    /// The motion subspace of the joint, the spatial velocity caused by a unit joint velocity.
+    motion_subspace: SpatialVector,
+}
+
+/// Computes the state of a link from the state of its parent and the joint coordinates.
+fn forward_kinematics(
+    link: &ArticulationLink,
+    parent: &BodyState,
+    position: Scalar,
+    velocity: Scalar,
+) -> LinkKinematics {
+    let (joint_rotation, joint_translation) = match link.joint {
+        ArticulationJoint::Fixed => (Quaternion::IDENTITY, Vector::ZERO),
+        ArticulationJoint::Revolute(axis) => {
+            (Quaternion::from_axis_angle(axis, position), Vector::ZERO)
+        }
+        ArticulationJoint::Prismatic(axis) => (Quaternion::IDENTITY, axis * position),
+    };
+
+    let rotation = Rotation(parent.rotation.0 * joint_rotation * link.rest_rotation.0);
+    let anchor = parent.position
+        + parent
+            .rotation
+            .rotate(link.local_anchor1 + joint_translation);
+    let position = anchor - rotation.rotate(link.local_anchor2);
+
+    let motion_subspace = match link.joint {
+        ArticulationJoint::Fixed => SpatialVector::ZERO,
+        ArticulationJoint::Revolute(axis) => {
+            let axis = parent.rotation.rotate(axis);
+            SpatialVector {
+                angular: axis,
+                linear: anchor.cross(axis),
+            }
+        }
+        ArticulationJoint::Prismatic(axis) => SpatialVector {
+            angular: Vector::ZERO,
+            linear: parent.rotation.rotate(axis),
+        },
+    };
+
+    LinkKinematics {
+        state: BodyState {
+            position,
+            rotation,
+            velocity: parent.velocity + motion_subspace * velocity,
+        },
+        motion_subspace,
+    }
+}
+
+/// Measures the joint coordinate and velocity of a link from the poses and velocities of the link and its parent.
+fn measure_joint(
+    link: &ArticulationLink,
+    parent: &BodyState,
+    child: &BodyState,
+) -> (Scalar, Scalar) {
+    match link.joint {
+        ArticulationJoint::Fixed => (0.0, 0.0),
+        ArticulationJoint::Revolute(axis) => {
+            let relative =
+                parent.rotation.inverse().0 * child.rotation.0 * link.rest_rotation.inverse().0;
+            let angle = 2.0 * relative.xyz().dot(axis).atan2(relative.w);
+            // Choose the angle closest to the previous one to allow multiple revolutions
+            let position = link.position + wrap_angle(angle - link.position);
+            let world_axis = parent.rotation.rotate(axis);
+            let velocity = (child.velocity.angular - parent.velocity.angular).dot(world_axis);
+            (position, velocity)
+        }
+        ArticulationJoint::Prismatic(axis) => {
+            let anchor1 = parent.position + parent.rotation.rotate(link.local_anchor1);
+            let anchor2 = child.position + child.rotation.rotate(link.local_anchor2);
+            let position = parent
+                .rotation
+                .inverse()
+                .rotate(anchor2 - anchor1)
+                .dot(axis);
+            let world_axis = parent.rotation.rotate(axis);
+            let velocity = (child.velocity.velocity_at_point(anchor2)
+                - parent.velocity.velocity_at_point(anchor2))
+            .dot(world_axis);
+            (position, velocity)
+        }
+    }
+}
+
+/// Clamps the joint coordinate of a link to its limits, removing velocity that points out of the limits.
+fn apply_limits(link: &ArticulationLink, position: &mut Scalar, velocity: &mut Scalar) {
+    if *position < link.lower_limit {
+        *position = link.lower_limit;
+        *velocity = velocity.max(0.0);
+    } else if *position > link.upper_limit {
+        *position = link.upper_limit;
+        *velocity = velocity.min(0.0);
+    }
+}
+
+type ArticulationBodyComponents = (
+    &'static mut Position,
+    &'static mut Rotation,
+    &'static PreviousRotation,
+    &'static mut AccumulatedTranslation,
+    &'static mut LinearVelocity,
+    &'static mut AngularVelocity,
+    &'static Mass,
+    &'static Inertia,
+    &'static CenterOfMass,
+    Option<&'static ExternalForce>,
+    Option<&'static ExternalTorque>,
+    Option<&'static GravityScale>,
+);
+
+/// Logs a warning for [`Articulation`]s with a [dynamic](RigidBody::Dynamic) root, as the links
+/// don't apply forces back on the root.
+#[allow(clippy::type_complexity)]
+fn warn_dynamic_articulation_roots(
+    roots: Query<
+        (Entity, &RigidBody),
+        (
+            With<Articulation>,
+            Or<(Added<Articulation>, Changed<RigidBody>)>,
+        ),
+    >,
+) {
+    for (entity, rb) in &roots {
+        if rb.is_dynamic() {
+            warn!(
+                "The root {:?} of an articulation is a dynamic rigid body. \
+                Only static and kinematic roots are supported, \
+                as the links don't apply forces back on the root.",
+                entity
+            );
+        }
+    }
+}
+
+/// Finds the [`Articulation`]s of new [`ArticulationLink`]s and of links that were added before their articulation,
+/// stores their rest rotations and updates the link lists of the articulations.
+fn init_articulation_links(
+    mut articulations: Query<(Entity, &mut Articulation)>,
+    mut links: Query<(Entity, &mut ArticulationLink)>,
+    rotations: Query<&Rotation>,
+    mut removed_links: RemovedComponents<ArticulationLink>,
+) {
+    let links_removed = removed_links.read().count() > 0;
+    let links_added = links.iter_mut().any(|(_, link)| link.is_added());
+    let articulations_added = articulations
+        .iter_mut()
+        .any(|(_, articulation)| articulation.is_added());
+    if !links_removed && !links_added && !articulations_added {
+        return;
+    }
+
+    // Attach new links, and retry links that couldn't be attached before,
+    // for example because their root didn't have an `Articulation` yet
+    let new_links: Vec<Entity> = links
+        .iter_mut()
+        .filter_map(|(entity, link)| {
+            (link.is_added() || link.articulation.is_none()).then_some(entity)
+        })
+        .collect();
+
+    for entity in new_links {
+        let Ok((_, link)) = links.get(entity) else {
+            continue;
+        };
+
+        // Find the root by walking up the parents
+        let mut articulation = None;
+        let mut current = link.parent;
+        for _ in 0..=links.iter().len() {
+            if articulations.contains(current) {
+                articulation = Some(current);
+                break;
+            }
+            match links.get(current) {
+                Ok((_, parent_link)) => current = parent_link.parent,
+                Err(_) => break,
+            }
+        }
+
+        let Ok((_, mut link)) = links.get_mut(entity) else {
+            continue;
+        };
+        link.articulation = articulation;
+        if let (Ok(parent_rot), Ok(rot)) = (rotations.get(link.parent), rotations.get(entity)) {
+            link.rest_rotation = Rotation(parent_rot.inverse().0 * rot.0);
+        }
+        link.position = 0.0;
+        link.velocity = 0.0;
+    }
+
+    // Order the links of each articulation by their depth so that parents come before their children
+    let mut depths: HashMap<Entity, usize> = HashMap::default();
+    for (entity, link) in &links {
+        let mut depth = 0;
+        let mut current = link.parent;
+        while let Ok((_, parent_link)) = links.get(current) {
+            depth += 1;
+            current = parent_link.parent;
+            if depth > links.iter().len() {
+                break;
+            }
+        }
+        depths.insert(entity, depth);
+    }
+
+    for (root, mut articulation) in &mut articulations {
+        let mut members: Vec<(usize, Entity)> = links
+            .iter()
+            .filter(|(_, link)| link.articulation == Some(root))
+            .map(|(entity, _)| (depths[&entity], entity))
+            .collect();
+        members.sort();
+
+        let indices: HashMap<Entity, usize> = members
+            .iter()
+            .enumerate()
+            .map(|(index, (_, entity))| (*entity, index))
+            .collect();
+
+        articulation.links = members
+            .iter()
+            .filter_map(|(_, entity)| {
+                let (_, link) = links.get(*entity).ok()?;
+                Some(LinkIndex {
+                    entity: *entity,
+                    parent: indices.get(&link.parent).copied(),
+                })
+            })
+            .collect();
+    }
+}
+
+/// Stores links and their parents in [`ConnectedBodies`] so that they don't collide with each other.
+fn connect_articulation_links(
+    links: Query<(Entity, &ArticulationLink)>,
+    mut connected_bodies: ResMut<ConnectedBodies>,
+) {
+    for (entity, link) in &links {
+        connected_bodies.insert(link.parent, entity);
+    }
+}
+
+/// Advances the joint coordinates of [`Articulation`]s using the articulated-body algorithm
+/// and moves the links to the resulting poses.
+fn integrate_articulations(
+    articulations: Query<(Entity, &Articulation)>,
+    mut links: Query<&mut ArticulationLink>,
+    mut bodies: Query<ArticulationBodyComponents>,
+    gravity: Res<Gravity>,
+    substep_forces: Res<SubstepForces>,
+    time: Res<Time>,
+) {
+    let delta_secs = time.delta_seconds_adjusted();
+
+    for (root_entity, articulation) in &articulations {
+        let Ok((pos, rot, prev_rot, translation, lin_vel, ang_vel, _, _, com, ..)) =
+            bodies.get(root_entity)
+        else {
+            continue;
+        };
+
+        // The current pose of the root during the substep, before the translation has been applied
+        let root_center = pos.0 + prev_rot.rotate(com.0) + translation.0;
+        let root = BodyState {
+            position: root_center - rot.rotate(com.0),
+            rotation: *rot,
+            velocity: SpatialVector::from_body_velocity(root_center, lin_vel.0, ang_vel.0),
+        };
+
+        let count = articulation.links.len();
+        let mut kinematics = Vec::with_capacity(count);
+        let mut inertias = Vec::with_capacity(count);
+        let mut biases = Vec::with_capacity(count);
+        let mut velocity_products = Vec::with_capacity(count);
+
+        // First pass: compute the link velocities and the bias forces from the root to the leaves
+        for link_index in articulation.links.iter() {
+            let parent = link_index.parent.map_or(root, |i| kinematics[i]);
+            let (
+                Ok(link),
+                Ok((_, _, _, _, _, _, mass, inertia, com, force, torque, gravity_scale)),
+            ) = (links.get(link_index.entity), bodies.get(link_index.entity))
+            else {
+                // Keep the indices aligned for links that can't be simulated
+                kinematics.push(parent);
+                inertias.push(SpatialInertia::from_body(0.0, Vector::ZERO, Matrix3::ZERO));
+                biases.push(SpatialVector::ZERO);
+                velocity_products.push(SpatialVector::ZERO);
+                continue;
+            };
+
+            let link_kinematics = forward_kinematics(link, &parent, link.position, link.velocity);
+            let state = link_kinematics.state;
+            let center = state.position + state.rotation.rotate(com.0);
+            let body_inertia =
+                SpatialInertia::from_body(mass.0, center, inertia.rotated(&state.rotation).0);
+
+            let gravity_force = mass.0 * gravity.0 * gravity_scale.map_or(1.0, |scale| scale.0);
+            let force_at_center = gravity_force
+                + force.map_or(Vector::ZERO, |f| f.force())
+                + substep_forces.get(link_index.entity);
+            let torque_at_center = force.map_or(Vector::ZERO, |f| f.torque())
+                + torque.map_or(Vector::ZERO, |t| t.torque());
+            let external_force = SpatialVector {
+                angular: torque_at_center + center.cross(force_at_center),
+                linear: force_at_center,
+            };
+
+            kinematics.push(state);
+            inertias.push(body_inertia);
+            biases.push(
+                state.velocity.cross_force(&(body_inertia * state.velocity)) - external_force,
+            );
+            velocity_products.push(
+                state
+                    .velocity
+                    .cross_motion(&(link_kinematics.motion_subspace * link.velocity)),
+            );
+        }
+
+        // Second pass: compute the articulated inertias and bias forces from the leaves to the root
+        let mut motion_subspaces = vec![SpatialVector::ZERO; count];
+        let mut projected_inertias = vec![SpatialVector::ZERO; count];
+        let mut effective_masses = vec![0.0; count];
+        let mut joint_forces = vec![0.0; count];
+
+        for (i, link_index) in articulation.links.iter().enumerate().rev() {
+            let Ok(link) = links.get(link_index.entity) else {
+                continue;
+            };
+
+            let parent = link_index.parent.map_or(root, |i| kinematics[i]);
+            let subspace = forward_kinematics(link, &parent, link.position, 0.0).motion_subspace;
+            motion_subspaces[i] = subspace;
+
+            let (articulated_inertia, articulated_bias) = if link.has_dof() {
+                let u = inertias[i] * subspace;
+                let mut d = subspace.dot(&u);
+                let mut joint_force = -subspace.dot(&biases[i]);
+
+                // The drive is integrated implicitly by adding its stiffness and damping to the effective mass
+                if let Some(drive) = link.drive {
+                    let position_error =
+                        drive.target_position - link.position - delta_secs * link.velocity;
+                    let velocity_error = drive.target_velocity - link.velocity;
+                    let drive_force = (drive.stiffness * position_error
+                        + drive.damping * velocity_error)
+                        .clamp(-drive.max_force, drive.max_force);
+                    joint_force += drive_force;
+                    d += delta_secs * drive.damping + delta_secs.powi(2) * drive.stiffness;
+                }
+
+                projected_inertias[i] = u;
+                effective_masses[i] = d;
+                joint_forces[i] = joint_force;
+
+                if d > Scalar::EPSILON {
+                    let inertia = inertias[i] - SpatialInertia::outer(&u, &u, 1.0 / d);
+                    let bias = biases[i] + inertia * velocity_products[i] + u * (joint_force / d);
+                    (inertia, bias)
+                } else {
+                    // The joint has no effective mass, so it moves rigidly with the parent
+                    (inertias[i], biases[i] + inertias[i] * velocity_products[i])
+                }
+            } else {
+                (inertias[i], biases[i] + inertias[i] * velocity_products[i])
+            };
+
+            if let Some(parent_index) = link_index.parent {
+                inertias[parent_index] = inertias[parent_index] + articulated_inertia;
+                biases[parent_index] += articulated_bias;
+            }
+        }
+
+        // Third pass: compute the joint accelerations from the root to the leaves and integrate the joint coordinates
+        let mut accelerations = vec![SpatialVector::ZERO; count];
+
+        for (i, link_index) in articulation.links.iter().enumerate() {
+            let Ok(mut link) = links.get_mut(link_index.entity) else {
+                continue;
+            };
+
+            let parent_acceleration = link_index
+                .parent
+                .map_or(SpatialVector::ZERO, |i| accelerations[i]);
+            let acceleration = parent_acceleration + velocity_products[i];
+
+            if link.has_dof() && effective_masses[i] > Scalar::EPSILON {
+                let joint_acceleration = (joint_forces[i]
+                    - projected_inertias[i].dot(&acceleration))
+                    / effective_masses[i];
+                accelerations[i] = acceleration + motion_subspaces[i] * joint_acceleration;
+
+                let mut velocity = link.velocity + delta_secs * joint_acceleration;
+                let mut position = link.position + delta_secs * velocity;
+                apply_limits(&link, &mut position, &mut velocity);
+                link.velocity = velocity;
+                link.position = position;
+            } else {
+                accelerations[i] = acceleration;
+            }
+        }
+
+        // Move the links to the poses given by the new joint coordinates
+        let mut states = Vec::with_capacity(count);
+        for link_index in articulation.links.iter() {
+            let parent = link_index.parent.map_or(root, |i| states[i]);
+            let Ok(link) = links.get(link_index.entity) else {
+                states.push(parent);
+                continue;
+            };
+            let state = forward_kinematics(link, &parent, link.position, link.velocity).state;
+            states.push(state);
+
+            let Ok((
+                pos,
+                mut rot,
+                prev_rot,
+                mut translation,
+                mut lin_vel,
+                mut ang_vel,
+                _,
+                _,
+                com,
+                ..,
+            )) = bodies.get_mut(link_index.entity)
+            else {
+                continue;
+            };
+            let center = state.position + state.rotation.rotate(com.0);
+            *rot = state.rotation;
+            translation.0 = center - (pos.0 + prev_rot.rotate(com.0));
+            lin_vel.0 = state.velocity.velocity_at_point(center);
+            ang_vel.0 = state.velocity.angular;
+        }
+    }
+}
+
+/// Projects the poses and velocities of the links of [`Articulation`]s, which may have been changed by contacts,
+/// back to joint coordinates, and moves the links to the poses given by the projected coordinates.
+fn project_articulations(
+    articulations: Query<(Entity, &Articulation)>,
+    mut links: Query<&mut ArticulationLink>,
+    mut bodies: Query<ArticulationBodyComponents>,
+) {
+    let body_state = |bodies: &Query<ArticulationBodyComponents>, entity: Entity| {
+        let (pos, rot, _, _, lin_vel, ang_vel, _, _, com, ..) = bodies.get(entity).ok()?;
+        let center = pos.0 + rot.rotate(com.0);
+        Some(BodyState {
+            position: pos.0,
+            rotation: *rot,
+            velocity: SpatialVector::from_body_velocity(center, lin_vel.0, ang_vel.0),
+        })
+    };
+
+    for (root_entity, articulation) in &articulations {
+        let Some(root) = body_state(&bodies, root_entity) else {
+            continue;
+        };
+
+        // Measure the joint coordinates from the current states of the bodies
+        for link_index in articulation.links.iter() {
+            let parent_entity = match link_index.parent {
+                Some(i) => articulation.links[i].entity,
+                None => root_entity,
+            };
+            let (Some(parent), Some(child), Ok(mut link)) = (
+                body_state(&bodies, parent_entity),
+                body_state(&bodies, link_index.entity),
+                links.get_mut(link_index.entity),
+            ) else {
+                continue;
+            };
+
+            let (mut position, mut velocity) = measure_joint(&link, &parent, &child);
+            apply_limits(&link, &mut position, &mut velocity);
+            link.position = position;
+            link.velocity = velocity;
+        }
+
+        // Move the links to the poses given by the joint coordinates
+        let mut states = Vec::with_capacity(articulation.links.len());
+        for link_index in articulation.links.iter() {
+            let parent = link_index.parent.map_or(root, |i| states[i]);
+            let Ok(link) = links.get(link_index.entity) else {
+                states.push(parent);
+                continue;
+            };
+            let state = forward_kinematics(link, &parent, link.position, link.velocity).state;
+            states.push(state);
+
+            let Ok((mut pos, mut rot, _, _, mut lin_vel, mut ang_vel, _, _, com, ..)) =
+                bodies.get_mut(link_index.entity)
+            else {
+                continue;
+            };
+            pos.0 = state.position;
+            *rot = state.rotation;
+            lin_vel.0 = state
+                .velocity
+                .velocity_at_point(state.position + state.rotation.rotate(com.0));
+            ang_vel.0 = state.velocity.angular;
+        }
+    }
+}
@@ -7,6 +7,7 @@ use crate::prelude::*;
 use bevy::{
     ecs::entity::{EntityMapper, MapEntities},
     prelude::*,
+    utils::HashSet,
 };
 
 /// Collects pairs of potentially colliding entities into [`BroadCollisionPairs`] using
@@ -15,6 +16,9 @@ use bevy::{
 ///
 /// Currently, the broad phase uses the [sweep and prune](https://en.wikipedia.org/wiki/Sweep_and_prune) algorithm.
 ///
+/// Pairs whose colliders or rigid bodies are listed in each other's [`CollisionExclusions`],
+/// or whose rigid bodies are stored in [`ConnectedBodies`], are skipped.
+///
 /// The broad phase systems run in [`PhysicsStepSet::BroadPhase`].
 pub struct BroadPhasePlugin;
 
@@ -45,6 +49,39 @@ impl Plugin for BroadPhasePlugin {
 #[reflect(Resource)]
 pub struct BroadCollisionPairs(pub Vec<(Entity, Entity)>);
 
+/// Pairs of rigid bodies that are connected by [joints](Joint) and shouldn't collide with each other.
+///
+/// The resource is owned and updated by the [`SolverPlugin`] before the broad phase,
+/// skipping joints that were configured with [`Joint::with_collide_connected`].
+/// Custom broad phases should skip the pairs stored here.
+#[derive(Resource, Default, Debug)]
+pub struct ConnectedBodies(HashSet<(Entity, Entity)>);
+
+impl ConnectedBodies {
+    /// Returns `true` if the given bodies are connected.
+    pub fn contains(&self, entity1: Entity, entity2: Entity) -> bool {
+        self.0.contains(&Self::ordered(entity1, entity2))
+    }
+
+    /// Marks the given bodies as connected.
+    pub fn insert(&mut self, entity1: Entity, entity2: Entity) {
+        self.0.insert(Self::ordered(entity1, entity2));
+    }
+
+    /// Removes all connected pairs.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn ordered(entity1: Entity, entity2: Entity) -> (Entity, Entity) {
+        if entity1 < entity2 {
+            (entity1, entity2)
+        } else {
+            (entity2, entity1)
+        }
+    }
+}
+
 type AABBChanged = Or<(
     Changed<Position>,
     Changed<Rotation>,
@@ -245,8 +282,15 @@ fn add_new_aabb_intervals(
 fn collect_collision_pairs(
     intervals: ResMut<AabbIntervals>,
     mut broad_collision_pairs: ResMut<BroadCollisionPairs>,
+    connected_bodies: Res<ConnectedBodies>,
+    exclusions: Query<&CollisionExclusions>,
 ) {
-    sweep_and_prune(intervals, &mut broad_collision_pairs.0);
+    sweep_and_prune(
+        intervals,
+        &mut broad_collision_pairs.0,
+        &connected_bodies,
+        &exclusions,
+    );
 }
 
 /// Sorts the entities by their minimum extents along an axis and collects the entity pairs that have intersecting AABBs.
@@ -255,6 +299,8 @@ fn collect_collision_pairs(
 fn sweep_and_prune(
     mut intervals: ResMut<AabbIntervals>,
     broad_collision_pairs: &mut Vec<(Entity, Entity)>,
+    connected_bodies: &ConnectedBodies,
+    exclusions: &Query<&CollisionExclusions>,
 ) {
     // Sort bodies along the x-axis using insertion sort, a sorting algorithm great for sorting nearly sorted lists.
     insertion_sort(&mut intervals.0, |a, b| a.2.mins.x > b.2.mins.x);
@@ -287,11 +333,31 @@ fn sweep_and_prune(
                 continue;
             }
 
+            // Bodies connected by joints and explicitly excluded entities don't collide
+            if connected_bodies.contains(parent1.get(), parent2.get())
+                || excludes(exclusions, [*ent1, parent1.get()], [*ent2, parent2.get()])
+                || excludes(exclusions, [*ent2, parent2.get()], [*ent1, parent1.get()])
+            {
+                continue;
+            }
+
             broad_collision_pairs.push((*ent1, *ent2));
         }
     }
 }
 
+/// Returns `true` if the [`CollisionExclusions`] of the first collider or its rigid body
+/// contain the second collider or its rigid body.
+fn excludes(
+    exclusions: &Query<&CollisionExclusions>,
+    [collider1, body1]: [Entity; 2],
+    [collider2, body2]: [Entity; 2],
+) -> bool {
+    exclusions
+        .iter_many([collider1, body1])
+        .any(|exclusions| exclusions.excludes(collider2) || exclusions.excludes(body2))
+}
+
 /// Sorts a list iteratively using comparisons. In an ascending sort order, when a smaller value is encountered, it is moved lower in the list until it is larger than the item before it.
 ///
 /// This is relatively slow for large lists, but very efficient in cases where the list is already mostly sorted.
@@ -0,0 +1,322 @@
+//! Modifies and filters contacts before they are solved, handling [`OneWayCollider`]s
+//! and running user-defined [`CollisionHook`]s.
+//!
+//! See [`ContactModificationPlugin`].
+
+use std::marker::PhantomData;
+
+use crate::{plugins::setup::run_post_process_collisions_schedule, prelude::*};
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+        system::{StaticSystemParam, SystemParam, SystemParamItem},
+    },
+    prelude::*,
+    utils::HashSet,
+};
+
+/// Modifies and filters contacts in [`SubstepSet::PostProcessCollisions`] before the
+/// [`PostProcessCollisions`] schedule is run.
+///
+/// The plugin removes the contacts of entities that should pass through [`OneWayCollider`]s
+/// and configures the [`CollisionHookSet`] that [`CollisionHook`]s added with the
+/// [`CollisionHookPlugin`] run in.
+pub struct ContactModificationPlugin;
+
+impl Plugin for ContactModificationPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<OneWayCollider>()
+            .register_type::<PassThroughOneWayColliders>();
+
+        let substep_schedule = app
+            .get_schedule_mut(SubstepSchedule)
+            .expect("add SubstepSchedule first");
+
+        substep_schedule.configure_sets(
+            CollisionHookSet
+                .in_set(SubstepSet::PostProcessCollisions)
+                .before(run_post_process_collisions_schedule),
+        );
+
+        substep_schedule.add_systems(
+            filter_one_way_collisions
+                .in_set(SubstepSet::PostProcessCollisions)
+                .before(CollisionHookSet),
+        );
+    }
+}
+
+/// A collider that only blocks entities hitting it from one side, like a one-way platform
+/// that can be jumped onto from below.
+///
+/// Entities are blocked when the contact normal pointing from the collider towards the entity
+/// is within the [`allowed_entry_angle`](Self::allowed_entry_angle) of the
+/// [`direction`](Self::direction). Other entities pass through the collider, and keep passing
+/// through it as long as they penetrate it, so they aren't pushed out halfway through.
+/// They are forgotten as soon as their contacts with the collider end, for example when they
+/// are moved away or despawned.
+///
+/// Entities with the [`PassThroughOneWayColliders`] component pass through one-way colliders
+/// from any side. This can be used for dropping down through platforms.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+#[cfg_attr(feature = "2d", doc = "use bevy_xpbd_2d::{math::*, prelude::*};")]
+#[cfg_attr(feature = "3d", doc = "use bevy_xpbd_3d::{math::*, prelude::*};")]
+///
+/// fn setup(mut commands: Commands) {
+///     // A platform that can be jumped onto from below
+///     commands.spawn((
+///         RigidBody::Static,
+#[cfg_attr(feature = "2d", doc = "        Collider::cuboid(4.0, 0.5),")]
+#[cfg_attr(feature = "3d", doc = "        Collider::cuboid(4.0, 0.5, 4.0),")]
+///         OneWayCollider::new(Vector::Y).with_allowed_entry_angle(PI / 4.0),
+///     ));
+/// }
+/// ```
+#[derive(Reflect, Clone, Component, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, MapEntities)]
+pub struct OneWayCollider {
+    /// The local-space direction that the blocking side of the collider faces.
+    /// Entities moving along this direction pass through the collider.
+    pub direction: Vector,
+    /// The largest angle in radians between the contact normal and the [`direction`](Self::direction)
+    /// at which entities are blocked by the collider.
+    pub allowed_entry_angle: Scalar,
+    /// Entities that are currently passing through the collider.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    passing_entities: HashSet<Entity>,
+}
+
+impl Default for OneWayCollider {
+    fn default() -> Self {
+        Self {
+            direction: Vector::Y,
+            allowed_entry_angle: PI / 3.0,
+            passing_entities: HashSet::default(),
+        }
+    }
+}
+
+impl OneWayCollider {
+    /// Creates a new [`OneWayCollider`] that blocks entities hitting the side facing the given local-space direction.
+    pub fn new(direction: Vector) -> Self {
+        Self {
+            direction,
+            ..default()
+        }
+    }
+
+    /// Sets the largest angle in radians between the contact normal and the [`direction`](Self::direction)
+    /// at which entities are blocked by the collider.
+    pub fn with_allowed_entry_angle(mut self, angle: Scalar) -> Self {
+        self.allowed_entry_angle = angle;
+        self
+    }
+
+    /// Returns `true` if the given entity is currently passing through the collider.
+    pub fn is_passing_through(&self, entity: Entity) -> bool {
+        self.passing_entities.contains(&entity)
+    }
+
+    /// Returns `true` if contacts with the given local-space contact normals should block the other entity.
+    fn blocks(&self, mut normals: impl Iterator<Item = Vector>) -> bool {
+        let direction = self.direction.normalize_or_zero();
+        let min_dot = self.allowed_entry_angle.cos();
+        normals.all(|normal| normal.dot(direction) >= min_dot)
+    }
+}
+
+impl MapEntities for OneWayCollider {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        self.passing_entities = self
+            .passing_entities
+            .drain()
+            .map(|entity| entity_mapper.get_or_reserve(entity))
+            .collect();
+    }
+}
+
+/// A component that makes an entity pass through all [`OneWayCollider`]s regardless of the side
+/// it hits them from. Colliders pass through if either they or their rigid bodies have the component.
+///
+/// Entities that have started passing through a one-way collider keep passing through it until they
+/// stop penetrating it, even if the component is removed before that.
+///
+/// Note that [`Sleeping`] bodies don't move, so a body resting on a one-way collider
+/// should be woken up by removing [`Sleeping`] when the component is added.
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct PassThroughOneWayColliders;
+
+/// A hook for modifying or removing contacts after they have been computed in the narrow phase
+/// and before they are solved.
+///
+/// The hook is a [`SystemParam`], so it can access queries and resources like a system.
+/// It is called once per substep for each pair of colliders in contact, with mutable access
+/// to their [`Contacts`] and manifolds. Returning `false` removes the contacts for the substep.
+///
+/// Hooks are added with the [`CollisionHookPlugin`] and run in the [`CollisionHookSet`].
+/// They can't access the [`Collisions`] resource, as it is borrowed mutably while the hook runs.
+///
+/// ## Example
+///
+/// ```no_run
+/// use bevy::{ecs::system::SystemParam, prelude::*};
+#[cfg_attr(feature = "2d", doc = "use bevy_xpbd_2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use bevy_xpbd_3d::prelude::*;")]
+///
+/// #[derive(Component)]
+/// struct Ghost;
+///
+/// #[derive(SystemParam)]
+/// struct GhostHook<'w, 's> {
+///     ghosts: Query<'w, 's, (), With<Ghost>>,
+/// }
+///
+/// impl CollisionHook for GhostHook<'_, '_> {
+///     fn modify_contacts(&mut self, contacts: &mut Contacts) -> bool {
+///         // Ghosts don't collide with anything
+///         !self.ghosts.contains(contacts.entity1) && !self.ghosts.contains(contacts.entity2)
+///     }
+/// }
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             PhysicsPlugins::default(),
+///             CollisionHookPlugin::<GhostHook>::default(),
+///         ))
+///         .run();
+/// }
+/// ```
+pub trait CollisionHook: SystemParam {
+    /// Modifies the contacts between two colliders, returning `false` if they should be removed.
+    fn modify_contacts(&mut self, contacts: &mut Contacts) -> bool;
+}
+
+/// Runs the [`CollisionHook`] `H` for each pair of colliders in contact during every substep.
+///
+/// Several hooks can be added, but the order they run in is unspecified.
+pub struct CollisionHookPlugin<H>(PhantomData<fn() -> H>);
+
+impl<H> Default for CollisionHookPlugin<H> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<H: CollisionHook + 'static> Plugin for CollisionHookPlugin<H>
+where
+    for<'w, 's> SystemParamItem<'w, 's, H>: CollisionHook,
+{
+    fn build(&self, app: &mut App) {
+        let substep_schedule = app
+            .get_schedule_mut(SubstepSchedule)
+            .expect("add SubstepSchedule first");
+
+        substep_schedule.add_systems(
+            run_collision_hook::<H>
+                .in_set(CollisionHookSet)
+                .ambiguous_with(CollisionHookSet),
+        );
+    }
+}
+
+/// The system set that [`CollisionHook`]s run in. The set is in [`SubstepSet::PostProcessCollisions`]
+/// and runs before the [`PostProcessCollisions`] schedule.
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CollisionHookSet;
+
+/// Calls the [`CollisionHook`] `H` for each collision detected during the current substep.
+fn run_collision_hook<H: CollisionHook + 'static>(
+    mut hook: StaticSystemParam<H>,
+    mut collisions: ResMut<Collisions>,
+) where
+    for<'w, 's> SystemParamItem<'w, 's, H>: CollisionHook,
+{
+    collisions
+        .retain(|contacts| !contacts.during_current_substep || hook.modify_contacts(contacts));
+}
+
+/// Removes the contacts between [`OneWayCollider`]s and the entities that should pass through them.
+///
+/// Entities stop passing through a one-way collider when they are no longer in contact with it during
+/// the current substep, for example because they were moved away or despawned, or their layers changed.
+fn filter_one_way_collisions(
+    mut one_way_colliders: Query<(Entity, &mut OneWayCollider)>,
+    pass_through: Query<(), With<PassThroughOneWayColliders>>,
+    collider_parents: Query<&ColliderParent>,
+    mut collisions: ResMut<Collisions>,
+) {
+    if one_way_colliders.is_empty() {
+        return;
+    }
+
+    for (entity, mut one_way_collider) in &mut one_way_colliders {
+        if !one_way_collider.passing_entities.is_empty() {
+            one_way_collider.passing_entities.retain(|&other| {
+                collisions
+                    .get(entity, other)
+                    .is_some_and(|contacts| contacts.during_current_substep)
+            });
+        }
+    }
+
+    collisions.retain(|contacts| {
+        if !contacts.during_current_substep {
+            return true;
+        }
+
+        // Find the one-way collider and use the contact normals pointing away from it
+        let (mut one_way_collider, other_entity, is_first) =
+            if let Ok((_, one_way_collider)) = one_way_colliders.get_mut(contacts.entity1) {
+                (one_way_collider, contacts.entity2, true)
+            } else if let Ok((_, one_way_collider)) = one_way_colliders.get_mut(contacts.entity2) {
+                (one_way_collider, contacts.entity1, false)
+            } else {
+                return true;
+            };
+
+        let penetrating = contacts
+            .manifolds
+            .iter()
+            .flat_map(|manifold| manifold.contacts.iter())
+            .any(|contact| contact.penetration > 0.0);
+
+        // Entities keep passing through the collider until they stop penetrating it
+        if one_way_collider.passing_entities.contains(&other_entity) {
+            if penetrating {
+                return false;
+            }
+            one_way_collider.passing_entities.remove(&other_entity);
+        }
+
+        let passes_through_all = pass_through.contains(other_entity)
+            || collider_parents
+                .get(other_entity)
+                .is_ok_and(|parent| pass_through.contains(parent.get()));
+        let blocks = !passes_through_all
+            && one_way_collider.blocks(contacts.manifolds.iter().map(|manifold| {
+                if is_first {
+                    manifold.normal1
+                } else {
+                    manifold.normal2
+                }
+            }));
+
+        // Contacts that don't penetrate yet are kept, as they don't push the entity
+        if blocks || !penetrating {
+            return true;
+        }
+
+        one_way_collider.passing_entities.insert(other_entity);
+        false
+    });
+}
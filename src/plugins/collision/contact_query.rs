@@ -100,13 +100,13 @@ pub fn contact(
                 return None;
             }
 
-            Some(ContactData {
+            Some(ContactData::new(
                 point1,
                 point2,
                 normal1,
                 normal2,
-                penetration: -contact.dist,
-            })
+                -contact.dist,
+            ))
         } else {
             None
         }
@@ -202,12 +202,11 @@ pub fn contact_manifolds(
                 contacts: manifold
                     .contacts()
                     .iter()
-                    .map(|contact| ContactData {
-                        point1: subpos1.transform_point(&contact.local_p1).into(),
-                        point2: subpos2.transform_point(&contact.local_p2).into(),
-                        normal1,
-                        normal2,
-                        penetration: -contact.dist,
+                    .map(|contact| {
+                        let point1: Vector = subpos1.transform_point(&contact.local_p1).into();
+                        let point2: Vector = subpos2.transform_point(&contact.local_p2).into();
+                        ContactData::new(point1, point2, normal1, normal2, -contact.dist)
+                            .with_feature_ids(contact.fid1, contact.fid2)
                     })
                     .collect(),
             })
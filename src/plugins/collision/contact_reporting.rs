@@ -3,6 +3,7 @@
 //! See [`ContactReportingPlugin`].
 
 use crate::prelude::*;
+use bevy::utils::HashSet;
 
 /// Sends collision events and updates [`CollidingEntities`].
 ///
@@ -14,6 +15,7 @@ use crate::prelude::*;
 /// - [`Collision`]
 /// - [`CollisionStarted`]
 /// - [`CollisionEnded`]
+/// - [`CollisionImpact`]
 ///
 /// You can listen to them with normal event readers:
 ///
@@ -45,7 +47,8 @@ impl Plugin for ContactReportingPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<Collision>()
             .add_event::<CollisionStarted>()
-            .add_event::<CollisionEnded>();
+            .add_event::<CollisionEnded>()
+            .add_event::<CollisionImpact>();
 
         let physics_schedule = app
             .get_schedule_mut(PhysicsSchedule)
@@ -148,6 +151,58 @@ pub struct CollisionStarted(pub Entity, pub Entity);
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollisionEnded(pub Entity, pub Entity);
 
+/// A [collision event](ContactReportingPlugin#collision-events)
+/// that is sent when two entities hit each other, describing how hard the impact was.
+///
+/// The event is sent on the first frame in which the contacts apply a normal impulse,
+/// and the impulses are the totals applied during that frame. Impulses applied during
+/// later frames can be read from [`Contacts::total_normal_impulse`] and [`Contacts::total_tangent_impulse`].
+///
+/// Bodies that wake up while resting on each other also cause an impact,
+/// so it can be useful to ignore impacts with a low [`impact_speed`](Self::impact_speed).
+///
+/// ## Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+#[cfg_attr(feature = "2d", doc = "use bevy_xpbd_2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use bevy_xpbd_3d::prelude::*;")]
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default()))
+///         .add_systems(Update, print_hard_impacts)
+///         .run();
+/// }
+///
+/// fn print_hard_impacts(mut collision_event_reader: EventReader<CollisionImpact>) {
+///     for impact in collision_event_reader.read() {
+///         if impact.impact_speed > 5.0 {
+///             println!(
+///                 "Entities {:?} and {:?} hit each other at {} m/s",
+///                 impact.entity1,
+///                 impact.entity2,
+///                 impact.impact_speed,
+///             );
+///         }
+///     }
+/// }
+/// ```
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct CollisionImpact {
+    /// The first entity in the collision.
+    pub entity1: Entity,
+    /// The second entity in the collision.
+    pub entity2: Entity,
+    /// The sum of the magnitudes of the normal impulses applied by the contacts.
+    pub normal_impulse: Scalar,
+    /// The sum of the magnitudes of the tangential impulses applied by friction.
+    pub tangent_impulse: Scalar,
+    /// The speed at which the entities approached each other along the contact normal.
+    pub impact_speed: Scalar,
+}
+
 /// Sends collision events and updates [`CollidingEntities`].
 pub fn report_contacts(
     mut colliders: Query<&mut CollidingEntities>,
@@ -155,11 +210,29 @@ pub fn report_contacts(
     mut collision_ev_writer: EventWriter<Collision>,
     mut collision_started_ev_writer: EventWriter<CollisionStarted>,
     mut collision_ended_ev_writer: EventWriter<CollisionEnded>,
+    mut collision_impact_ev_writer: EventWriter<CollisionImpact>,
+    mut impacting_pairs: Local<HashSet<(Entity, Entity)>>,
 ) {
+    let previous_impacting_pairs = std::mem::take(&mut *impacting_pairs);
+
     for ((entity1, entity2), contacts) in collisions.get_internal().iter() {
         if contacts.during_current_frame {
             collision_ev_writer.send(Collision(contacts.clone()));
 
+            // Impact started
+            if contacts.total_normal_impulse > 0.0 {
+                if !previous_impacting_pairs.contains(&(*entity1, *entity2)) {
+                    collision_impact_ev_writer.send(CollisionImpact {
+                        entity1: *entity1,
+                        entity2: *entity2,
+                        normal_impulse: contacts.total_normal_impulse,
+                        tangent_impulse: contacts.total_tangent_impulse,
+                        impact_speed: contacts.max_impact_speed,
+                    });
+                }
+                impacting_pairs.insert((*entity1, *entity2));
+            }
+
             // Collision started
             if !contacts.during_previous_frame {
                 collision_started_ev_writer.send(CollisionStarted(*entity1, *entity2));
@@ -1,9 +1,10 @@
 //! Collision detection is used to detect and compute intersections between [`Collider`]s.
 //!
-//! In `bevy_xpbd`, collision detection is split into three plugins:
+//! In `bevy_xpbd`, collision detection is split into four plugins:
 //!
 //! - [`BroadPhasePlugin`]: Collects pairs of potentially colliding entities into [`BroadCollisionPairs`].
 //! - [`NarrowPhasePlugin`]: Computes contacts for broad phase collision pairs and adds them to [`Collisions`].
+//! - [`ContactModificationPlugin`]: Filters contacts for [`OneWayCollider`]s and runs [`CollisionHook`]s.
 //! - [`ContactReportingPlugin`] (optional): Sends collision events and updates [`CollidingEntities`] based on [`Collisions`].
 //!
 //! Spatial queries are handled by the [`SpatialQueryPlugin`].
@@ -11,6 +12,7 @@
 //! You can also find several utility methods for computing contacts in [`contact_query`].
 
 pub mod broad_phase;
+pub mod contact_modification;
 pub mod contact_query;
 pub mod contact_reporting;
 pub mod narrow_phase;
@@ -18,6 +20,7 @@ pub mod narrow_phase;
 use crate::prelude::*;
 use bevy::prelude::*;
 use indexmap::IndexMap;
+use parry::shape::PackedFeatureId;
 
 // Collisions are stored in an `IndexMap` that uses fxhash.
 // It should have faster iteration than a `HashMap` while mostly retaining other performance characteristics.
@@ -249,8 +252,12 @@ pub(super) struct PreviousCollisions(Collisions);
 /// The contacts are stored in contact manifolds.
 /// Each manifold contains one or more contact points, and each contact
 /// in a given manifold shares the same contact normal.
+///
+/// New fields may be added in the future, so contacts created outside of this crate
+/// should use [`Contacts::new`].
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct Contacts {
     /// First entity in the contact.
     pub entity1: Entity,
@@ -266,6 +273,35 @@ pub struct Contacts {
     pub during_current_substep: bool,
     /// True if the bodies were in contact during the previous frame.
     pub during_previous_frame: bool,
+    /// The sum of the magnitudes of the normal impulses applied by the contacts during this frame.
+    ///
+    /// Divide by the frame's delta time to get the average normal force.
+    pub total_normal_impulse: Scalar,
+    /// The sum of the magnitudes of the tangential impulses applied by friction during this frame.
+    pub total_tangent_impulse: Scalar,
+    /// The largest speed at which the contact points approached each other
+    /// along the contact normal during this frame.
+    pub max_impact_speed: Scalar,
+}
+
+impl Contacts {
+    /// Creates new contacts between two entities with the given contact manifolds.
+    ///
+    /// The contacts are marked as ongoing during the current frame and substep,
+    /// but not during the previous frame. The impulses and impact speed are zero.
+    pub fn new(entity1: Entity, entity2: Entity, manifolds: Vec<ContactManifold>) -> Self {
+        Self {
+            entity1,
+            entity2,
+            manifolds,
+            during_current_frame: true,
+            during_current_substep: true,
+            during_previous_frame: false,
+            total_normal_impulse: 0.0,
+            total_tangent_impulse: 0.0,
+            max_impact_speed: 0.0,
+        }
+    }
 }
 
 /// A contact manifold between two colliders, containing a set of contact points.
@@ -296,8 +332,12 @@ impl ContactManifold {
 }
 
 /// Data related to a contact between two bodies.
+///
+/// New fields may be added in the future, so contacts created outside of this crate
+/// should use [`ContactData::new`].
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct ContactData {
     /// Contact point on the first entity in local coordinates.
     pub point1: Vector,
@@ -309,9 +349,64 @@ pub struct ContactData {
     pub normal2: Vector,
     /// Penetration depth.
     pub penetration: Scalar,
+    /// The ID of the feature (vertex, edge or face) of the first shape that the contact is on.
+    /// It is used for matching contacts between substeps and frames.
+    pub feature_id1: PackedFeatureId,
+    /// The ID of the feature (vertex, edge or face) of the second shape that the contact is on.
+    /// It is used for matching contacts between substeps and frames.
+    pub feature_id2: PackedFeatureId,
+    /// The static friction anchor on the first entity in local coordinates.
+    ///
+    /// Static friction pulls the anchors of a persistent contact back together, so that bodies don't creep
+    /// over time. The anchors are reset to the contact points when the contact starts sliding.
+    pub anchor1: Vector,
+    /// The static friction anchor on the second entity in local coordinates. See [`anchor1`](Self::anchor1).
+    pub anchor2: Vector,
+    /// The Lagrange multiplier for the normal force from the previous substep.
+    /// It is used as a lower bound for the normal Lagrange multiplier in the static friction limit.
+    pub normal_lagrange: Scalar,
 }
 
 impl ContactData {
+    /// Creates a new contact with the given local contact points, local normals and penetration depth.
+    ///
+    /// The feature IDs are [`PackedFeatureId::UNKNOWN`], so the contact isn't matched with
+    /// contacts from previous substeps or frames. The static friction anchors are
+    /// at the contact points.
+    pub fn new(
+        point1: Vector,
+        point2: Vector,
+        normal1: Vector,
+        normal2: Vector,
+        penetration: Scalar,
+    ) -> Self {
+        Self {
+            point1,
+            point2,
+            normal1,
+            normal2,
+            penetration,
+            feature_id1: PackedFeatureId::UNKNOWN,
+            feature_id2: PackedFeatureId::UNKNOWN,
+            anchor1: point1,
+            anchor2: point2,
+            normal_lagrange: 0.0,
+        }
+    }
+
+    /// Sets the IDs of the features (vertex, edge or face) of the shapes that the contact is on.
+    pub fn with_feature_ids(
+        self,
+        feature_id1: PackedFeatureId,
+        feature_id2: PackedFeatureId,
+    ) -> Self {
+        Self {
+            feature_id1,
+            feature_id2,
+            ..self
+        }
+    }
+
     /// Returns the global contact point on the first entity,
     /// transforming the local point by the given entity position and rotation.
     pub fn global_point1(&self, position: &Position, rotation: &Rotation) -> Vector {
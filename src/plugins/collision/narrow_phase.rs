@@ -3,9 +3,10 @@
 //! See [`NarrowPhasePlugin`].
 
 use crate::prelude::*;
-use bevy::ecs::query::Has;
 #[cfg(feature = "parallel")]
 use bevy::tasks::{ComputeTaskPool, ParallelSlice};
+use bevy::{ecs::query::Has, utils::HashMap};
+use parry::shape::PackedFeatureId;
 
 /// Computes contacts between entities.
 ///
@@ -13,6 +14,10 @@ use bevy::tasks::{ComputeTaskPool, ParallelSlice};
 /// which is handled by the [`BroadPhasePlugin`].
 ///
 /// The results of the narrow phase are added into [`Collisions`].
+///
+/// Before contacts are computed, bodies with [`Ccd::Sweep`] are moved back to their earliest time of impact
+/// to prevent them from tunneling through thin colliders. Contacts for bodies with [`Ccd::Speculative`]
+/// are computed at the start of the substep with an extended prediction distance.
 pub struct NarrowPhasePlugin;
 
 impl Plugin for NarrowPhasePlugin {
@@ -46,7 +51,11 @@ impl Plugin for NarrowPhasePlugin {
         app.get_schedule_mut(SubstepSchedule)
             .expect("add SubstepSchedule first")
             .add_systems(
-                (reset_substep_collision_states, collect_collisions)
+                (
+                    reset_substep_collision_states,
+                    sweep_ccd_bodies,
+                    collect_collisions,
+                )
                     .chain()
                     .in_set(SubstepSet::NarrowPhase),
             );
@@ -86,7 +95,9 @@ pub fn collect_collisions(
         Option<&AccumulatedTranslation>,
         Ref<Rotation>,
         &Collider,
+        Option<&ColliderParent>,
     )>,
+    ccd_bodies: Query<(&AccumulatedTranslation, &Ccd)>,
     broad_collision_pairs: Res<BroadCollisionPairs>,
     mut collisions: ResMut<Collisions>,
     narrow_phase_config: Res<NarrowPhaseConfig>,
@@ -95,8 +106,8 @@ pub fn collect_collisions(
     // but not included in [`BroadCollisionPairs`].
     let stationary_collisions = collisions.0.keys().filter(|&&(e1, e2)| {
         if let Ok([bundle1, bundle2]) = bodies.get_many([e1, e2]) {
-            let (position1, _, rotation1, _) = bundle1;
-            let (position2, _, rotation2, _) = bundle2;
+            let (position1, _, rotation1, ..) = bundle1;
+            let (position2, _, rotation2, ..) = bundle2;
             !(position1.is_changed()
                 || rotation1.is_changed()
                 || position2.is_changed()
@@ -122,6 +133,7 @@ pub fn collect_collisions(
                         *entity1,
                         *entity2,
                         &bodies,
+                        &ccd_bodies,
                         &collisions,
                         &narrow_phase_config,
                         |contacts| {
@@ -144,6 +156,7 @@ pub fn collect_collisions(
                 entity1,
                 entity2,
                 &bodies,
+                &ccd_bodies,
                 &collisions,
                 &narrow_phase_config,
                 |contacts| {
@@ -166,7 +179,9 @@ fn process_collision_pair<F>(
         Option<&AccumulatedTranslation>,
         Ref<Rotation>,
         &Collider,
+        Option<&ColliderParent>,
     )>,
+    ccd_bodies: &Query<(&AccumulatedTranslation, &Ccd)>,
     collisions: &ResMut<Collisions>,
     narrow_phase_config: &Res<NarrowPhaseConfig>,
     mut handle_collision: F,
@@ -174,29 +189,51 @@ fn process_collision_pair<F>(
     F: FnMut(Contacts),
 {
     if let Ok([bundle1, bundle2]) = bodies.get_many([entity1, entity2]) {
-        let (position1, accumulated_translation1, rotation1, collider1) = bundle1;
-        let (position2, accumulated_translation2, rotation2, collider2) = bundle2;
+        let (position1, accumulated_translation1, rotation1, collider1, parent1) = bundle1;
+        let (position2, accumulated_translation2, rotation2, collider2, parent2) = bundle2;
+
+        let (displacement1, speculative1) = ccd_motion(entity1, parent1, ccd_bodies);
+        let (displacement2, speculative2) = ccd_motion(entity2, parent2, ccd_bodies);
 
-        let position1 = position1.0 + accumulated_translation1.copied().unwrap_or_default().0;
-        let position2 = position2.0 + accumulated_translation2.copied().unwrap_or_default().0;
+        // Speculative contacts are computed at the start of the substep, and the prediction distance
+        // is extended by the relative motion of the bodies during the substep.
+        let (position1, position2, prediction_distance) = if speculative1 || speculative2 {
+            (
+                position1.0,
+                position2.0,
+                narrow_phase_config.prediction_distance + (displacement1 - displacement2).length(),
+            )
+        } else {
+            (
+                position1.0 + accumulated_translation1.copied().unwrap_or_default().0,
+                position2.0 + accumulated_translation2.copied().unwrap_or_default().0,
+                narrow_phase_config.prediction_distance,
+            )
+        };
 
         let previous_contact = collisions.get_internal().get(&(entity1, entity2));
 
+        let mut manifolds = contact_query::contact_manifolds(
+            collider1,
+            position1,
+            *rotation1,
+            collider2,
+            position2,
+            *rotation2,
+            prediction_distance,
+        );
+
+        // Carry over friction anchors and Lagrange multipliers from matching contacts
+        if let Some(previous_contact) = previous_contact {
+            match_persistent_contacts(previous_contact, &mut manifolds);
+        }
+
         let contacts = Contacts {
-            entity1,
-            entity2,
-            during_current_frame: true,
-            during_current_substep: true,
             during_previous_frame: previous_contact.map_or(false, |c| c.during_previous_frame),
-            manifolds: contact_query::contact_manifolds(
-                collider1,
-                position1,
-                *rotation1,
-                collider2,
-                position2,
-                *rotation2,
-                narrow_phase_config.prediction_distance,
-            ),
+            total_normal_impulse: previous_contact.map_or(0.0, |c| c.total_normal_impulse),
+            total_tangent_impulse: previous_contact.map_or(0.0, |c| c.total_tangent_impulse),
+            max_impact_speed: previous_contact.map_or(0.0, |c| c.max_impact_speed),
+            ..Contacts::new(entity1, entity2, manifolds)
         };
 
         if !contacts.manifolds.is_empty() {
@@ -205,14 +242,206 @@ fn process_collision_pair<F>(
     }
 }
 
+/// Copies the static friction anchors and the normal Lagrange multipliers of the contacts
+/// in `previous_contacts` to the new contacts in `manifolds` that are on the same features.
+///
+/// This lets contacts persist between substeps and frames. Contacts with an unknown
+/// feature ID can't be told apart, so they are never matched.
+fn match_persistent_contacts(previous_contacts: &Contacts, manifolds: &mut [ContactManifold]) {
+    for manifold in manifolds.iter_mut() {
+        for contact in manifold.contacts.iter_mut() {
+            if contact.feature_id1 == PackedFeatureId::UNKNOWN
+                || contact.feature_id2 == PackedFeatureId::UNKNOWN
+            {
+                continue;
+            }
+
+            let previous = previous_contacts
+                .manifolds
+                .iter()
+                .filter(|previous_manifold| previous_manifold.normal1.dot(manifold.normal1) > 0.9)
+                .flat_map(|previous_manifold| previous_manifold.contacts.iter())
+                .find(|previous| {
+                    previous.feature_id1 == contact.feature_id1
+                        && previous.feature_id2 == contact.feature_id2
+                });
+
+            if let Some(previous) = previous {
+                contact.anchor1 = previous.anchor1;
+                contact.anchor2 = previous.anchor2;
+                contact.normal_lagrange = previous.normal_lagrange;
+            }
+        }
+    }
+}
+
+/// Returns the displacement of the body of the given collider during the substep
+/// and whether the body uses [`Ccd::Speculative`].
+fn ccd_motion(
+    entity: Entity,
+    parent: Option<&ColliderParent>,
+    ccd_bodies: &Query<(&AccumulatedTranslation, &Ccd)>,
+) -> (Vector, bool) {
+    ccd_bodies
+        .get(parent.map_or(entity, |p| p.get()))
+        .map_or((Vector::ZERO, false), |(translation, ccd)| {
+            (translation.0, *ccd == Ccd::Speculative)
+        })
+}
+
+/// Clamps the motion of bodies with [`Ccd::Sweep`] at their earliest time of impact with the colliders
+/// in [`BroadCollisionPairs`] to prevent them from tunneling through thin colliders.
+///
+/// The colliders are swept linearly from their positions at the start of the substep using the
+/// [`AccumulatedTranslation`] of their bodies. Rotation during the substep is not taken into account.
+/// Bodies are only swept if they move further than the smallest half-extent of the collider during the substep.
+///
+/// Only the part of the translation along the normal of the hit is clamped, so bodies that graze a surface
+/// keep their tangential motion.
+#[allow(clippy::type_complexity)]
+pub fn sweep_ccd_bodies(
+    mut bodies: ParamSet<(
+        Query<(
+            Option<&Collider>,
+            &Position,
+            &Rotation,
+            Option<&AccumulatedTranslation>,
+            Option<&ColliderParent>,
+            Has<Sensor>,
+        )>,
+        Query<&mut AccumulatedTranslation>,
+    )>,
+    ccd_bodies: Query<(&Ccd, &RigidBody), Without<Sleeping>>,
+    broad_collision_pairs: Res<BroadCollisionPairs>,
+) {
+    if ccd_bodies.is_empty() {
+        return;
+    }
+
+    // The earliest time of impact of each body, the world-space normal of the hit
+    // and the relative translation of the colliders along the normal
+    let mut earliest_impacts: HashMap<Entity, (Scalar, Vector, Scalar)> = HashMap::default();
+
+    {
+        let colliders = bodies.p0();
+
+        // Returns the collider, its position at the start of the substep, its rotation,
+        // the displacement of its body, its body and whether it is a sensor.
+        let collider_motion = |entity: Entity| {
+            let (Some(collider), position, rotation, _, parent, is_sensor) =
+                colliders.get(entity).ok()?
+            else {
+                return None;
+            };
+            let body = parent.map_or(entity, |p| p.get());
+            let displacement = colliders
+                .get(body)
+                .ok()
+                .and_then(|(_, _, _, translation, ..)| translation)
+                .map_or(Vector::ZERO, |translation| translation.0);
+            Some((
+                collider,
+                position.0,
+                *rotation,
+                displacement,
+                body,
+                is_sensor,
+            ))
+        };
+
+        for &(entity1, entity2) in broad_collision_pairs.0.iter() {
+            for (entity, other) in [(entity1, entity2), (entity2, entity1)] {
+                let Some((collider1, position1, rotation1, displacement1, body1, sensor1)) =
+                    collider_motion(entity)
+                else {
+                    continue;
+                };
+
+                if !ccd_bodies
+                    .get(body1)
+                    .is_ok_and(|(ccd, rb)| *ccd == Ccd::Sweep && rb.is_dynamic())
+                {
+                    continue;
+                }
+
+                let Some((collider2, position2, rotation2, displacement2, body2, sensor2)) =
+                    collider_motion(other)
+                else {
+                    continue;
+                };
+
+                if body1 == body2 || sensor1 || sensor2 {
+                    continue;
+                }
+
+                // Only sweep colliders that could move past something during the substep
+                let threshold = collider1
+                    .shape_scaled()
+                    .compute_local_aabb()
+                    .half_extents()
+                    .min();
+                if (displacement1 - displacement2).length() <= threshold {
+                    continue;
+                }
+
+                let Ok(Some(impact)) = contact_query::time_of_impact(
+                    collider1,
+                    position1,
+                    rotation1,
+                    displacement1,
+                    collider2,
+                    position2,
+                    rotation2,
+                    displacement2,
+                    1.0,
+                ) else {
+                    continue;
+                };
+
+                // Bodies that are already penetrating are handled by the solver
+                if impact.status == contact_query::TimeOfImpactStatus::Penetrating {
+                    continue;
+                }
+
+                // The normal points from the first collider towards the second one
+                let normal = rotation1.rotate(impact.normal1);
+                let approach = (displacement1 - displacement2).dot(normal);
+                if approach <= 0.0 {
+                    continue;
+                }
+
+                let earliest = earliest_impacts
+                    .entry(body1)
+                    .or_insert((1.0, Vector::ZERO, 0.0));
+                if impact.time_of_impact < earliest.0 {
+                    *earliest = (impact.time_of_impact, normal, approach);
+                }
+            }
+        }
+    }
+
+    let mut translations = bodies.p1();
+    for (body, (time_of_impact, normal, approach)) in earliest_impacts {
+        if let Ok(mut translation) = translations.get_mut(body) {
+            // Remove the motion along the normal after the time of impact
+            translation.0 -= (1.0 - time_of_impact) * approach * normal;
+        }
+    }
+}
+
 // TODO: The collision state handling feels a bit confusing and error-prone.
 //       Ideally, the narrow phase wouldn't need to handle it at all, or it would at least be simpler.
-/// Resets collision states like `during_current_frame` and `during_previous_frame`.
+/// Resets collision states like `during_current_frame` and `during_previous_frame`,
+/// and clears the impulses accumulated during the previous frame.
 pub fn reset_collision_states(
     mut collisions: ResMut<Collisions>,
     query: Query<(Option<&RigidBody>, Has<Sleeping>)>,
 ) {
     for contacts in collisions.get_internal_mut().values_mut() {
+        contacts.total_normal_impulse = 0.0;
+        contacts.total_tangent_impulse = 0.0;
+        contacts.max_impact_speed = 0.0;
+
         if let Ok([(rb1, sleeping1), (rb2, sleeping2)]) =
             query.get_many([contacts.entity1, contacts.entity2])
         {
@@ -0,0 +1,1271 @@
+//! Cuts and fractures soft bodies built from [`VolumeConstraint`]s and [`EdgeConstraint`]s.
+//!
+//! See [`CuttingPlugin`].
+
+use crate::prelude::*;
+#[cfg(feature = "collider-from-mesh")]
+use bevy::render::mesh::Indices;
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        query::WorldQuery,
+        reflect::ReflectMapEntities,
+        system::SystemParam,
+    },
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+
+/// Cuts and fractures soft bodies built from particles connected by [`VolumeConstraint`]s,
+/// [`EdgeConstraint`]s and bending constraints.
+///
+/// ## Cutting
+///
+/// Send a [`CutSoftBody`] event to cut all soft bodies along a plane or a blade segment.
+/// Depending on the [`CutMode`], the constraints intersected by the cut are either removed,
+/// or split along the cut so that the pieces on each side of the cut separate.
+/// Several cuts can be sent in the same frame, and later cuts also cut the pieces left by earlier ones.
+///
+/// ## Fracturing
+///
+/// [`VolumeConstraint`]s with a [`FractureThreshold`] are split off from their neighbors
+/// when the relative change in their volume exceeds the threshold.
+///
+/// ## Updating visuals
+///
+/// A [`SoftBodyCut`] event is sent for each cut and fracture. It contains the spawned particles
+/// and constraints and the removed constraints so that the application can update its rendering.
+///
+/// Triangles stored in a [`SoftBodySurface`] are split and remapped along with the constraints.
+/// With the `collider-from-mesh` feature, the [`Mesh`] of an entity with a [`SoftBodySurface`]
+/// is rebuilt from the surface when its particles have moved or its triangles have changed.
+/// The feature enables rendering support, so without it, the meshes are not updated.
+///
+/// The cutting systems run in [`PhysicsSchedule`] after [`PhysicsStepSet::ReportContacts`],
+/// and the soft body meshes are updated in [`PostUpdate`] after [`PhysicsSet::StepSimulation`].
+pub struct CuttingPlugin;
+
+impl Plugin for CuttingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CutSoftBody>()
+            .add_event::<SoftBodyCut>()
+            .register_type::<FractureThreshold>()
+            .register_type::<SoftBodySurface>();
+
+        let physics_schedule = app
+            .get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first");
+
+        physics_schedule.add_systems(
+            cut_soft_bodies
+                .after(PhysicsStepSet::ReportContacts)
+                .before(PhysicsStepSet::Sleeping),
+        );
+
+        #[cfg(feature = "collider-from-mesh")]
+        app.add_systems(
+            PostUpdate,
+            update_soft_body_meshes.after(PhysicsSet::StepSimulation),
+        );
+    }
+}
+
+/// The shape of a [`CutSoftBody`] cut.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CutShape {
+    /// An infinite plane going through `point` with the given `normal`.
+    Plane {
+        /// A point on the plane.
+        point: Vector,
+        /// The normal of the plane. It doesn't need to be normalized.
+        normal: Vector,
+    },
+    /// A blade segment from `start` to `end` that is swept along `sweep`.
+    ///
+    /// Only constraints that intersect the parallelogram spanned by the segment
+    /// and the sweep vector are cut.
+    Blade {
+        /// The start of the blade segment.
+        start: Vector,
+        /// The end of the blade segment.
+        end: Vector,
+        /// The direction and distance that the blade segment is moved during the cut.
+        sweep: Vector,
+    },
+}
+
+/// Determines what happens to the constraints intersected by a [`CutSoftBody`] cut.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CutMode {
+    /// The intersected constraints are removed, leaving a gap between the pieces.
+    Remove,
+    /// The intersected constraints are split along the cut. A particle is spawned on each side
+    /// of every edge crossing the cut, and the pieces of the constraints on each side use the particles
+    /// on their own side so that the pieces can separate.
+    ///
+    /// Edge and volume constraints are split into pieces with proportional rest lengths and volumes.
+    /// Bending constraints can't be split, so they are removed instead.
+    #[default]
+    Split,
+}
+
+/// An event that cuts all soft bodies intersected by the given [`CutShape`].
+///
+/// See [`CuttingPlugin`].
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// # #[cfg(feature = "f32")]
+/// fn cut(mut cuts: EventWriter<CutSoftBody>) {
+///     // Split everything along the YZ-plane.
+///     cuts.send(CutSoftBody::plane(Vec3::ZERO, Vec3::X));
+///
+///     // Carve a gap with a blade moving down through a soft body.
+///     cuts.send(
+///         CutSoftBody::blade(Vec3::new(-1.0, 2.0, 0.0), Vec3::new(1.0, 2.0, 0.0), Vec3::NEG_Y * 2.0)
+///             .with_mode(CutMode::Remove),
+///     );
+/// }
+/// ```
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct CutSoftBody {
+    /// The shape of the cut.
+    pub shape: CutShape,
+    /// What happens to the intersected constraints.
+    pub mode: CutMode,
+}
+
+impl CutSoftBody {
+    /// Creates a [`CutMode::Split`] cut along a plane going through `point` with the given `normal`.
+    pub fn plane(point: Vector, normal: Vector) -> Self {
+        Self {
+            shape: CutShape::Plane { point, normal },
+            mode: CutMode::default(),
+        }
+    }
+
+    /// Creates a [`CutMode::Split`] cut with a blade segment from `start` to `end` swept along `sweep`.
+    pub fn blade(start: Vector, end: Vector, sweep: Vector) -> Self {
+        Self {
+            shape: CutShape::Blade { start, end, sweep },
+            mode: CutMode::default(),
+        }
+    }
+
+    /// Sets the [`CutMode`] of the cut.
+    pub fn with_mode(mut self, mode: CutMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+/// An event that is sent after a soft body has been cut or fractured.
+#[derive(Event, Clone, Debug, Default, PartialEq)]
+pub struct SoftBodyCut {
+    /// Pairs of original particles and the particles that were spawned from them.
+    /// The spawned particles take their share of the original particle's mass.
+    pub duplicated_particles: Vec<(Entity, Entity)>,
+    /// Constraint entities that were spawned for the pieces of the split constraints.
+    pub spawned_constraints: Vec<Entity>,
+    /// Constraint entities that were despawned by the cut.
+    pub removed_constraints: Vec<Entity>,
+}
+
+/// Makes a [`VolumeConstraint`] fracture when the relative change of its volume
+/// exceeds the given threshold, for example `0.5` for 50% compression or expansion.
+///
+/// The particles that the fractured tetrahedron shares with its neighbors are duplicated
+/// so that it splits off from them, and the threshold is removed.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct FractureThreshold(pub Scalar);
+
+/// The render surface of a soft body, stored as triangles of particle entities.
+///
+/// The vertices are the world-space positions of the particles, so the entity should have
+/// the default [`Transform`]. With the `collider-from-mesh` feature, the [`Mesh`] of the entity
+/// is rebuilt from the triangles when the particles have moved or the triangles have changed.
+/// Only the positions, normals and indices of the mesh are written, so other attributes
+/// like UVs and tangents are removed. Without the feature, the mesh is not updated.
+///
+/// When the soft body is split, the triangles crossing the cut are split along with the constraints,
+/// and triangles intersected by a [`CutMode::Remove`] cut are dropped. No triangles are added
+/// to close the surface along the cut.
+#[derive(Component, Reflect, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, MapEntities)]
+pub struct SoftBodySurface {
+    /// The triangles of the surface.
+    pub triangles: Vec<[Entity; 3]>,
+}
+
+impl MapEntities for SoftBodySurface {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        for particle in self.triangles.iter_mut().flatten() {
+            *particle = entity_mapper.get_or_reserve(*particle);
+        }
+    }
+}
+
+/// A soft body constraint that can be cut.
+#[derive(Clone, Copy, Debug)]
+enum SoftBodyConstraint {
+    Edge(EdgeConstraint),
+    Volume(VolumeConstraint),
+    IsometricBending(IsometricBendingConstraint),
+    DihedralBending(DihedralBendingConstraint),
+}
+
+impl SoftBodyConstraint {
+    /// Returns the particles of the constraint.
+    fn particles(&self) -> Vec<Entity> {
+        match self {
+            Self::Edge(constraint) => constraint.entities().to_vec(),
+            Self::Volume(constraint) => constraint.entities().to_vec(),
+            Self::IsometricBending(constraint) => constraint.entities().to_vec(),
+            Self::DihedralBending(constraint) => constraint.entities().to_vec(),
+        }
+    }
+
+    /// Returns mutable references to the particles of the constraint.
+    fn particles_mut(&mut self) -> Vec<&mut Entity> {
+        match self {
+            Self::Edge(constraint) => vec![&mut constraint.entity1, &mut constraint.entity2],
+            Self::Volume(constraint) => vec![
+                &mut constraint.entity1,
+                &mut constraint.entity2,
+                &mut constraint.entity3,
+                &mut constraint.entity4,
+            ],
+            Self::IsometricBending(constraint) => vec![
+                &mut constraint.entity1,
+                &mut constraint.entity2,
+                &mut constraint.entity3,
+                &mut constraint.entity4,
+            ],
+            Self::DihedralBending(constraint) => vec![
+                &mut constraint.entity1,
+                &mut constraint.entity2,
+                &mut constraint.entity3,
+                &mut constraint.entity4,
+            ],
+        }
+    }
+
+    /// Inserts the constraint component on the given entity.
+    fn insert(self, commands: &mut Commands, entity: Entity) {
+        let mut entity = commands.entity(entity);
+        match self {
+            Self::Edge(constraint) => entity.insert(constraint),
+            Self::Volume(constraint) => entity.insert(constraint),
+            Self::IsometricBending(constraint) => entity.insert(constraint),
+            Self::DihedralBending(constraint) => entity.insert(constraint),
+        };
+    }
+}
+
+/// How a constraint has been changed by the cuts of the current frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ElementState {
+    Unchanged,
+    Changed,
+    Spawned,
+    Removed,
+}
+
+/// A soft body constraint that the cuts of the current frame operate on.
+struct Element {
+    entity: Entity,
+    constraint: SoftBodyConstraint,
+    state: ElementState,
+}
+
+impl Element {
+    fn is_removed(&self) -> bool {
+        self.state == ElementState::Removed
+    }
+
+    /// Replaces the constraint. Constraints spawned by earlier cuts of the same frame stay spawned.
+    fn set(&mut self, constraint: SoftBodyConstraint) {
+        self.constraint = constraint;
+        if self.state == ElementState::Unchanged {
+            self.state = ElementState::Changed;
+        }
+    }
+}
+
+#[derive(SystemParam)]
+struct SoftBodyConstraints<'w, 's> {
+    edges: Query<'w, 's, (Entity, &'static EdgeConstraint)>,
+    volumes: Query<'w, 's, (Entity, &'static VolumeConstraint)>,
+    isometric_bending: Query<'w, 's, (Entity, &'static IsometricBendingConstraint)>,
+    dihedral_bending: Query<'w, 's, (Entity, &'static DihedralBendingConstraint)>,
+}
+
+impl<'w, 's> SoftBodyConstraints<'w, 's> {
+    /// Collects all soft body constraints.
+    fn elements(&self) -> Vec<Element> {
+        let edges = self
+            .edges
+            .iter()
+            .map(|(entity, constraint)| (entity, SoftBodyConstraint::Edge(*constraint)));
+        let volumes = self
+            .volumes
+            .iter()
+            .map(|(entity, constraint)| (entity, SoftBodyConstraint::Volume(*constraint)));
+        let isometric_bending = self.isometric_bending.iter().map(|(entity, constraint)| {
+            (entity, SoftBodyConstraint::IsometricBending(*constraint))
+        });
+        let dihedral_bending = self
+            .dihedral_bending
+            .iter()
+            .map(|(entity, constraint)| (entity, SoftBodyConstraint::DihedralBending(*constraint)));
+
+        edges
+            .chain(volumes)
+            .chain(isometric_bending)
+            .chain(dihedral_bending)
+            .map(|(entity, constraint)| Element {
+                entity,
+                constraint,
+                state: ElementState::Unchanged,
+            })
+            .collect()
+    }
+}
+
+/// The components that are copied when a particle is spawned by a cut.
+#[derive(WorldQuery)]
+#[world_query(mutable)]
+struct ParticleQuery {
+    entity: Entity,
+    rb: &'static RigidBody,
+    position: &'static Position,
+    rotation: &'static Rotation,
+    linear_velocity: &'static LinearVelocity,
+    angular_velocity: &'static AngularVelocity,
+    mass: &'static mut Mass,
+    inertia: &'static mut Inertia,
+    center_of_mass: &'static CenterOfMass,
+    external_force: Option<&'static ExternalForce>,
+    gravity_scale: Option<&'static GravityScale>,
+    linear_damping: Option<&'static LinearDamping>,
+    angular_damping: Option<&'static AngularDamping>,
+    locked_axes: Option<&'static LockedAxes>,
+    collider: Option<&'static Collider>,
+    collision_layers: Option<&'static CollisionLayers>,
+    friction: Option<&'static Friction>,
+    restitution: Option<&'static Restitution>,
+    transform: Option<&'static Transform>,
+    global_transform: Option<&'static GlobalTransform>,
+}
+
+/// The part of a soft body that a cut affects.
+enum CutRegion {
+    /// Everything intersected by the plane going through `point` with the unit `normal`.
+    Plane { point: Vector, normal: Vector },
+    /// Everything intersected by the parallelogram spanned by `edge` and `sweep` at `start`.
+    Blade {
+        start: Vector,
+        edge: Vector,
+        sweep: Vector,
+        normal: Vector,
+    },
+}
+
+/// A cut in a form that is convenient for classifying constraints.
+struct Cut {
+    mode: CutMode,
+    region: CutRegion,
+}
+
+impl Cut {
+    /// Creates a cut from the given event, or `None` if the cut has no well-defined plane.
+    fn new(event: &CutSoftBody) -> Option<Self> {
+        let region = match event.shape {
+            CutShape::Plane { point, normal } => CutRegion::Plane {
+                point,
+                normal: normal.try_normalize()?,
+            },
+            CutShape::Blade { start, end, sweep } => {
+                let edge = end - start;
+                CutRegion::Blade {
+                    start,
+                    edge,
+                    sweep,
+                    normal: edge.cross(sweep).try_normalize()?,
+                }
+            }
+        };
+        Some(Self {
+            mode: event.mode,
+            region,
+        })
+    }
+
+    /// Returns the signed distance of the point from the plane of the cut.
+    fn distance(&self, point: Vector) -> Scalar {
+        let (CutRegion::Plane {
+            point: origin,
+            normal,
+        }
+        | CutRegion::Blade {
+            start: origin,
+            normal,
+            ..
+        }) = self.region;
+        (point - origin).dot(normal)
+    }
+
+    /// Returns true if the point is on the positive side of the plane of the cut.
+    fn is_positive(&self, point: Vector) -> bool {
+        self.distance(point) >= 0.0
+    }
+
+    /// Returns the point where the segment between two points on different sides of the cut crosses its plane.
+    fn crossing_point(&self, a: Vector, b: Vector) -> Vector {
+        let (distance_a, distance_b) = (self.distance(a), self.distance(b));
+        a + (b - a) * (distance_a / (distance_a - distance_b))
+    }
+
+    /// Returns the edges between the given particles that cross the plane of the cut,
+    /// with the particles of each edge in ascending order.
+    fn crossing_edges(&self, particles: &[Entity], vertices: &[Vector]) -> Vec<(Entity, Entity)> {
+        let mut edges = vec![];
+        for i in 0..particles.len() {
+            for j in i + 1..particles.len() {
+                if self.is_positive(vertices[i]) != self.is_positive(vertices[j]) {
+                    edges.push(ordered(particles[i], particles[j]));
+                }
+            }
+        }
+        edges
+    }
+
+    /// Returns true if the element with the given vertices is intersected by the cut.
+    ///
+    /// A blade intersects an element if the parallelogram swept by the blade overlaps
+    /// the cross-section of the element on the blade's plane, even if the blade only
+    /// passes through an edge or a face of the element.
+    fn intersects(&self, vertices: &[Vector]) -> bool {
+        let positive = vertices.iter().filter(|v| self.is_positive(**v)).count();
+        if positive == 0 || positive == vertices.len() {
+            return false;
+        }
+
+        let CutRegion::Blade {
+            start,
+            edge,
+            sweep,
+            normal,
+        } = self.region
+        else {
+            return true;
+        };
+
+        // The corners of the cross-section are the points where the element's edges cross the plane.
+        // They are expressed in coordinates where the blade's parallelogram is the unit square.
+        let denominator = edge.cross(sweep).dot(normal);
+        let mut section = vec![];
+        for (i, a) in vertices.iter().enumerate() {
+            for b in vertices.iter().skip(i + 1) {
+                if self.is_positive(*a) != self.is_positive(*b) {
+                    let offset = self.crossing_point(*a, *b) - start;
+                    section.push(Vector2::new(
+                        offset.cross(sweep).dot(normal) / denominator,
+                        edge.cross(offset).dot(normal) / denominator,
+                    ));
+                }
+            }
+        }
+        overlaps_unit_square(&section)
+    }
+}
+
+/// Returns true if the convex hull of the given points overlaps the unit square.
+fn overlaps_unit_square(points: &[Vector2]) -> bool {
+    let square = [Vector2::ZERO, Vector2::X, Vector2::Y, Vector2::ONE];
+    let project = |points: &[Vector2], axis: Vector2| {
+        points
+            .iter()
+            .map(|point| point.dot(axis))
+            .fold((Scalar::MAX, Scalar::MIN), |(min, max), projection| {
+                (min.min(projection), max.max(projection))
+            })
+    };
+
+    // Separating axis test. The edges of the hull are among the segments between the points.
+    let hull_axes = points
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| points.iter().skip(i + 1).map(move |b| (*b - *a).perp()));
+    [Vector2::X, Vector2::Y]
+        .into_iter()
+        .chain(hull_axes)
+        .all(|axis| {
+            let (min1, max1) = project(points, axis);
+            let (min2, max2) = project(&square, axis);
+            min1 <= max2 && min2 <= max1
+        })
+}
+
+/// Returns the given particles in ascending order.
+fn ordered(particle1: Entity, particle2: Entity) -> (Entity, Entity) {
+    (particle1.min(particle2), particle1.max(particle2))
+}
+
+/// Returns the positions of the given particles, or `None` if any of them is missing.
+fn vertices(positions: &HashMap<Entity, Vector>, particles: &[Entity]) -> Option<Vec<Vector>> {
+    particles
+        .iter()
+        .map(|particle| positions.get(particle).copied())
+        .collect()
+}
+
+/// Returns the tetrahedra that a tetrahedron crossing a cut is split into.
+///
+/// `crossing(a, b)` returns the particle on the side of `a` where the edge from `a` to `b` crosses the cut.
+fn tetrahedron_pieces(
+    particles: [Entity; 4],
+    is_positive: impl Fn(Entity) -> bool,
+    crossing: impl Fn(Entity, Entity) -> Option<Entity>,
+) -> Option<Vec<[Entity; 4]>> {
+    let (positive, negative): (Vec<_>, Vec<_>) = particles
+        .into_iter()
+        .partition(|particle| is_positive(*particle));
+
+    match (positive.as_slice(), negative.as_slice()) {
+        // A tetrahedron is cut off from the tip, leaving a prism on the other side.
+        (&[tip], &[a, b, c]) | (&[a, b, c], &[tip]) => {
+            let cut_tip = [crossing(tip, a)?, crossing(tip, b)?, crossing(tip, c)?];
+            let cut_base = [crossing(a, tip)?, crossing(b, tip)?, crossing(c, tip)?];
+            let mut pieces = vec![[tip, cut_tip[0], cut_tip[1], cut_tip[2]]];
+            pieces.extend(prism_tetrahedra([a, b, c], cut_base));
+            Some(pieces)
+        }
+        // The tetrahedron is split into two prisms.
+        (&[p1, p2], &[n1, n2]) => {
+            let mut pieces = Vec::from(prism_tetrahedra(
+                [p1, crossing(p1, n1)?, crossing(p1, n2)?],
+                [p2, crossing(p2, n1)?, crossing(p2, n2)?],
+            ));
+            pieces.extend(prism_tetrahedra(
+                [n1, crossing(n1, p1)?, crossing(n1, p2)?],
+                [n2, crossing(n2, p1)?, crossing(n2, p2)?],
+            ));
+            Some(pieces)
+        }
+        _ => None,
+    }
+}
+
+/// Splits a prism into three tetrahedra. The vertices of the triangles `a` and `b` with the same index
+/// are connected by the edges of the prism.
+fn prism_tetrahedra(a: [Entity; 3], b: [Entity; 3]) -> [[Entity; 4]; 3] {
+    [
+        [a[0], a[1], a[2], b[0]],
+        [a[1], a[2], b[0], b[1]],
+        [a[2], b[0], b[1], b[2]],
+    ]
+}
+
+/// Returns the triangles that a triangle crossing a cut is split into, keeping the winding of the triangle.
+///
+/// `crossing(a, b)` returns the particle on the side of `a` where the edge from `a` to `b` crosses the cut.
+fn triangle_pieces(
+    triangle: [Entity; 3],
+    is_positive: impl Fn(Entity) -> bool,
+    crossing: impl Fn(Entity, Entity) -> Option<Entity>,
+) -> Option<Vec<[Entity; 3]>> {
+    let sides = triangle.map(is_positive);
+    let tip =
+        (0..3).find(|i| sides[*i] != sides[(i + 1) % 3] && sides[*i] != sides[(i + 2) % 3])?;
+    let [tip, b, c] = [0, 1, 2].map(|i| triangle[(tip + i) % 3]);
+
+    let (tip_b, tip_c) = (crossing(tip, b)?, crossing(tip, c)?);
+    let (b_tip, c_tip) = (crossing(b, tip)?, crossing(c, tip)?);
+    Some(vec![[tip, tip_b, tip_c], [b, c, c_tip], [b, c_tip, b_tip]])
+}
+
+/// Returns true if the segment between two particles of a split tetrahedron lies along one of its original edges.
+fn along_original_edge(
+    particle1: Entity,
+    particle2: Entity,
+    crossing_edges: &HashMap<Entity, (Entity, Entity)>,
+) -> bool {
+    match (
+        crossing_edges.get(&particle1),
+        crossing_edges.get(&particle2),
+    ) {
+        (None, None) => true,
+        (Some(&(a, b)), None) => particle2 == a || particle2 == b,
+        (None, Some(&(a, b))) => particle1 == a || particle1 == b,
+        (Some(edge1), Some(edge2)) => edge1 == edge2,
+    }
+}
+
+/// The soft bodies that the cuts of the current frame operate on.
+///
+/// Particles and constraints spawned by a cut only exist once the commands have been applied,
+/// so the cuts are applied to this copy, which lets later cuts of the same frame split and duplicate
+/// the particles and constraints spawned by earlier ones.
+struct SoftBodies {
+    elements: Vec<Element>,
+    positions: HashMap<Entity, Vector>,
+    /// The masses of the particles whose mass has been split by the cuts.
+    masses: HashMap<Entity, (Mass, Inertia)>,
+    /// The particles spawned by the cuts.
+    spawned_particles: Vec<Entity>,
+    /// The existing particles that the spawned particles copy their components from.
+    sources: HashMap<Entity, Entity>,
+}
+
+impl SoftBodies {
+    fn new(particles: &Query<ParticleQuery>, constraints: &SoftBodyConstraints) -> Self {
+        Self {
+            elements: constraints.elements(),
+            positions: particles
+                .iter()
+                .map(|particle| (particle.entity, particle.position.0))
+                .collect(),
+            masses: HashMap::new(),
+            spawned_particles: vec![],
+            sources: HashMap::new(),
+        }
+    }
+
+    /// Spawns a copy of the given particle at `position` and moves the given fraction of the particle's mass to it.
+    ///
+    /// Returns `None` if the particle doesn't exist.
+    fn split_particle(
+        &mut self,
+        commands: &mut Commands,
+        particles: &Query<ParticleQuery>,
+        particle: Entity,
+        position: Vector,
+        fraction: Scalar,
+    ) -> Option<Entity> {
+        let (mass, inertia) = match self.masses.get(&particle) {
+            Some(mass) => *mass,
+            None => {
+                let particle = particles.get(particle).ok()?;
+                (*particle.mass, *particle.inertia)
+            }
+        };
+        self.masses.insert(
+            particle,
+            (
+                Mass(mass.0 * (1.0 - fraction)),
+                Inertia(inertia.0 * (1.0 - fraction)),
+            ),
+        );
+
+        let entity = commands.spawn_empty().id();
+        let source = self.sources.get(&particle).copied().unwrap_or(particle);
+        self.masses.insert(
+            entity,
+            (Mass(mass.0 * fraction), Inertia(inertia.0 * fraction)),
+        );
+        self.positions.insert(entity, position);
+        self.sources.insert(entity, source);
+        self.spawned_particles.push(entity);
+        Some(entity)
+    }
+
+    /// Removes the constraints and surface triangles intersected by the cut.
+    fn remove(
+        &mut self,
+        cut: &Cut,
+        surfaces: &mut Query<&mut SoftBodySurface>,
+    ) -> Option<SoftBodyCut> {
+        let mut event = SoftBodyCut::default();
+
+        for element in self.elements.iter_mut() {
+            if element.is_removed() {
+                continue;
+            }
+            let Some(vertices) = vertices(&self.positions, &element.constraint.particles()) else {
+                continue;
+            };
+            if cut.intersects(&vertices) {
+                element.state = ElementState::Removed;
+                event.removed_constraints.push(element.entity);
+            }
+        }
+
+        if event.removed_constraints.is_empty() {
+            return None;
+        }
+
+        for mut surface in surfaces {
+            surface.triangles.retain(|triangle| {
+                !vertices(&self.positions, triangle)
+                    .is_some_and(|vertices| cut.intersects(&vertices))
+            });
+        }
+
+        Some(event)
+    }
+
+    /// Splits the constraints and surface triangles intersected by the cut into pieces on each side of it.
+    ///
+    /// A particle is spawned on each side of every edge that crosses the cut, and the pieces on each side
+    /// use the particles on their own side. Bending constraints intersected by the cut are removed.
+    fn split(
+        &mut self,
+        cut: &Cut,
+        commands: &mut Commands,
+        particles: &Query<ParticleQuery>,
+        surfaces: &mut Query<&mut SoftBodySurface>,
+    ) -> Option<SoftBodyCut> {
+        // Find the intersected constraints and surface triangles and the edges of them that cross the cut.
+        let mut intersected = HashSet::new();
+        let mut crossing_edges = HashSet::new();
+        for (index, element) in self.elements.iter().enumerate() {
+            if element.is_removed() {
+                continue;
+            }
+            let particles = element.constraint.particles();
+            let Some(vertices) = vertices(&self.positions, &particles) else {
+                continue;
+            };
+            if cut.intersects(&vertices) {
+                intersected.insert(index);
+                if let SoftBodyConstraint::Edge(_) | SoftBodyConstraint::Volume(_) =
+                    element.constraint
+                {
+                    crossing_edges.extend(cut.crossing_edges(&particles, &vertices));
+                }
+            }
+        }
+        for surface in surfaces.iter() {
+            for triangle in surface.triangles.iter() {
+                if let Some(vertices) = vertices(&self.positions, triangle) {
+                    if cut.intersects(&vertices) {
+                        crossing_edges.extend(cut.crossing_edges(triangle, &vertices));
+                    }
+                }
+            }
+        }
+
+        if intersected.is_empty() && crossing_edges.is_empty() {
+            return None;
+        }
+
+        let mut event = SoftBodyCut::default();
+
+        // Spawn a particle on each side of every crossing edge. Each particle along the cut gives half
+        // of its mass to the particles spawned next to it, split evenly between its crossing edges.
+        let mut crossing_edges = crossing_edges.into_iter().collect::<Vec<_>>();
+        crossing_edges.sort();
+        let mut edge_counts = HashMap::<Entity, usize>::new();
+        for (a, b) in crossing_edges.iter() {
+            *edge_counts.entry(*a).or_default() += 1;
+            *edge_counts.entry(*b).or_default() += 1;
+        }
+        let mut split_counts = HashMap::<Entity, usize>::new();
+        let mut crossings = HashMap::<(Entity, Entity), Entity>::new();
+        let mut crossing_particle_edges = HashMap::<Entity, (Entity, Entity)>::new();
+        for (a, b) in crossing_edges.iter().copied() {
+            let point = cut.crossing_point(self.positions[&a], self.positions[&b]);
+            for (end, other) in [(a, b), (b, a)] {
+                // Taking 1 / (2k - i) of the remaining mass for the i:th of k edges leaves half of the mass.
+                let split_count = split_counts.entry(end).or_default();
+                let fraction = 1.0 / (2 * edge_counts[&end] - *split_count) as Scalar;
+                *split_count += 1;
+
+                if let Some(particle) =
+                    self.split_particle(commands, particles, end, point, fraction)
+                {
+                    crossings.insert((end, other), particle);
+                    crossing_particle_edges.insert(particle, (a, b));
+                    event.duplicated_particles.push((end, particle));
+                }
+            }
+        }
+
+        let crossing_edges = crossing_edges.into_iter().collect::<HashSet<_>>();
+        let positions = &self.positions;
+        let is_positive = |particle: Entity| {
+            positions
+                .get(&particle)
+                .is_some_and(|p| cut.is_positive(*p))
+        };
+        let crossing = |end: Entity, other: Entity| crossings.get(&(end, other)).copied();
+
+        // New edges inside the split tetrahedra copy the edge constraints of the tetrahedra.
+        let edge_constraints = self
+            .elements
+            .iter()
+            .filter(|element| !element.is_removed())
+            .filter_map(|element| match element.constraint {
+                SoftBodyConstraint::Edge(constraint) => {
+                    Some((ordered(constraint.entity1, constraint.entity2), constraint))
+                }
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+        let mut new_edges = HashSet::new();
+
+        let mut replacements = vec![];
+        for (index, element) in self.elements.iter().enumerate() {
+            if element.is_removed() {
+                continue;
+            }
+
+            let pieces = match element.constraint {
+                // Edges are split where they cross the cut, dividing the rest length between the pieces.
+                SoftBodyConstraint::Edge(constraint) => {
+                    let [a, b] = constraint.entities();
+                    if !crossing_edges.contains(&ordered(a, b)) {
+                        continue;
+                    }
+                    let (Some(crossing_a), Some(crossing_b)) = (crossing(a, b), crossing(b, a))
+                    else {
+                        continue;
+                    };
+                    let length = positions[&a].distance(positions[&b]);
+                    let fraction = positions[&a].distance(positions[&crossing_a]) / length;
+                    [(a, crossing_a, fraction), (crossing_b, b, 1.0 - fraction)]
+                        .into_iter()
+                        .filter(|(_, _, fraction)| *fraction > Scalar::EPSILON)
+                        .map(|(entity1, entity2, fraction)| {
+                            SoftBodyConstraint::Edge(EdgeConstraint {
+                                entity1,
+                                entity2,
+                                rest_length: constraint.rest_length * fraction,
+                                ..constraint
+                            })
+                        })
+                        .collect::<Vec<_>>()
+                }
+                // Tetrahedra are split into pieces whose rest volumes are proportional to their current volumes.
+                SoftBodyConstraint::Volume(constraint) if intersected.contains(&index) => {
+                    let tetrahedron = constraint.entities();
+                    let Some(pieces) = tetrahedron_pieces(tetrahedron, is_positive, crossing)
+                    else {
+                        continue;
+                    };
+                    let [p1, p2, p3, p4] = tetrahedron.map(|particle| positions[&particle]);
+                    let volume = VolumeConstraint::volume(&p1, &p2, &p3, &p4).abs();
+                    let scale = if volume > Scalar::EPSILON {
+                        constraint.rest_volume / volume
+                    } else {
+                        1.0
+                    };
+
+                    let edge_template = tetrahedron.iter().enumerate().find_map(|(i, a)| {
+                        tetrahedron
+                            .iter()
+                            .skip(i + 1)
+                            .find_map(|b| edge_constraints.get(&ordered(*a, *b)))
+                    });
+
+                    let mut new_constraints = vec![];
+                    for piece in pieces {
+                        let [p1, p2, p3, p4] = piece.map(|particle| positions[&particle]);
+                        let piece_volume = VolumeConstraint::volume(&p1, &p2, &p3, &p4);
+                        // Skip slivers left by cuts going through the corners of the tetrahedron.
+                        if piece_volume.abs() <= 1e-6 * volume {
+                            continue;
+                        }
+                        new_constraints.push(SoftBodyConstraint::Volume(
+                            VolumeConstraint::new(
+                                &piece[0], &p1, &piece[1], &p2, &piece[2], &p3, &piece[3], &p4,
+                            )
+                            .with_compliance(constraint.compliance)
+                            .with_damping(constraint.damping)
+                            .with_rest_volume(piece_volume.abs() * scale),
+                        ));
+
+                        let Some(edge_template) = edge_template else {
+                            continue;
+                        };
+                        for (i, a) in piece.iter().enumerate() {
+                            for b in piece.iter().skip(i + 1) {
+                                let edge = ordered(*a, *b);
+                                let length = positions[a].distance(positions[b]);
+                                if along_original_edge(*a, *b, &crossing_particle_edges)
+                                    || length <= Scalar::EPSILON
+                                    || !new_edges.insert(edge)
+                                {
+                                    continue;
+                                }
+                                new_constraints.push(SoftBodyConstraint::Edge(EdgeConstraint {
+                                    entity1: edge.0,
+                                    entity2: edge.1,
+                                    rest_length: length * scale.cbrt(),
+                                    ..*edge_template
+                                }));
+                            }
+                        }
+                    }
+                    new_constraints
+                }
+                // Bending constraints can't be split, so they are removed.
+                SoftBodyConstraint::IsometricBending(_)
+                | SoftBodyConstraint::DihedralBending(_)
+                    if intersected.contains(&index) =>
+                {
+                    vec![]
+                }
+                _ => continue,
+            };
+
+            replacements.push((index, pieces));
+        }
+
+        for mut surface in surfaces {
+            let mut triangles = Vec::with_capacity(surface.triangles.len());
+            for triangle in surface.triangles.iter() {
+                match triangle_pieces(*triangle, is_positive, crossing) {
+                    Some(pieces) => triangles.extend(pieces),
+                    None => triangles.push(*triangle),
+                }
+            }
+            surface.triangles = triangles;
+        }
+
+        for (index, pieces) in replacements {
+            let mut pieces = pieces.into_iter();
+            let element = &mut self.elements[index];
+            match pieces.next() {
+                Some(piece) => element.set(piece),
+                None => {
+                    element.state = ElementState::Removed;
+                    event.removed_constraints.push(element.entity);
+                }
+            }
+            for constraint in pieces {
+                let entity = commands.spawn_empty().id();
+                self.elements.push(Element {
+                    entity,
+                    constraint,
+                    state: ElementState::Spawned,
+                });
+                event.spawned_constraints.push(entity);
+            }
+        }
+
+        Some(event)
+    }
+
+    /// Splits off the tetrahedron of the given volume constraint from its neighbors
+    /// by duplicating the particles that it shares with other constraints.
+    fn fracture(
+        &mut self,
+        entity: Entity,
+        commands: &mut Commands,
+        particles: &Query<ParticleQuery>,
+        surfaces: &mut Query<&mut SoftBodySurface>,
+    ) -> Option<SoftBodyCut> {
+        let index = self
+            .elements
+            .iter()
+            .position(|element| element.entity == entity && !element.is_removed())?;
+        let fractured = self.elements[index].constraint.particles();
+
+        let mut shared_particles = fractured
+            .iter()
+            .copied()
+            .filter(|particle| {
+                self.elements.iter().enumerate().any(|(i, element)| {
+                    i != index
+                        && !element.is_removed()
+                        && element.constraint.particles().contains(particle)
+                })
+            })
+            .collect::<Vec<_>>();
+        shared_particles.sort();
+        shared_particles.dedup();
+
+        let mut event = SoftBodyCut::default();
+        let mut duplicates = HashMap::new();
+        for particle in shared_particles {
+            let position = self.positions[&particle];
+            if let Some(duplicate) =
+                self.split_particle(commands, particles, particle, position, 0.5)
+            {
+                duplicates.insert(particle, duplicate);
+                event.duplicated_particles.push((particle, duplicate));
+            }
+        }
+
+        if duplicates.is_empty() {
+            return None;
+        }
+
+        let mut constraint = self.elements[index].constraint;
+        for particle in constraint.particles_mut() {
+            if let Some(duplicate) = duplicates.get(particle) {
+                *particle = *duplicate;
+            }
+        }
+        self.elements[index].set(constraint);
+
+        for mut surface in surfaces {
+            for triangle in surface.triangles.iter_mut() {
+                if triangle.iter().all(|particle| fractured.contains(particle)) {
+                    for particle in triangle.iter_mut() {
+                        *particle = duplicates.get(particle).copied().unwrap_or(*particle);
+                    }
+                }
+            }
+        }
+
+        Some(event)
+    }
+
+    /// Spawns, updates and despawns the particles and constraints changed by the cuts.
+    fn apply(self, commands: &mut Commands, particles: &mut Query<ParticleQuery>) {
+        for element in self.elements {
+            match element.state {
+                ElementState::Unchanged => {}
+                ElementState::Changed | ElementState::Spawned => {
+                    element.constraint.insert(commands, element.entity);
+                }
+                ElementState::Removed => commands.entity(element.entity).despawn(),
+            }
+        }
+
+        for entity in self.spawned_particles {
+            let Ok(source) = particles.get(self.sources[&entity]) else {
+                continue;
+            };
+            let (mass, inertia) = self.masses[&entity];
+            insert_particle(
+                commands,
+                entity,
+                &source,
+                self.positions[&entity],
+                mass,
+                inertia,
+            );
+        }
+
+        for (entity, (mass, inertia)) in self.masses {
+            if let Ok(mut particle) = particles.get_mut(entity) {
+                *particle.mass = mass;
+                *particle.inertia = inertia;
+            }
+        }
+    }
+}
+
+/// Inserts the components of a particle spawned by a cut, copying them from the given particle.
+///
+/// The copied collider is given a [`ColliderDensity`] of zero so that it doesn't add
+/// its own mass on top of the split [`Mass`] and [`Inertia`].
+fn insert_particle(
+    commands: &mut Commands,
+    entity: Entity,
+    source: &ParticleQueryReadOnlyItem,
+    position: Vector,
+    mass: Mass,
+    inertia: Inertia,
+) {
+    let mut particle = commands.entity(entity);
+    particle.insert((
+        *source.rb,
+        Position(position),
+        *source.rotation,
+        *source.linear_velocity,
+        *source.angular_velocity,
+        mass,
+        inertia,
+        *source.center_of_mass,
+    ));
+    if let Some(external_force) = source.external_force {
+        particle.insert(*external_force);
+    }
+    if let Some(gravity_scale) = source.gravity_scale {
+        particle.insert(*gravity_scale);
+    }
+    if let Some(linear_damping) = source.linear_damping {
+        particle.insert(*linear_damping);
+    }
+    if let Some(angular_damping) = source.angular_damping {
+        particle.insert(*angular_damping);
+    }
+    if let Some(locked_axes) = source.locked_axes {
+        particle.insert(*locked_axes);
+    }
+    if let Some(collider) = source.collider {
+        particle.insert((collider.clone(), ColliderDensity::ZERO));
+    }
+    if let Some(collision_layers) = source.collision_layers {
+        particle.insert(*collision_layers);
+    }
+    if let Some(friction) = source.friction {
+        particle.insert(*friction);
+    }
+    if let Some(restitution) = source.restitution {
+        particle.insert(*restitution);
+    }
+    if let Some(transform) = source.transform {
+        let transform = Transform {
+            translation: position.as_f32(),
+            ..*transform
+        };
+        particle.insert(transform);
+        if source.global_transform.is_some() {
+            particle.insert(GlobalTransform::from(transform));
+        }
+    }
+}
+
+/// Handles [`CutSoftBody`] events and fractures [`VolumeConstraint`]s that exceed their [`FractureThreshold`].
+fn cut_soft_bodies(
+    mut commands: Commands,
+    mut cut_events: EventReader<CutSoftBody>,
+    mut soft_body_cut_events: EventWriter<SoftBodyCut>,
+    mut particles: Query<ParticleQuery>,
+    constraints: SoftBodyConstraints,
+    fracture_thresholds: Query<(Entity, &FractureThreshold)>,
+    mut surfaces: Query<&mut SoftBodySurface>,
+) {
+    let cuts = cut_events.read().filter_map(Cut::new).collect::<Vec<_>>();
+
+    // Split off tetrahedra whose volume has changed too much.
+    let mut fractures = vec![];
+    for (entity, threshold) in &fracture_thresholds {
+        let Ok((_, constraint)) = constraints.volumes.get(entity) else {
+            continue;
+        };
+        let Ok(vertices) = particles
+            .get_many(constraint.entities())
+            .map(|particles| particles.map(|particle| particle.position.0))
+        else {
+            continue;
+        };
+
+        let volume =
+            VolumeConstraint::volume(&vertices[0], &vertices[1], &vertices[2], &vertices[3]);
+        if (volume / constraint.rest_volume - 1.0).abs() <= threshold.0 {
+            continue;
+        }
+
+        fractures.push(entity);
+        commands.entity(entity).remove::<FractureThreshold>();
+    }
+
+    if cuts.is_empty() && fractures.is_empty() {
+        return;
+    }
+
+    let mut soft_bodies = SoftBodies::new(&particles, &constraints);
+
+    for cut in cuts.iter() {
+        let event = match cut.mode {
+            CutMode::Remove => soft_bodies.remove(cut, &mut surfaces),
+            CutMode::Split => soft_bodies.split(cut, &mut commands, &particles, &mut surfaces),
+        };
+        if let Some(event) = event {
+            soft_body_cut_events.send(event);
+        }
+    }
+
+    for entity in fractures {
+        if let Some(event) = soft_bodies.fracture(entity, &mut commands, &particles, &mut surfaces)
+        {
+            soft_body_cut_events.send(event);
+        }
+    }
+
+    soft_bodies.apply(&mut commands, &mut particles);
+}
+
+/// Rebuilds the meshes of [`SoftBodySurface`]s from the current positions of their particles.
+///
+/// A mesh is only rebuilt when the surface or its mesh handle has changed or when a particle has moved.
+/// Meshes that haven't been loaded yet are rebuilt once they are available.
+#[cfg(feature = "collider-from-mesh")]
+fn update_soft_body_meshes(
+    surfaces: Query<(Entity, Ref<SoftBodySurface>, Ref<Handle<Mesh>>)>,
+    positions: Query<Ref<Position>>,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    mut unloaded_meshes: Local<HashSet<Entity>>,
+) {
+    let Some(mut meshes) = meshes else {
+        return;
+    };
+
+    unloaded_meshes.retain(|entity| surfaces.contains(*entity));
+
+    for (entity, surface, mesh) in &surfaces {
+        let changed = surface.is_changed()
+            || mesh.is_changed()
+            || unloaded_meshes.contains(&entity)
+            || surface.triangles.iter().flatten().any(|particle| {
+                positions
+                    .get(*particle)
+                    .is_ok_and(|position| position.is_changed())
+            });
+        if !changed {
+            continue;
+        }
+
+        let Some(mesh) = meshes.get_mut(&*mesh) else {
+            unloaded_meshes.insert(entity);
+            continue;
+        };
+        unloaded_meshes.remove(&entity);
+
+        // Each particle is a single vertex so that the normals are smooth across the surface.
+        let mut vertices = HashMap::new();
+        let mut vertex_positions = vec![];
+        let mut indices = vec![];
+        for triangle in surface.triangles.iter() {
+            let Ok(triangle_positions) = positions.get_many(*triangle) else {
+                continue;
+            };
+            for (particle, position) in triangle.iter().zip(triangle_positions) {
+                let index = *vertices.entry(*particle).or_insert_with(|| {
+                    vertex_positions.push(position.0.as_f32());
+                    vertex_positions.len() as u32 - 1
+                });
+                indices.push(index);
+            }
+        }
+
+        // Weight the normals of the triangles by their areas.
+        let mut normals = vec![Vec3::ZERO; vertex_positions.len()];
+        for triangle in indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| vertex_positions[triangle[i] as usize]);
+            let normal = (b - a).cross(c - a);
+            for index in triangle {
+                normals[*index as usize] += normal;
+            }
+        }
+
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            vertex_positions
+                .iter()
+                .map(|position| position.to_array())
+                .collect::<Vec<_>>(),
+        );
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_NORMAL,
+            normals
+                .iter()
+                .map(|normal| normal.normalize_or_zero().to_array())
+                .collect::<Vec<_>>(),
+        );
+        mesh.set_indices(Some(Indices::U32(indices)));
+
+        // The other attributes would no longer match the vertices.
+        let other_attributes = mesh
+            .attributes()
+            .map(|(id, _)| id)
+            .filter(|&id| id != Mesh::ATTRIBUTE_POSITION.id && id != Mesh::ATTRIBUTE_NORMAL.id)
+            .collect::<Vec<_>>();
+        for id in other_attributes {
+            mesh.remove_attribute(id);
+        }
+    }
+}
@@ -70,6 +70,24 @@ pub struct PhysicsDebugConfig {
     pub shapecast_point_color: Option<Color>,
     /// The color used for the hit normals in [shapecasts](spatial_query#shapecasting).
     pub shapecast_normal_color: Option<Color>,
+    /// The strain of [edge constraints](EdgeConstraint) at which their color reaches
+    /// the ends of the [soft body color ramp](Self::soft_body_color_ramp).
+    /// If `None`, edge constraints will not be rendered.
+    #[cfg(feature = "3d")]
+    pub edge_strain_limit: Option<Scalar>,
+    /// The relative change in volume of [volume constraints](VolumeConstraint) at which their color reaches
+    /// the ends of the [soft body color ramp](Self::soft_body_color_ramp).
+    /// If `None`, volume constraints will not be rendered.
+    #[cfg(feature = "3d")]
+    pub volume_ratio_limit: Option<Scalar>,
+    /// The energy of [isometric](IsometricBendingConstraint) and [dihedral](DihedralBendingConstraint)
+    /// bending constraints at which their color reaches the end of the [soft body color ramp](Self::soft_body_color_ramp).
+    /// If `None`, bending constraints will not be rendered.
+    #[cfg(feature = "3d")]
+    pub bending_energy_limit: Option<Scalar>,
+    /// The colors used for the strain and stress of soft body constraints.
+    #[cfg(feature = "3d")]
+    pub soft_body_color_ramp: ColorRamp,
     /// Determines if the visibility of entities with [colliders](Collider) should be set to `Visibility::Hidden`,
     /// which will only show the debug renders.
     pub hide_meshes: bool,
@@ -97,6 +115,14 @@ impl Default for PhysicsDebugConfig {
             shapecast_shape_color: Some(Color::rgb(0.4, 0.6, 1.0)),
             shapecast_point_color: Some(Color::YELLOW),
             shapecast_normal_color: Some(Color::PINK),
+            #[cfg(feature = "3d")]
+            edge_strain_limit: None,
+            #[cfg(feature = "3d")]
+            volume_ratio_limit: None,
+            #[cfg(feature = "3d")]
+            bending_energy_limit: None,
+            #[cfg(feature = "3d")]
+            soft_body_color_ramp: ColorRamp::default(),
             hide_meshes: false,
         }
     }
@@ -125,6 +151,14 @@ impl PhysicsDebugConfig {
             shapecast_shape_color: Some(Color::rgb(0.4, 0.6, 1.0)),
             shapecast_point_color: Some(Color::YELLOW),
             shapecast_normal_color: Some(Color::PINK),
+            #[cfg(feature = "3d")]
+            edge_strain_limit: Some(0.1),
+            #[cfg(feature = "3d")]
+            volume_ratio_limit: Some(0.2),
+            #[cfg(feature = "3d")]
+            bending_energy_limit: Some(0.5),
+            #[cfg(feature = "3d")]
+            soft_body_color_ramp: ColorRamp::default(),
             hide_meshes: true,
         }
     }
@@ -150,6 +184,14 @@ impl PhysicsDebugConfig {
             shapecast_shape_color: None,
             shapecast_point_color: None,
             shapecast_normal_color: None,
+            #[cfg(feature = "3d")]
+            edge_strain_limit: None,
+            #[cfg(feature = "3d")]
+            volume_ratio_limit: None,
+            #[cfg(feature = "3d")]
+            bending_energy_limit: None,
+            #[cfg(feature = "3d")]
+            soft_body_color_ramp: ColorRamp::default(),
             hide_meshes: false,
         }
     }
@@ -209,6 +251,14 @@ impl PhysicsDebugConfig {
         }
     }
 
+    /// Creates a [`PhysicsDebugConfig`] configuration with the given limits for the strain of [edge constraints](EdgeConstraint),
+    /// the relative change in volume of [volume constraints](VolumeConstraint) and the energy of bending constraints.
+    /// Other debug rendering options will be disabled.
+    #[cfg(feature = "3d")]
+    pub fn soft_bodies(edge_strain: Scalar, volume_ratio: Scalar, bending_energy: Scalar) -> Self {
+        Self::none().with_soft_body_limits(edge_strain, volume_ratio, bending_energy)
+    }
+
     /// Sets the lengths of the axes drawn for the entity.
     pub fn with_axes(mut self, axis_lengths: Vector) -> Self {
         self.axis_lengths = Some(axis_lengths);
@@ -282,6 +332,29 @@ impl PhysicsDebugConfig {
         self
     }
 
+    /// Enables debug rendering for [edge](EdgeConstraint), [volume](VolumeConstraint) and bending constraints
+    /// with the given limits for the strain, the relative change in volume and the bending energy.
+    /// The limits are the values at which the colors reach the ends of the [soft body color ramp](Self::soft_body_color_ramp).
+    #[cfg(feature = "3d")]
+    pub fn with_soft_body_limits(
+        mut self,
+        edge_strain: Scalar,
+        volume_ratio: Scalar,
+        bending_energy: Scalar,
+    ) -> Self {
+        self.edge_strain_limit = Some(edge_strain);
+        self.volume_ratio_limit = Some(volume_ratio);
+        self.bending_energy_limit = Some(bending_energy);
+        self
+    }
+
+    /// Sets the colors used for the strain and stress of soft body constraints.
+    #[cfg(feature = "3d")]
+    pub fn with_soft_body_color_ramp(mut self, color_ramp: ColorRamp) -> Self {
+        self.soft_body_color_ramp = color_ramp;
+        self
+    }
+
     /// Disables axis debug rendering.
     pub fn without_axes(mut self) -> Self {
         self.axis_lengths = None;
@@ -335,6 +408,61 @@ impl PhysicsDebugConfig {
         self.shapecast_normal_color = None;
         self
     }
+
+    /// Disables soft body constraint debug rendering.
+    #[cfg(feature = "3d")]
+    pub fn without_soft_bodies(mut self) -> Self {
+        self.edge_strain_limit = None;
+        self.volume_ratio_limit = None;
+        self.bending_energy_limit = None;
+        self
+    }
+}
+
+/// A color ramp used for debug rendering values like the strain and stress of soft body constraints.
+///
+/// Values from `-1.0` to `1.0` are mapped to colors from [`compressed`](Self::compressed)
+/// through [`rest`](Self::rest) to [`stretched`](Self::stretched).
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorRamp {
+    /// The color for a value of `-1.0`, like a fully compressed constraint.
+    pub compressed: Color,
+    /// The color for a value of `0.0`, like a constraint at rest.
+    pub rest: Color,
+    /// The color for a value of `1.0`, like a fully stretched constraint.
+    pub stretched: Color,
+}
+
+impl Default for ColorRamp {
+    fn default() -> Self {
+        Self {
+            compressed: Color::rgb(0.2, 0.4, 1.0),
+            rest: Color::rgb(0.9, 0.9, 0.9),
+            stretched: Color::rgb(1.0, 0.2, 0.2),
+        }
+    }
+}
+
+impl ColorRamp {
+    /// Returns the color for the given value. The value is clamped to the range `[-1.0, 1.0]`.
+    #[allow(clippy::unnecessary_cast)]
+    pub fn sample(&self, value: Scalar) -> Color {
+        let value = value.clamp(-1.0, 1.0) as f32;
+        let (from, to, t) = if value < 0.0 {
+            (self.rest, self.compressed, -value)
+        } else {
+            (self.rest, self.stretched, value)
+        };
+        let [r1, g1, b1, a1] = from.as_rgba_f32();
+        let [r2, g2, b2, a2] = to.as_rgba_f32();
+        Color::rgba(
+            r1 + (r2 - r1) * t,
+            g1 + (g2 - g1) * t,
+            b1 + (b2 - b1) * t,
+            a1 + (a2 - a1) * t,
+        )
+    }
 }
 
 /// A component for the debug render configuration of an entity. See [`PhysicsDebugPlugin`].
@@ -3,11 +3,11 @@
 //! See [`PhysicsDebugPlugin`].
 
 mod configuration;
-mod particle_drag;
+mod picking;
 mod renderer;
 
 pub use configuration::*;
-pub use particle_drag::*;
+pub use picking::*;
 pub use renderer::*;
 
 use crate::prelude::*;
@@ -26,9 +26,17 @@ use bevy::{ecs::query::Has, prelude::*, utils::intern::Interned};
 /// - [Joints](joints)
 /// - [`RayCaster`]
 /// - [`ShapeCaster`]
+#[cfg_attr(
+    feature = "3d",
+    doc = "- The strain of [edge constraints](EdgeConstraint), the volume change of [volume constraints](VolumeConstraint) and the energy of bending constraints"
+)]
 /// - Changing the visibility of entities to only show debug rendering
 ///
 /// By default, [AABBs](ColliderAabb) and [contacts](Contacts) are not debug rendered.
+#[cfg_attr(
+    feature = "3d",
+    doc = "Soft body constraints are also not rendered by default, but they can be enabled with [`PhysicsDebugConfig::with_soft_body_limits`]."
+)]
 /// You can use the [`PhysicsDebugConfig`] resource for the global configuration and the
 /// [`DebugRender`] component for entity-level configuration.
 ///
@@ -111,6 +119,10 @@ impl Plugin for PhysicsDebugPlugin {
                     debug_render_joints::<DistanceJoint>,
                     debug_render_joints::<RevoluteJoint>,
                     debug_render_joints::<SphericalJoint>,
+                    debug_render_joints::<GenericJoint>,
+                    debug_render_joints::<GearJoint>,
+                    debug_render_joints::<RackAndPinionJoint>,
+                    debug_render_joints::<PulleyJoint>,
                     debug_render_raycasts,
                     debug_render_shapecasts,
                 )
@@ -121,18 +133,33 @@ impl Plugin for PhysicsDebugPlugin {
                 self.schedule,
                 change_mesh_visibility.after(PhysicsSet::StepSimulation),
             );
+
+        #[cfg(feature = "3d")]
+        app.add_systems(
+            self.schedule,
+            (
+                debug_render_edge_constraints,
+                debug_render_volume_constraints,
+                debug_render_bending_constraints,
+            )
+                .after(PhysicsSet::StepSimulation)
+                .run_if(|config: Res<PhysicsDebugConfig>| config.enabled),
+        );
     }
 }
 
 #[allow(clippy::type_complexity)]
 fn debug_render_axes(
-    bodies: Query<(
-        &Position,
-        &Rotation,
-        &CenterOfMass,
-        Has<Sleeping>,
-        Option<&DebugRender>,
-    )>,
+    bodies: Query<
+        (
+            &Position,
+            &Rotation,
+            &CenterOfMass,
+            Has<Sleeping>,
+            Option<&DebugRender>,
+        ),
+        Without<WorldBody>,
+    >,
     mut debug_renderer: PhysicsDebugRenderer,
     config: Res<PhysicsDebugConfig>,
 ) {
@@ -341,13 +368,13 @@ fn debug_render_contacts(
 }
 
 fn debug_render_joints<T: Joint>(
-    bodies: Query<(&Position, &Rotation, Has<Sleeping>)>,
+    bodies: Query<(&Position, &Rotation, Has<Sleeping>, Has<WorldBody>)>,
     joints: Query<(&T, Option<&DebugRender>)>,
     mut debug_renderer: PhysicsDebugRenderer,
     config: Res<PhysicsDebugConfig>,
 ) {
     for (joint, render_config) in &joints {
-        if let Ok([(pos1, rot1, sleeping1), (pos2, rot2, sleeping2)]) =
+        if let Ok([(pos1, rot1, sleeping1, is_world1), (pos2, rot2, sleeping2, is_world2)]) =
             bodies.get_many(joint.entities())
         {
             if let Some(mut anchor_color) = config.joint_anchor_color {
@@ -361,16 +388,21 @@ fn debug_render_joints<T: Joint>(
                     }
                 }
 
-                debug_renderer.draw_line(
-                    pos1.0,
-                    pos1.0 + rot1.rotate(joint.local_anchor_1()),
-                    anchor_color,
-                );
-                debug_renderer.draw_line(
-                    pos2.0,
-                    pos2.0 + rot2.rotate(joint.local_anchor_2()),
-                    anchor_color,
-                );
+                // The anchors on the world are world-space points, so they have no line to a body
+                if !is_world1 {
+                    debug_renderer.draw_line(
+                        pos1.0,
+                        pos1.0 + rot1.rotate(joint.local_anchor_1()),
+                        anchor_color,
+                    );
+                }
+                if !is_world2 {
+                    debug_renderer.draw_line(
+                        pos2.0,
+                        pos2.0 + rot2.rotate(joint.local_anchor_2()),
+                        anchor_color,
+                    );
+                }
             }
             if let Some(mut separation_color) = config.joint_separation_color {
                 // If both bodies are sleeping, multiply the color by the sleeping color multiplier
@@ -479,3 +511,119 @@ fn change_mesh_visibility(
         }
     }
 }
+
+/// Renders [edge constraints](EdgeConstraint) colored by their strain.
+#[cfg(feature = "3d")]
+fn debug_render_edge_constraints(
+    constraints: Query<&EdgeConstraint>,
+    positions: Query<&Position>,
+    mut debug_renderer: PhysicsDebugRenderer,
+    config: Res<PhysicsDebugConfig>,
+) {
+    let Some(limit) = config.edge_strain_limit else {
+        return;
+    };
+
+    for constraint in &constraints {
+        let Ok([p1, p2]) = positions.get_many(constraint.entities()) else {
+            continue;
+        };
+
+        let strain = if constraint.rest_length > 0.0 {
+            (p1.distance(p2.0) - constraint.rest_length) / constraint.rest_length
+        } else {
+            0.0
+        };
+        let color = config.soft_body_color_ramp.sample(strain / limit);
+
+        debug_renderer.draw_line(p1.0, p2.0, color);
+    }
+}
+
+/// Renders [volume constraints](VolumeConstraint) as slightly shrunk tetrahedra
+/// colored by the relative change in their volume.
+#[cfg(feature = "3d")]
+fn debug_render_volume_constraints(
+    constraints: Query<&VolumeConstraint>,
+    positions: Query<&Position>,
+    mut debug_renderer: PhysicsDebugRenderer,
+    config: Res<PhysicsDebugConfig>,
+) {
+    let Some(limit) = config.volume_ratio_limit else {
+        return;
+    };
+
+    for constraint in &constraints {
+        let Ok(positions) = positions.get_many(constraint.entities()) else {
+            continue;
+        };
+        let [p1, p2, p3, p4] = positions.map(|position| position.0);
+
+        let volume_change = if constraint.rest_volume > 0.0 {
+            VolumeConstraint::volume(&p1, &p2, &p3, &p4) / constraint.rest_volume - 1.0
+        } else {
+            0.0
+        };
+        let color = config.soft_body_color_ramp.sample(volume_change / limit);
+
+        // Shrink the tetrahedron to make it easier to see
+        let center = (p1 + p2 + p3 + p4) / 4.0;
+        let [p1, p2, p3, p4] = [p1, p2, p3, p4].map(|p| center + (p - center) * 0.9);
+
+        debug_renderer.draw_line(p1, p2, color);
+        debug_renderer.draw_line(p1, p3, color);
+        debug_renderer.draw_line(p1, p4, color);
+        debug_renderer.draw_line(p2, p3, color);
+        debug_renderer.draw_line(p2, p4, color);
+        debug_renderer.draw_line(p3, p4, color);
+    }
+}
+
+/// Renders [isometric](IsometricBendingConstraint) and [dihedral](DihedralBendingConstraint) bending constraints
+/// as lines between the centers of their triangles, colored by the bending energy.
+#[cfg(feature = "3d")]
+fn debug_render_bending_constraints(
+    isometric_constraints: Query<&IsometricBendingConstraint>,
+    dihedral_constraints: Query<&DihedralBendingConstraint>,
+    positions: Query<&Position>,
+    mut debug_renderer: PhysicsDebugRenderer,
+    config: Res<PhysicsDebugConfig>,
+) {
+    let Some(limit) = config.bending_energy_limit else {
+        return;
+    };
+
+    let mut draw = |[p1, p2, p3, p4]: [Vector; 4], energy: Scalar| {
+        let color = config.soft_body_color_ramp.sample(energy / limit);
+        debug_renderer.draw_line((p1 + p2 + p3) / 3.0, (p1 + p2 + p4) / 3.0, color);
+    };
+
+    for constraint in &isometric_constraints {
+        let Ok(positions) = positions.get_many(constraint.entities()) else {
+            continue;
+        };
+        let [p1, p2, p3, p4] = positions.map(|position| position.0);
+        draw(
+            [p1, p2, p3, p4],
+            constraint.calculate_constraint_value(&p1, &p2, &p3, &p4),
+        );
+    }
+
+    for constraint in &dihedral_constraints {
+        let Ok(positions) = positions.get_many(constraint.entities()) else {
+            continue;
+        };
+        let positions = positions.map(|position| position.0);
+        let Some((angle, _)) = DihedralBendingConstraint::angle_and_gradients(&positions) else {
+            continue;
+        };
+        // Take the shorter way around to the rest angle
+        let mut deviation = angle - constraint.rest_angle;
+        if deviation > PI {
+            deviation -= 2.0 * PI;
+        } else if deviation < -PI {
+            deviation += 2.0 * PI;
+        }
+        draw(positions, 0.5 * deviation.powi(2));
+    }
+}
@@ -0,0 +1,376 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// A debugging tool for picking up and dragging [rigid bodies](RigidBody) and soft body particles with the mouse.
+///
+/// When the [`mouse_button`](PhysicsPickingConfig::mouse_button) is pressed, a ray is cast from the cursor
+/// using [`SpatialQuery::cast_ray`]. In 2D, the colliders containing the cursor position are used instead.
+/// If the first hit collider is attached to a dynamic body, the body is attached to the cursor at the hit point
+/// with a compliant [`DistanceJoint`] that has a rest length of zero.
+///
+/// The triangles of [soft body surfaces](SoftBodySurface) can be picked as well. In 3D, the hit closest to the camera
+/// is used, and in 2D, the surfaces are only picked when there is no collider at the cursor. When a surface triangle
+/// is picked, the particle of the triangle closest to the hit point is attached to the cursor at its center.
+///
+/// The other end of the joint is an invisible kinematic body that follows the cursor, so the dragged body
+/// is pulled towards the cursor by the solver like by any other joint instead of being moved directly.
+/// In 3D, the cursor body stays at the distance where the body was hit.
+///
+/// The plugin uses the first active [`Camera3d`] in 3D and [`Camera2d`] in 2D, and it can be configured
+/// using the [`PhysicsPickingConfig`] resource.
+///
+/// ## Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+#[cfg_attr(feature = "2d", doc = "use bevy_xpbd_2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use bevy_xpbd_3d::prelude::*;")]
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             PhysicsPlugins::default(),
+///             PhysicsPickingPlugin,
+///         ))
+///         .run();
+/// }
+/// ```
+pub struct PhysicsPickingPlugin;
+
+impl Plugin for PhysicsPickingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhysicsPickingConfig>()
+            .register_type::<PhysicsPickingConfig>()
+            .add_systems(Update, drag_picked_body);
+    }
+}
+
+/// Configures the [`PhysicsPickingPlugin`].
+#[derive(Reflect, Resource, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource)]
+pub struct PhysicsPickingConfig {
+    /// Determines if picking is enabled.
+    pub enabled: bool,
+    /// The mouse button used for picking and dragging bodies.
+    pub mouse_button: MouseButton,
+    /// The compliance of the joint that attaches the dragged body to the cursor.
+    /// Larger values make the dragging softer.
+    pub compliance: Scalar,
+    /// The linear velocity damping of the joint that attaches the dragged body to the cursor.
+    pub damping: Scalar,
+    /// The color of the line from the anchor on the dragged body to the cursor.
+    /// If `None`, the line is not rendered.
+    pub color: Option<Color>,
+}
+
+impl Default for PhysicsPickingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            mouse_button: MouseButton::Left,
+            compliance: 0.0001,
+            damping: 1.0,
+            color: Some(Color::hex("#568C4D").unwrap()),
+        }
+    }
+}
+
+/// The body that is currently being dragged.
+#[derive(Clone, Copy, Debug)]
+struct PickingState {
+    /// The dragged rigid body.
+    body: Entity,
+    /// The kinematic body that follows the cursor.
+    cursor: Entity,
+    /// The entity of the [`DistanceJoint`] between the cursor and the dragged body.
+    joint: Entity,
+    /// The anchor of the joint in the local space of the dragged body.
+    local_anchor: Vector,
+    /// The distance from the camera along the picking ray.
+    #[cfg(feature = "3d")]
+    distance: Scalar,
+}
+
+#[cfg(feature = "2d")]
+type PickingCamera = Camera2d;
+#[cfg(feature = "3d")]
+type PickingCamera = Camera3d;
+
+#[allow(clippy::too_many_arguments)]
+fn drag_picked_body(
+    mut commands: Commands,
+    cameras: Query<(&Camera, &GlobalTransform), With<PickingCamera>>,
+    windows: Query<&Window>,
+    buttons: Res<Input<MouseButton>>,
+    spatial_query: SpatialQuery,
+    colliders: Query<&ColliderParent>,
+    surfaces: Query<&SoftBodySurface>,
+    mut bodies: Query<(&RigidBody, &mut Position, &Rotation)>,
+    config: Res<PhysicsPickingConfig>,
+    mut renderer: PhysicsDebugRenderer,
+    mut state: Local<Option<PickingState>>,
+) {
+    let release = |commands: &mut Commands, state: PickingState| {
+        commands.entity(state.joint).despawn();
+        commands.entity(state.cursor).despawn();
+    };
+
+    if !config.enabled || buttons.just_released(config.mouse_button) {
+        if let Some(state) = state.take() {
+            release(&mut commands, state);
+        }
+        return;
+    }
+
+    let Some(cursor_position) = windows
+        .get_single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+    else {
+        return;
+    };
+    let Some((camera, camera_transform)) = cameras.iter().find(|(camera, _)| camera.is_active)
+    else {
+        return;
+    };
+
+    #[cfg(feature = "2d")]
+    let Some(point) = camera
+        .viewport_to_world_2d(camera_transform, cursor_position)
+        .map(|point| point.adjust_precision())
+    else {
+        return;
+    };
+    #[cfg(feature = "3d")]
+    let Some(ray) = camera.viewport_to_world(camera_transform, cursor_position) else {
+        return;
+    };
+    #[cfg(feature = "3d")]
+    let (origin, direction) = (
+        ray.origin.adjust_precision(),
+        ray.direction.adjust_precision(),
+    );
+
+    if buttons.just_pressed(config.mouse_button) {
+        if let Some(state) = state.take() {
+            release(&mut commands, state);
+        }
+
+        #[cfg(feature = "2d")]
+        let hit = spatial_query
+            .point_intersections(point, SpatialQueryFilter::default())
+            .first()
+            .map(|&entity| (entity, point));
+        #[cfg(feature = "3d")]
+        let hit = spatial_query
+            .cast_ray(
+                origin,
+                direction,
+                Scalar::MAX,
+                true,
+                SpatialQueryFilter::default(),
+            )
+            .map(|hit| (hit.entity, origin + direction * hit.time_of_impact));
+
+        // The body, the point where it is attached to the cursor, and the initial cursor position
+        let collider_hit = hit.map(|(collider, hit_point)| {
+            let body = colliders
+                .get(collider)
+                .map_or(collider, |parent| parent.get());
+            (body, hit_point, hit_point)
+        });
+        let surface_hit = pick_soft_body_surface(
+            &surfaces,
+            |particle| bodies.get(particle).ok().map(|(_, position, _)| position.0),
+            #[cfg(feature = "2d")]
+            point,
+            #[cfg(feature = "3d")]
+            origin,
+            #[cfg(feature = "3d")]
+            direction,
+        )
+        .and_then(|(particle, hit_point)| {
+            let (_, position, _) = bodies.get(particle).ok()?;
+            Some((particle, position.0, hit_point))
+        });
+
+        #[cfg(feature = "2d")]
+        let hit = collider_hit.or(surface_hit);
+        #[cfg(feature = "3d")]
+        let hit = match (collider_hit, surface_hit) {
+            (Some(collider_hit), Some(surface_hit)) => {
+                let distance = |hit_point: Vector| (hit_point - origin).length_squared();
+                if distance(surface_hit.2) < distance(collider_hit.2) {
+                    Some(surface_hit)
+                } else {
+                    Some(collider_hit)
+                }
+            }
+            (collider_hit, surface_hit) => collider_hit.or(surface_hit),
+        };
+
+        let Some((body, anchor, hit_point)) = hit else {
+            return;
+        };
+        let Ok((rb, position, rotation)) = bodies.get(body) else {
+            return;
+        };
+        if !rb.is_dynamic() {
+            return;
+        }
+
+        let local_anchor = rotation.inverse().rotate(anchor - position.0);
+        let cursor = commands
+            .spawn((RigidBody::Kinematic, Position(hit_point)))
+            .id();
+        let joint = commands
+            .spawn(
+                DistanceJoint::new(cursor, body)
+                    .with_local_anchor_2(local_anchor)
+                    .with_rest_length(0.0)
+                    .with_compliance(config.compliance)
+                    .with_linear_velocity_damping(config.damping),
+            )
+            .id();
+
+        *state = Some(PickingState {
+            body,
+            cursor,
+            joint,
+            local_anchor,
+            #[cfg(feature = "3d")]
+            distance: (hit_point - origin).length(),
+        });
+        return;
+    }
+
+    let Some(picking) = *state else {
+        return;
+    };
+
+    // Release the body if it has been despawned
+    let Ok((_, position, rotation)) = bodies.get(picking.body) else {
+        release(&mut commands, picking);
+        *state = None;
+        return;
+    };
+    let anchor = position.0 + rotation.rotate(picking.local_anchor);
+
+    #[cfg(feature = "2d")]
+    let target = point;
+    #[cfg(feature = "3d")]
+    let target = origin + direction * picking.distance;
+
+    // The cursor body is spawned with commands, so it might not exist yet
+    if let Ok((_, mut cursor_position, _)) = bodies.get_mut(picking.cursor) {
+        cursor_position.0 = target;
+    }
+
+    if let Some(color) = config.color {
+        renderer.draw_line(anchor, target, color);
+        #[cfg(feature = "2d")]
+        renderer.gizmos.circle_2d(target.as_f32(), 3.0, color);
+        #[cfg(feature = "3d")]
+        renderer
+            .gizmos
+            .sphere(target.as_f32(), Quat::IDENTITY, 0.05, color);
+    }
+}
+
+/// Finds the [`SoftBodySurface`] triangle at the cursor and returns the particle of the triangle
+/// closest to the hit point, along with the hit point.
+///
+/// In 2D, the first triangle containing the `point` is used. In 3D, the triangle closest to the `origin`
+/// along the ray is used. Triangles with particles that have no position are ignored.
+pub(crate) fn pick_soft_body_surface<'a>(
+    surfaces: impl IntoIterator<Item = &'a SoftBodySurface>,
+    positions: impl Fn(Entity) -> Option<Vector>,
+    #[cfg(feature = "2d")] point: Vector,
+    #[cfg(feature = "3d")] origin: Vector,
+    #[cfg(feature = "3d")] direction: Vector,
+) -> Option<(Entity, Vector)> {
+    let triangles = surfaces.into_iter().flat_map(|surface| &surface.triangles);
+
+    #[cfg(feature = "2d")]
+    let (triangle, hit_point) = {
+        let triangle = triangles.copied().find(|&triangle| {
+            let Some([a, b, c]) = triangle_positions(triangle, &positions) else {
+                return false;
+            };
+            // The point is inside if it is on the same side of all three edges
+            let sides = [
+                (b - a).perp_dot(point - a),
+                (c - b).perp_dot(point - b),
+                (a - c).perp_dot(point - c),
+            ];
+            !(sides.iter().any(|&side| side < 0.0) && sides.iter().any(|&side| side > 0.0))
+        })?;
+        (triangle, point)
+    };
+    #[cfg(feature = "3d")]
+    let (triangle, hit_point) = {
+        let (triangle, time_of_impact) = triangles
+            .filter_map(|&triangle| {
+                let [a, b, c] = triangle_positions(triangle, &positions)?;
+                Some((
+                    triangle,
+                    ray_triangle_intersection(origin, direction, [a, b, c])?,
+                ))
+            })
+            .min_by(|(_, t1), (_, t2)| t1.total_cmp(t2))?;
+        (triangle, origin + direction * time_of_impact)
+    };
+
+    let positions = triangle_positions(triangle, &positions)?;
+    let (particle, _) = triangle
+        .into_iter()
+        .zip(positions)
+        .min_by(|(_, p1), (_, p2)| {
+            p1.distance_squared(hit_point)
+                .total_cmp(&p2.distance_squared(hit_point))
+        })?;
+    Some((particle, hit_point))
+}
+
+/// Returns the positions of the particles of a triangle, or `None` if a particle has no position.
+fn triangle_positions(
+    triangle: [Entity; 3],
+    positions: &impl Fn(Entity) -> Option<Vector>,
+) -> Option<[Vector; 3]> {
+    Some([
+        positions(triangle[0])?,
+        positions(triangle[1])?,
+        positions(triangle[2])?,
+    ])
+}
+
+/// Computes the time of impact of a ray and a triangle using the Möller–Trumbore algorithm.
+#[cfg(feature = "3d")]
+fn ray_triangle_intersection(
+    origin: Vector,
+    direction: Vector,
+    [a, b, c]: [Vector; 3],
+) -> Option<Scalar> {
+    let (edge1, edge2) = (b - a, c - a);
+    let p = direction.cross(edge2);
+    let determinant = edge1.dot(p);
+    // The ray is parallel to the triangle
+    if determinant.abs() < Scalar::EPSILON {
+        return None;
+    }
+    let inv_determinant = determinant.recip();
+    let s = origin - a;
+    let u = s.dot(p) * inv_determinant;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = s.cross(edge1);
+    let v = direction.dot(q) * inv_determinant;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let time_of_impact = edge2.dot(q) * inv_determinant;
+    (time_of_impact >= 0.0).then_some(time_of_impact)
+}
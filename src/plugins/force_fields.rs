@@ -0,0 +1,345 @@
+//! Applies forces to bodies inside of [`ForceField`] volumes.
+//!
+//! See [`ForceFieldPlugin`].
+
+use crate::{plugins::integrator::SubstepForces, prelude::*};
+use bevy::prelude::*;
+
+/// Applies forces to [rigid bodies](RigidBody) and particles inside of [`ForceField`] volumes.
+///
+/// Force fields can be used for things like wind zones, fans, black holes and whirlpools,
+/// or the lingering push of an explosion.
+///
+/// The forces are computed before [`SubstepSet::Integrate`] in each substep and integrated
+/// together with the [`ExternalForce`]s of the affected bodies, without modifying them.
+pub struct ForceFieldPlugin;
+
+impl Plugin for ForceFieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SubstepForces>()
+            .register_type::<ForceField>();
+
+        app.get_schedule_mut(SubstepSchedule)
+            .expect("add SubstepSchedule first")
+            .add_systems(apply_force_fields.before(SubstepSet::Integrate));
+    }
+}
+
+/// A volume that applies forces to the [rigid bodies](RigidBody) and particles inside of it.
+///
+/// The volume is defined by the [`Collider`] of the entity, and a body is affected when its
+/// [`Position`] is inside of the collider. The collider should usually be a [`Sensor`] so that
+/// it doesn't block the bodies it affects.
+///
+/// Only bodies whose [`CollisionLayers`] interact with the [`CollisionLayers`] of the force field are affected.
+/// The strength of the force can be scaled based on the distance to the center of the field using a [`ForceFieldFalloff`].
+///
+/// See [`ForceFieldKind`] for the available types of force fields.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+#[cfg_attr(feature = "2d", doc = "use bevy_xpbd_2d::prelude::*;")]
+#[cfg_attr(feature = "3d", doc = "use bevy_xpbd_3d::prelude::*;")]
+///
+/// # #[cfg(feature = "f32")]
+/// fn setup(mut commands: Commands) {
+///     // A wind zone that blows bodies upwards
+///     commands.spawn((
+#[cfg_attr(
+    feature = "2d",
+    doc = "        ForceField::wind(Vec2::Y * 20.0).with_turbulence(0.5, 1.0),"
+)]
+#[cfg_attr(
+    feature = "3d",
+    doc = "        ForceField::wind(Vec3::Y * 20.0).with_turbulence(0.5, 1.0),"
+)]
+#[cfg_attr(feature = "2d", doc = "        Collider::cuboid(4.0, 10.0),")]
+#[cfg_attr(feature = "3d", doc = "        Collider::cuboid(4.0, 10.0, 4.0),")]
+///         Sensor,
+///     ));
+///
+///     // A black hole that pulls bodies towards its center
+///     commands.spawn((
+///         ForceField::radial(-50.0).with_falloff(ForceFieldFalloff::Linear { distance: 8.0 }),
+///         Collider::ball(8.0),
+///         Sensor,
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ForceField {
+    /// The type of the force field.
+    pub kind: ForceFieldKind,
+    /// How the strength of the force changes based on the distance to the center of the field.
+    pub falloff: ForceFieldFalloff,
+}
+
+impl ForceField {
+    /// Creates a force field that pushes bodies with the given `force`.
+    pub fn wind(force: Vector) -> Self {
+        Self::new(ForceFieldKind::Wind {
+            force,
+            turbulence: 0.0,
+            frequency: 1.0,
+        })
+    }
+
+    /// Creates a force field that pushes bodies away from its center with the given `strength`.
+    /// A negative strength pulls bodies towards the center.
+    pub fn radial(strength: Scalar) -> Self {
+        Self::new(ForceFieldKind::Radial { strength })
+    }
+
+    /// Creates a force field that pushes bodies around its center with the given `strength`.
+    /// A positive strength pushes bodies counterclockwise.
+    #[cfg(feature = "2d")]
+    pub fn vortex(strength: Scalar) -> Self {
+        Self::new(ForceFieldKind::Vortex { strength })
+    }
+
+    /// Creates a force field that pushes bodies around the given `axis` going through its center
+    /// with the given `strength`. A positive strength pushes bodies counterclockwise around the axis.
+    #[cfg(feature = "3d")]
+    pub fn vortex(axis: Vector, strength: Scalar) -> Self {
+        Self::new(ForceFieldKind::Vortex { axis, strength })
+    }
+
+    /// Creates a force field that slows bodies down with a force proportional to their velocity.
+    pub fn drag(coefficient: Scalar) -> Self {
+        Self::new(ForceFieldKind::Drag { coefficient })
+    }
+
+    fn new(kind: ForceFieldKind) -> Self {
+        Self {
+            kind,
+            falloff: ForceFieldFalloff::None,
+        }
+    }
+
+    /// Sets the turbulence of a [wind](ForceFieldKind::Wind) force field.
+    ///
+    /// `turbulence` is the maximum random deviation relative to the force, and `frequency`
+    /// controls how quickly the deviation changes in space and time. Other force fields are unaffected.
+    pub fn with_turbulence(mut self, turbulence: Scalar, frequency: Scalar) -> Self {
+        if let ForceFieldKind::Wind {
+            turbulence: ref mut current_turbulence,
+            frequency: ref mut current_frequency,
+            ..
+        } = self.kind
+        {
+            *current_turbulence = turbulence;
+            *current_frequency = frequency;
+        }
+        self
+    }
+
+    /// Sets the [falloff](ForceFieldFalloff) of the force field.
+    pub fn with_falloff(self, falloff: ForceFieldFalloff) -> Self {
+        Self { falloff, ..self }
+    }
+
+    /// Computes the force applied to a body at the given `position` moving with the given `velocity`.
+    ///
+    /// `center` is the position of the force field and `time` is the elapsed time used for turbulence.
+    /// The falloff is taken into account, but the volume of the field is not.
+    pub fn force_at(
+        &self,
+        center: Vector,
+        position: Vector,
+        velocity: Vector,
+        time: Scalar,
+    ) -> Vector {
+        let offset = position - center;
+
+        let force = match self.kind {
+            ForceFieldKind::Wind {
+                force,
+                turbulence,
+                frequency,
+            } => {
+                if turbulence == 0.0 {
+                    force
+                } else {
+                    force
+                        + force.length() * turbulence * turbulence_noise(position, time, frequency)
+                }
+            }
+            ForceFieldKind::Radial { strength } => strength * offset.normalize_or_zero(),
+            #[cfg(feature = "2d")]
+            ForceFieldKind::Vortex { strength } => {
+                strength * Vector::new(-offset.y, offset.x).normalize_or_zero()
+            }
+            #[cfg(feature = "3d")]
+            ForceFieldKind::Vortex { axis, strength } => {
+                strength * axis.cross(offset).normalize_or_zero()
+            }
+            ForceFieldKind::Drag { coefficient } => -coefficient * velocity,
+        };
+
+        force * self.falloff.factor(offset.length())
+    }
+}
+
+/// The type of a [`ForceField`].
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ForceFieldKind {
+    /// Pushes bodies in a direction, optionally with turbulence.
+    Wind {
+        /// The force applied to bodies.
+        force: Vector,
+        /// The maximum random deviation of the force relative to its magnitude.
+        turbulence: Scalar,
+        /// How quickly the turbulence changes in space and time.
+        frequency: Scalar,
+    },
+    /// Pushes bodies away from the center of the field, or towards it if the strength is negative.
+    Radial {
+        /// The magnitude of the force.
+        strength: Scalar,
+    },
+    /// Pushes bodies around the center of the field, counterclockwise if the strength is positive.
+    Vortex {
+        /// The axis that bodies are pushed around.
+        #[cfg(feature = "3d")]
+        axis: Vector,
+        /// The magnitude of the force.
+        strength: Scalar,
+    },
+    /// Slows bodies down with a force proportional to their velocity.
+    Drag {
+        /// The ratio between the force and the velocity.
+        coefficient: Scalar,
+    },
+}
+
+/// Determines how the strength of a [`ForceField`] changes based on the distance
+/// to the center of the field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ForceFieldFalloff {
+    /// The strength is the same everywhere inside of the field.
+    #[default]
+    None,
+    /// The strength decreases linearly from full strength at the center to zero at the given distance.
+    Linear {
+        /// The distance at which the strength reaches zero.
+        distance: Scalar,
+    },
+    /// The strength decreases quadratically from full strength at the center to zero at the given distance.
+    Quadratic {
+        /// The distance at which the strength reaches zero.
+        distance: Scalar,
+    },
+}
+
+impl ForceFieldFalloff {
+    /// Returns the factor that the force is multiplied by at the given distance from the center of the field.
+    pub fn factor(&self, distance: Scalar) -> Scalar {
+        match *self {
+            Self::None => 1.0,
+            Self::Linear { distance: max } => (1.0 - distance / max).max(0.0),
+            Self::Quadratic { distance: max } => (1.0 - distance / max).max(0.0).powi(2),
+        }
+    }
+}
+
+/// Returns a smoothly changing pseudo-random vector with components in the range `[-1, 1]`.
+fn turbulence_noise(position: Vector, time: Scalar, frequency: Scalar) -> Vector {
+    let p = position * frequency;
+    let t = time * frequency;
+
+    #[cfg(feature = "2d")]
+    {
+        Vector::new(
+            (p.y * 1.7 + t * 1.3).sin() * (p.x * 0.9 - t * 0.7).cos(),
+            (p.x * 1.3 - t * 1.1).sin() * (p.y * 0.8 + t * 0.5).cos(),
+        )
+    }
+    #[cfg(feature = "3d")]
+    {
+        Vector::new(
+            (p.y * 1.7 + p.z * 0.6 + t * 1.3).sin() * (p.x * 0.9 - t * 0.7).cos(),
+            (p.z * 1.3 - p.x * 0.5 - t * 1.1).sin() * (p.y * 0.8 + t * 0.5).cos(),
+            (p.x * 1.1 + p.y * 0.7 + t * 0.9).sin() * (p.z * 1.2 - t * 0.6).cos(),
+        )
+    }
+}
+
+type ForceFieldComponents = (
+    Entity,
+    &'static ForceField,
+    &'static Collider,
+    &'static ColliderAabb,
+    &'static Position,
+    &'static Rotation,
+    Option<&'static CollisionLayers>,
+);
+
+type ForceFieldBodyComponents = (
+    Entity,
+    &'static RigidBody,
+    &'static Position,
+    &'static LinearVelocity,
+    &'static Mass,
+    Option<&'static CollisionLayers>,
+    &'static mut TimeSleeping,
+    Has<Sleeping>,
+);
+
+/// Computes the forces of each [`ForceField`] and adds them to the [`SubstepForces`]
+/// of the bodies inside of the fields.
+///
+/// Drag forces are clamped so that they can't reverse the velocity of a body within a single substep.
+/// Sleeping bodies that receive a force are woken up.
+pub(crate) fn apply_force_fields(
+    mut commands: Commands,
+    fields: Query<ForceFieldComponents>,
+    mut bodies: Query<ForceFieldBodyComponents>,
+    time: Res<Time>,
+    physics_time: Res<Time<Physics>>,
+    mut forces: ResMut<SubstepForces>,
+) {
+    let delta_secs = time.delta_seconds_adjusted();
+
+    if delta_secs == 0.0 || fields.is_empty() {
+        return;
+    }
+
+    let elapsed_secs = physics_time.elapsed().as_secs_adjusted();
+
+    for (field_entity, field, collider, aabb, field_pos, field_rot, field_layers) in &fields {
+        let field_layers = field_layers.copied().unwrap_or_default();
+
+        for (entity, rb, pos, lin_vel, mass, layers, mut time_sleeping, is_sleeping) in &mut bodies
+        {
+            // Check the AABB updated by the broad phase before the more expensive exact containment test
+            if entity == field_entity
+                || !rb.is_dynamic()
+                || !aabb.contains_local_point(&pos.0.into())
+                || !field_layers.interacts_with(layers.copied().unwrap_or_default())
+                || !collider.contains_point(*field_pos, *field_rot, pos.0)
+            {
+                continue;
+            }
+
+            let mut force = field.force_at(field_pos.0, pos.0, lin_vel.0, elapsed_secs);
+
+            if let ForceFieldKind::Drag { .. } = field.kind {
+                force = force.clamp_length_max(mass.0 * lin_vel.length() / delta_secs);
+            }
+
+            if force != Vector::ZERO {
+                forces.add(entity, force);
+
+                if is_sleeping {
+                    commands.entity(entity).remove::<Sleeping>();
+                }
+                time_sleeping.0 = 0.0;
+            }
+        }
+    }
+}
@@ -3,7 +3,7 @@
 //! See [`IntegratorPlugin`].
 
 use crate::prelude::*;
-use bevy::prelude::*;
+use bevy::{prelude::*, utils::HashMap};
 
 /// Integrates Newton's 2nd law of motion, applying forces and moving entities according to their velocities.
 ///
@@ -17,9 +17,16 @@ pub struct IntegratorPlugin;
 
 impl Plugin for IntegratorPlugin {
     fn build(&self, app: &mut App) {
+        app.init_resource::<SubstepForces>();
+
         app.get_schedule_mut(SubstepSchedule)
             .expect("add SubstepSchedule first")
-            .add_systems((integrate_pos, integrate_rot).in_set(SubstepSet::Integrate));
+            .add_systems(
+                (integrate_pos, integrate_rot)
+                    .chain()
+                    .in_set(SubstepSet::Integrate),
+            )
+            .add_systems(clear_substep_forces.after(SubstepSet::ApplyTranslation));
         app.get_schedule_mut(PhysicsSchedule)
             .expect("add PhysicsSchedule first")
             .add_systems(
@@ -31,7 +38,34 @@ impl Plugin for IntegratorPlugin {
     }
 }
 
+/// Forces that plugins like the [`ForceFieldPlugin`] apply to bodies in the current substep,
+/// in addition to their [`ExternalForce`]s.
+///
+/// The forces are added before [`SubstepSet::Integrate`], applied at the center of mass
+/// during integration and cleared at the end of the substep, so they never change the
+/// [`ExternalForce`]s of the bodies.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct SubstepForces(HashMap<Entity, Vector>);
+
+impl SubstepForces {
+    /// Adds a force to the given body for the current substep.
+    pub(crate) fn add(&mut self, entity: Entity, force: Vector) {
+        *self.0.entry(entity).or_default() += force;
+    }
+
+    /// Returns the force added to the given body for the current substep.
+    pub(crate) fn get(&self, entity: Entity) -> Vector {
+        self.0.get(&entity).copied().unwrap_or_default()
+    }
+}
+
+/// Clears the [`SubstepForces`] at the end of the substep.
+fn clear_substep_forces(mut forces: ResMut<SubstepForces>) {
+    forces.0.clear();
+}
+
 type PosIntegrationComponents = (
+    Entity,
     &'static RigidBody,
     &'static Position,
     &'static mut PreviousPosition,
@@ -43,18 +77,32 @@ type PosIntegrationComponents = (
     &'static Mass,
     &'static InverseMass,
     Option<&'static LockedAxes>,
+    Option<&'static CollisionLayers>,
+);
+
+type GravityFieldComponents = (
+    Entity,
+    &'static GravityField,
+    Option<&'static Position>,
+    Option<&'static Rotation>,
+    Option<&'static Collider>,
+    Option<&'static ColliderAabb>,
+    Option<&'static CollisionLayers>,
 );
 
 /// Explicitly integrates the positions and linear velocities of bodies taking only external forces
 /// like gravity into account. This acts as a prediction for the next positions of the bodies.
 fn integrate_pos(
     mut bodies: Query<PosIntegrationComponents, Without<Sleeping>>,
+    gravity_fields: Query<GravityFieldComponents>,
     gravity: Res<Gravity>,
+    substep_forces: Res<SubstepForces>,
     time: Res<Time>,
 ) {
     let delta_secs = time.delta_seconds_adjusted();
 
     for (
+        entity,
         rb,
         pos,
         mut prev_pos,
@@ -66,6 +114,7 @@ fn integrate_pos(
         mass,
         inv_mass,
         locked_axes,
+        layers,
     ) in &mut bodies
     {
         prev_pos.0 = pos.0;
@@ -86,10 +135,20 @@ fn integrate_pos(
             let effective_mass = locked_axes.apply_to_vec(Vector::splat(mass.0));
             let effective_inv_mass = locked_axes.apply_to_vec(Vector::splat(inv_mass.0));
 
+            // Gravity fields override the global gravity
+            let gravity = gravity_field_acceleration(
+                entity,
+                pos.0 + translation.0,
+                layers.copied().unwrap_or_default(),
+                &gravity_fields,
+            )
+            .unwrap_or(gravity.0);
+
             // Apply forces
             let gravitation_force =
-                effective_mass * gravity.0 * gravity_scale.map_or(1.0, |scale| scale.0);
-            let external_forces = gravitation_force + external_force.force();
+                effective_mass * gravity * gravity_scale.map_or(1.0, |scale| scale.0);
+            let external_forces =
+                gravitation_force + external_force.force() + substep_forces.get(entity);
             let delta_lin_vel = delta_secs * external_forces * effective_inv_mass;
             // avoid triggering bevy's change detection unnecessarily
             if delta_lin_vel != Vector::ZERO {
@@ -102,6 +161,54 @@ fn integrate_pos(
     }
 }
 
+/// Computes the gravitational acceleration at the given `point` caused by the [`GravityField`]s containing it,
+/// ignoring fields on the given `entity` and fields whose [`CollisionLayers`] don't interact with `layers`.
+///
+/// Fields without a [`Collider`] contain every point.
+/// Only the fields with the highest priority are taken into account.
+/// Returns `None` if the point isn't inside of any field.
+fn gravity_field_acceleration(
+    entity: Entity,
+    point: Vector,
+    layers: CollisionLayers,
+    gravity_fields: &Query<GravityFieldComponents>,
+) -> Option<Vector> {
+    let mut result: Option<(i32, Vector)> = None;
+
+    for (field_entity, field, position, rotation, collider, aabb, field_layers) in gravity_fields {
+        let position = position.copied().unwrap_or_default();
+        let rotation = rotation.copied().unwrap_or_default();
+
+        if field_entity == entity
+            || !field_layers
+                .copied()
+                .unwrap_or_default()
+                .interacts_with(layers)
+        {
+            continue;
+        }
+
+        // Check the AABB updated by the broad phase before the more expensive exact containment test
+        if let Some(collider) = collider {
+            if aabb.is_some_and(|aabb| !aabb.contains_local_point(&point.into()))
+                || !collider.contains_point(position, rotation, point)
+            {
+                continue;
+            }
+        }
+
+        let acceleration = field.acceleration_at(position.0, &rotation, point);
+
+        match result {
+            Some((priority, ref mut sum)) if priority == field.priority => *sum += acceleration,
+            Some((priority, _)) if priority > field.priority => {}
+            _ => result = Some((field.priority, acceleration)),
+        }
+    }
+
+    result.map(|(_, acceleration)| acceleration)
+}
+
 type RotIntegrationComponents = (
     &'static RigidBody,
     &'static mut Rotation,
@@ -246,7 +353,8 @@ type ImpulseQueryComponents = (
     Option<&'static LockedAxes>,
 );
 
-fn apply_impulses(mut bodies: Query<ImpulseQueryComponents, Without<Sleeping>>) {
+/// Applies [`ExternalImpulse`]s and [`ExternalAngularImpulse`]s to the velocities of bodies.
+pub fn apply_impulses(mut bodies: Query<ImpulseQueryComponents, Without<Sleeping>>) {
     for (
         rb,
         impulse,
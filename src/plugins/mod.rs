@@ -20,26 +20,44 @@
 //! - [`PhysicsSchedule`] and [`PhysicsStepSet`]
 //! - [`SubstepSchedule`] and [`SubstepSet`]
 
+#[cfg(feature = "3d")]
+pub mod aerodynamics;
+#[cfg(feature = "3d")]
+pub mod articulation;
 pub mod collision;
+#[cfg(feature = "3d")]
+pub mod cutting;
 #[cfg(feature = "debug-plugin")]
 pub mod debug;
+pub mod force_fields;
 pub mod integrator;
 pub mod prepare;
+#[cfg(all(feature = "3d", feature = "async-collider"))]
+pub mod ragdoll;
 pub mod setup;
 pub mod sleeping;
 pub mod solver;
 pub mod spatial_query;
 pub mod sync;
 
+#[cfg(feature = "3d")]
+pub use aerodynamics::AerodynamicsPlugin;
+#[cfg(feature = "3d")]
+pub use articulation::ArticulationPlugin;
 use bevy::utils::intern::Interned;
 pub use collision::{
-    broad_phase::BroadPhasePlugin, contact_reporting::ContactReportingPlugin,
-    narrow_phase::NarrowPhasePlugin,
+    broad_phase::BroadPhasePlugin, contact_modification::ContactModificationPlugin,
+    contact_reporting::ContactReportingPlugin, narrow_phase::NarrowPhasePlugin,
 };
+#[cfg(feature = "3d")]
+pub use cutting::CuttingPlugin;
 #[cfg(feature = "debug-plugin")]
 pub use debug::PhysicsDebugPlugin;
+pub use force_fields::ForceFieldPlugin;
 pub use integrator::IntegratorPlugin;
 pub use prepare::PreparePlugin;
+#[cfg(all(feature = "3d", feature = "async-collider"))]
+pub use ragdoll::RagdollPlugin;
 pub use setup::PhysicsSetupPlugin;
 pub use sleeping::SleepingPlugin;
 pub use solver::SolverPlugin;
@@ -60,10 +78,28 @@ use bevy::prelude::*;
 /// - [`BroadPhasePlugin`]: Collects pairs of potentially colliding entities into [`BroadCollisionPairs`] using
 /// [AABB](ColliderAabb) intersection checks.
 /// - [`IntegratorPlugin`]: Integrates Newton's 2nd law of motion, applying forces and moving entities according to their velocities.
+#[cfg_attr(
+    feature = "3d",
+    doc = "- [`AerodynamicsPlugin`]: Applies aerodynamic drag and lift to cloth and other surfaces made of particles."
+)]
+/// - [`ForceFieldPlugin`]: Applies forces to bodies inside of [`ForceField`] volumes.
 /// - [`NarrowPhasePlugin`]: Computes contacts between entities and sends collision events.
+/// - [`ContactModificationPlugin`]: Filters contacts for [`OneWayCollider`]s and runs [`CollisionHook`]s.
 /// - [`ContactReportingPlugin`]: Sends collision events and updates [`CollidingEntities`].
 /// - [`SolverPlugin`]: Solves positional and angular [constraints], updates velocities and solves velocity constraints
 /// (dynamic [friction](Friction) and [restitution](Restitution)).
+#[cfg_attr(
+    feature = "3d",
+    doc = "- [`CuttingPlugin`]: Cuts and fractures soft bodies built from [`VolumeConstraint`]s and [`EdgeConstraint`]s."
+)]
+#[cfg_attr(
+    all(feature = "3d", feature = "async-collider"),
+    doc = "- [`RagdollPlugin`]: Builds [`Ragdoll`]s from the skeletons of skinned meshes and switches them between animated and simulated modes."
+)]
+#[cfg_attr(
+    feature = "3d",
+    doc = "- [`ArticulationPlugin`]: Simulates [`Articulation`]s, trees of rigid bodies connected by joints, in reduced coordinates."
+)]
 /// - [`SleepingPlugin`]: Controls when bodies should be deactivated and marked as [`Sleeping`] to improve performance.
 /// - [`SpatialQueryPlugin`]: Handles spatial queries like [raycasting](RayCaster) and shapecasting.
 /// - [`SyncPlugin`]: Keeps [`Position`] and [`Rotation`] in sync with `Transform`.
@@ -186,14 +222,32 @@ impl Default for PhysicsPlugins {
 
 impl PluginGroup for PhysicsPlugins {
     fn build(self) -> PluginGroupBuilder {
-        PluginGroupBuilder::start::<Self>()
+        let builder = PluginGroupBuilder::start::<Self>()
             .add(PhysicsSetupPlugin::new(self.schedule))
             .add(PreparePlugin::new(self.schedule))
             .add(BroadPhasePlugin)
-            .add(IntegratorPlugin)
+            .add(IntegratorPlugin);
+
+        #[cfg(feature = "3d")]
+        let builder = builder.add(AerodynamicsPlugin);
+
+        let builder = builder
+            .add(ForceFieldPlugin)
             .add(NarrowPhasePlugin)
+            .add(ContactModificationPlugin)
             .add(ContactReportingPlugin)
-            .add(SolverPlugin)
+            .add(SolverPlugin);
+
+        #[cfg(feature = "3d")]
+        let builder = builder.add(CuttingPlugin);
+
+        #[cfg(all(feature = "3d", feature = "async-collider"))]
+        let builder = builder.add(RagdollPlugin);
+
+        #[cfg(feature = "3d")]
+        let builder = builder.add(ArticulationPlugin);
+
+        builder
             .add(SleepingPlugin)
             .add(SpatialQueryPlugin::new(self.schedule))
             .add(SyncPlugin::new(self.schedule))
@@ -22,6 +22,7 @@ use bevy::{
 /// - Adds missing mass properties for entities with a [`RigidBody`] or [`Collider`] component
 /// - Updates mass properties and adds [`ColliderMassProperties`] on top of the existing mass properties
 /// - Clamps restitution coefficients between 0 and 1
+/// - Respawns the [`WorldBody`] if it has been despawned
 ///
 /// The [`Transform`] component will be initialized based on [`Position`] or [`Rotation`]
 /// and vice versa. You can configure this synchronization using the [`PrepareConfig`] resource.
@@ -100,7 +101,13 @@ impl Plugin for PreparePlugin {
 
         app.init_resource::<ColliderStorageMap>()
             .init_resource::<PrepareConfig>()
+            .init_resource::<WorldBodyEntity>()
             .register_type::<PrepareConfig>()
+            .register_type::<WorldBody>()
+            .add_systems(
+                self.schedule,
+                validate_world_body.in_set(PrepareSet::PreInit),
+            )
             .add_systems(
                 self.schedule,
                 (
@@ -172,6 +179,52 @@ impl Plugin for PreparePlugin {
     }
 }
 
+/// The entity of the static [`WorldBody`] that represents the world in [constraints] and [joints].
+///
+/// Use it as one of the entities of a constraint to attach the constraint to a fixed point in space instead of a body.
+/// Local anchors on the world body are world-space points.
+///
+/// The entity is spawned as a static [rigid body](RigidBody) at the origin when the [`PreparePlugin`] is added,
+/// so any constraint, including custom ones, can use it.
+///
+/// If the world body is despawned, a new one is spawned and a warning is logged. Constraints attached to the old body
+/// are not updated, so they stop being solved until they are attached to the new body.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WorldBodyEntity(pub Entity);
+
+impl FromWorld for WorldBodyEntity {
+    fn from_world(world: &mut World) -> Self {
+        Self(world.spawn(world_body_bundle()).id())
+    }
+}
+
+/// Returns the components of a [`WorldBody`] entity.
+fn world_body_bundle() -> impl Bundle {
+    (
+        WorldBody,
+        RigidBody::Static,
+        Position::default(),
+        Rotation::default(),
+    )
+}
+
+/// Spawns a new [`WorldBody`] if the previous one has been despawned.
+fn validate_world_body(
+    mut commands: Commands,
+    mut world_body: ResMut<WorldBodyEntity>,
+    world_bodies: Query<(), With<WorldBody>>,
+) {
+    if !world_bodies.contains(world_body.0) {
+        let old_world_body = world_body.0;
+        world_body.0 = commands.spawn(world_body_bundle()).id();
+        warn!(
+            "The world body {:?} was despawned and replaced by {:?}. \
+            Constraints attached to the old world body are no longer solved.",
+            old_world_body, world_body.0
+        );
+    }
+}
+
 /// Configures what is initialized by the [`PreparePlugin`] and how.
 #[derive(Resource, Reflect, Clone, Debug, PartialEq, Eq)]
 #[reflect(Resource)]
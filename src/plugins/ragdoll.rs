@@ -0,0 +1,657 @@
+//! Builds ragdolls from the skeletons of skinned meshes.
+//!
+//! See [`RagdollPlugin`].
+
+use crate::prelude::*;
+use bevy::{
+    prelude::*,
+    render::mesh::skinning::SkinnedMesh,
+    scene::SceneInstance,
+    utils::{HashMap, HashSet},
+};
+
+/// Builds [`Ragdoll`]s from the skeletons of skinned meshes and switches them between
+/// [animated and simulated](RagdollMode) modes.
+///
+/// ## Building ragdolls
+///
+/// A ragdoll is built for each entity with a [`Ragdoll`] component once its scene has been spawned.
+/// The bones are found from the joints of the `SkinnedMesh`es that are descendants of the entity.
+///
+/// Each bone that has a configuration in the [`Ragdoll`] becomes a [rigid body](RigidBody) with a
+/// [`Collider`] that spans the bone, and it is attached to its closest ancestor bone with a [`GenericJoint`]
+/// that limits the rotation of the bone relative to its rest pose. The generated entities are stored
+/// in the [`RagdollBones`] component of the ragdoll, and each bone has a [`RagdollBone`] component.
+///
+/// Bones that are connected by a joint don't collide with each other.
+///
+/// ## Modes
+///
+/// The [`RagdollMode`] of a ragdoll determines whether the bones are [kinematic](RigidBody::Kinematic)
+/// and follow their animated transforms, or [dynamic](RigidBody::Dynamic) and simulated by the physics engine.
+///
+/// ## Pose matching
+///
+/// Simulated ragdolls can try to hold the pose of an animated reference skeleton using [`RagdollPoseMatching`].
+/// The joints of the bones are driven towards the rotations of the reference bones by [`OrientationDrive`]s,
+/// which are solved in the substepping loop together with the joint limits and contacts.
+///
+/// Ragdolls are built, their modes are updated and their pose targets are updated in `Update`.
+pub struct RagdollPlugin;
+
+impl Plugin for RagdollPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<RagdollMode>()
+            .register_type::<RagdollBones>()
+            .register_type::<RagdollBone>()
+            .add_systems(
+                Update,
+                (
+                    build_ragdolls,
+                    update_ragdoll_modes,
+                    update_ragdoll_drive_targets,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// A component that builds a ragdoll from the skeleton of the skinned meshes in a scene
+/// once the scene has been spawned. The bones used for the ragdoll and their [shapes](RagdollShape)
+/// and [joint limits](RagdollJointLimits) are configured by bone name.
+///
+/// See [`RagdollPlugin`] for more information.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands, assets: Res<AssetServer>) {
+///     let scene = assets.load("character.gltf#Scene0");
+///
+///     // Only generate bodies for the listed bones, and use spheres for the head and hands
+///     commands.spawn((
+///         SceneBundle { scene, ..default() },
+///         Ragdoll::new(None)
+///             .with_bones_for_names(
+///                 &["Hips", "Spine", "LeftUpLeg", "RightUpLeg", "LeftArm", "RightArm"],
+///                 RagdollBoneData::default(),
+///             )
+///             .with_shape_for_name("Head", RagdollShape::Sphere { radius: 0.12 })
+///             .with_shape_for_name("LeftHand", RagdollShape::Sphere { radius: 0.05 })
+///             .with_shape_for_name("RightHand", RagdollShape::Sphere { radius: 0.05 })
+///             .with_joint_for_name("LeftLeg", RagdollJointLimits::Hinge { min: -2.5, max: 0.0 })
+///             .with_joint_for_name("RightLeg", RagdollJointLimits::Hinge { min: -2.5, max: 0.0 })
+///             .with_density_for_name("Hips", 2.0),
+///         RagdollMode::Simulated,
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+pub struct Ragdoll {
+    /// The configuration used for each bone that isn't included in [`bones_by_name`](#structfield.bones_by_name).
+    /// If `None`, all bones except the ones in [`bones_by_name`](#structfield.bones_by_name) will be skipped.
+    pub default_bone: Option<RagdollBoneData>,
+    /// Specifies the configuration of bones by name. Entries with a `None` value will be skipped.
+    /// For the bones not found in this `HashMap`, [`default_bone`](#structfield.default_bone) will be used instead.
+    pub bones_by_name: HashMap<String, Option<RagdollBoneData>>,
+}
+
+impl Ragdoll {
+    /// Creates a new [`Ragdoll`] with the configuration used for bones set to the given `default_bone`.
+    ///
+    /// If the given configuration is `None`, all bones except the ones in
+    /// [`bones_by_name`](#structfield.bones_by_name) will be skipped.
+    pub fn new(default_bone: Option<RagdollBoneData>) -> Self {
+        Self {
+            default_bone,
+            bones_by_name: default(),
+        }
+    }
+
+    /// Specifies the configuration used for the bones with the given `names`.
+    pub fn with_bones_for_names(mut self, names: &[&str], data: RagdollBoneData) -> Self {
+        for name in names {
+            self.bones_by_name
+                .insert(name.to_string(), Some(data.clone()));
+        }
+        self
+    }
+
+    /// Specifies the [`RagdollShape`] used for the bone with the given `name`.
+    pub fn with_shape_for_name(mut self, name: &str, shape: RagdollShape) -> Self {
+        self.bone_data_mut(name).shape = shape;
+        self
+    }
+
+    /// Specifies the [`RagdollJointLimits`] used for the bone with the given `name`.
+    pub fn with_joint_for_name(mut self, name: &str, joint: RagdollJointLimits) -> Self {
+        self.bone_data_mut(name).joint = joint;
+        self
+    }
+
+    /// Specifies the [`CollisionLayers`] used for the bone with the given `name`.
+    pub fn with_layers_for_name(mut self, name: &str, layers: CollisionLayers) -> Self {
+        self.bone_data_mut(name).layers = layers;
+        self
+    }
+
+    /// Specifies the [`ColliderDensity`] used for the bone with the given `name`.
+    pub fn with_density_for_name(mut self, name: &str, density: Scalar) -> Self {
+        self.bone_data_mut(name).density = density;
+        self
+    }
+
+    /// Sets the configuration of the bone associated with the given `name` to `None`,
+    /// skipping it when building the ragdoll.
+    pub fn without_bone_with_name(mut self, name: &str) -> Self {
+        self.bones_by_name.insert(name.to_string(), None);
+        self
+    }
+
+    /// Returns the configuration used for the bone with the given `name`, if it is a part of the ragdoll.
+    pub fn bone_data(&self, name: &str) -> Option<&RagdollBoneData> {
+        match self.bones_by_name.get(name) {
+            Some(data) => data.as_ref(),
+            None => self.default_bone.as_ref(),
+        }
+    }
+
+    /// Returns the configuration of the bone with the given `name`, inserting the default
+    /// configuration if the bone isn't configured yet.
+    fn bone_data_mut(&mut self, name: &str) -> &mut RagdollBoneData {
+        let default_bone = self.default_bone.clone().unwrap_or_default();
+        self.bones_by_name
+            .entry(name.to_string())
+            .or_insert(None)
+            .get_or_insert(default_bone)
+    }
+}
+
+/// Configuration for a specific bone of a [`Ragdoll`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RagdollBoneData {
+    /// The shape of the collider generated for the bone.
+    pub shape: RagdollShape,
+    /// The limits of the joint that attaches the bone to its parent bone.
+    pub joint: RagdollJointLimits,
+    /// The [`CollisionLayers`] used for the bone.
+    pub layers: CollisionLayers,
+    /// The [`ColliderDensity`] used for the bone.
+    pub density: Scalar,
+}
+
+impl Default for RagdollBoneData {
+    fn default() -> Self {
+        Self {
+            shape: RagdollShape::default(),
+            joint: RagdollJointLimits::default(),
+            layers: CollisionLayers::default(),
+            density: 1.0,
+        }
+    }
+}
+
+/// The shape of the collider generated for a bone of a [`Ragdoll`].
+///
+/// The bones are assumed to point along their local Y axis, like bones exported from Blender.
+/// The length of a bone is the distance to its furthest child bone.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum RagdollShape {
+    /// A capsule that spans the bone. Bones without child bones get a capsule
+    /// with a length of two times the radius along their local Y axis.
+    Capsule {
+        /// The radius of the capsule.
+        radius: Scalar,
+    },
+    /// A sphere at the center of the bone.
+    Sphere {
+        /// The radius of the sphere.
+        radius: Scalar,
+    },
+}
+
+impl Default for RagdollShape {
+    fn default() -> Self {
+        Self::Capsule { radius: 0.05 }
+    }
+}
+
+/// The limits of the joint that attaches a bone of a [`Ragdoll`] to its parent bone.
+///
+/// The angles are measured relative to the rest pose of the skeleton, in the local space of the bone.
+/// The twist axis is the local Y axis of the bone.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum RagdollJointLimits {
+    /// The bone can swing up to `swing_limit` radians around its local X and Z axes
+    /// and twist up to `twist_limit` radians around its local Y axis in both directions.
+    Spherical {
+        /// The maximum swing angle around the local X and Z axes.
+        swing_limit: Scalar,
+        /// The maximum twist angle around the local Y axis.
+        twist_limit: Scalar,
+    },
+    /// The bone can only rotate around its local X axis between `min` and `max` radians,
+    /// like a knee or an elbow.
+    Hinge {
+        /// The minimum angle around the local X axis.
+        min: Scalar,
+        /// The maximum angle around the local X axis.
+        max: Scalar,
+    },
+    /// The bone can't rotate relative to its parent bone.
+    Fixed,
+}
+
+impl Default for RagdollJointLimits {
+    fn default() -> Self {
+        Self::Spherical {
+            swing_limit: PI / 4.0,
+            twist_limit: PI / 8.0,
+        }
+    }
+}
+
+impl RagdollJointLimits {
+    /// Returns the motions allowed around the angular axes of the joint.
+    fn angular_motions(&self) -> [AxisMotion; 3] {
+        match *self {
+            Self::Spherical {
+                swing_limit,
+                twist_limit,
+            } => [
+                AxisMotion::limited(-swing_limit, swing_limit),
+                AxisMotion::limited(-twist_limit, twist_limit),
+                AxisMotion::limited(-swing_limit, swing_limit),
+            ],
+            Self::Hinge { min, max } => [
+                AxisMotion::limited(min, max),
+                AxisMotion::Locked,
+                AxisMotion::Locked,
+            ],
+            Self::Fixed => [AxisMotion::Locked; 3],
+        }
+    }
+}
+
+/// A component that makes a simulated [`Ragdoll`] try to match the pose of an animated reference skeleton.
+///
+/// The reference skeleton is usually a second, hidden instance of the character's scene that is animated
+/// by an `AnimationPlayer`. Its bones are matched with the bones of the ragdoll by name. Each frame,
+/// the joint of each ragdoll bone gets an [`OrientationDrive`] that targets the rotation of the reference bone
+/// relative to its parent bone. The root bone isn't driven, so the ragdoll can still stumble and fall over.
+///
+/// The drives are configured by bone name. Lowering the stiffness of the drives or limiting their
+/// maximum torque makes the ragdoll react more to hits and contacts. When the component is removed,
+/// the drives are removed from the joints and the ragdoll goes limp.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands, assets: Res<AssetServer>) {
+///     let scene = assets.load("character.gltf#Scene0");
+///
+///     // The animated reference skeleton
+///     let reference = commands
+///         .spawn(SceneBundle {
+///             scene: scene.clone(),
+///             visibility: Visibility::Hidden,
+///             ..default()
+///         })
+///         .id();
+///
+///     // Match the pose with soft drives, and let the arms hang limp
+///     commands.spawn((
+///         SceneBundle { scene, ..default() },
+///         Ragdoll::new(Some(RagdollBoneData::default())),
+///         RagdollMode::Simulated,
+///         RagdollPoseMatching::new(
+///             reference,
+///             Some(OrientationDrive::default().with_compliance(0.001).with_max_torque(200.0)),
+///         )
+///         .without_drive_with_name("LeftArm")
+///         .without_drive_with_name("RightArm"),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct RagdollPoseMatching {
+    /// The root entity of the reference skeleton.
+    pub reference: Entity,
+    /// The drive used for each bone that isn't included in [`drives_by_name`](#structfield.drives_by_name).
+    /// The target of the drive is replaced by the pose of the reference skeleton.
+    /// If `None`, only the bones in [`drives_by_name`](#structfield.drives_by_name) will be driven.
+    pub default_drive: Option<OrientationDrive>,
+    /// Specifies the drives of bones by name. Bones with a `None` value won't be driven.
+    /// For the bones not found in this `HashMap`, [`default_drive`](#structfield.default_drive) will be used instead.
+    pub drives_by_name: HashMap<String, Option<OrientationDrive>>,
+}
+
+impl RagdollPoseMatching {
+    /// Creates a new [`RagdollPoseMatching`] that matches the pose of the skeleton under the `reference` entity,
+    /// with the drive used for bones set to the given `default_drive`.
+    ///
+    /// If the given drive is `None`, only the bones in [`drives_by_name`](#structfield.drives_by_name)
+    /// will be driven.
+    pub fn new(reference: Entity, default_drive: Option<OrientationDrive>) -> Self {
+        Self {
+            reference,
+            default_drive,
+            drives_by_name: default(),
+        }
+    }
+
+    /// Specifies the drive used for the bone with the given `name`.
+    pub fn with_drive_for_name(mut self, name: &str, drive: OrientationDrive) -> Self {
+        self.drives_by_name.insert(name.to_string(), Some(drive));
+        self
+    }
+
+    /// Sets the drive of the bone associated with the given `name` to `None`, so that it isn't driven.
+    pub fn without_drive_with_name(mut self, name: &str) -> Self {
+        self.drives_by_name.insert(name.to_string(), None);
+        self
+    }
+
+    /// Returns the drive used for the bone with the given `name`, if the bone is driven.
+    pub fn drive(&self, name: &str) -> Option<&OrientationDrive> {
+        match self.drives_by_name.get(name) {
+            Some(drive) => drive.as_ref(),
+            None => self.default_drive.as_ref(),
+        }
+    }
+}
+
+/// Determines whether the bones of a [`Ragdoll`] follow their animated transforms
+/// or are simulated by the physics engine.
+///
+/// The mode can be changed at any time, for example to make a character go limp when it is hit.
+/// Animations that write to the transforms of the bones should be paused while the ragdoll is simulated.
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub enum RagdollMode {
+    /// The bones are [kinematic](RigidBody::Kinematic) and follow their transforms.
+    #[default]
+    Animated,
+    /// The bones are [dynamic](RigidBody::Dynamic) and simulated by the physics engine.
+    Simulated,
+}
+
+impl RagdollMode {
+    /// Returns the type of rigid body used for the bones in this mode.
+    pub fn rigid_body(&self) -> RigidBody {
+        match self {
+            Self::Animated => RigidBody::Kinematic,
+            Self::Simulated => RigidBody::Dynamic,
+        }
+    }
+}
+
+/// The bone entities of a [`Ragdoll`] that have been turned into rigid bodies.
+///
+/// This is added to the ragdoll entity once the ragdoll has been built.
+#[derive(Component, Reflect, Clone, Debug, Default, Deref, DerefMut, PartialEq)]
+#[reflect(Component)]
+pub struct RagdollBones(pub Vec<Entity>);
+
+/// A bone of a [`Ragdoll`] that has been turned into a rigid body.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct RagdollBone {
+    /// The entity with the [`Ragdoll`] component.
+    pub ragdoll: Entity,
+    /// The closest ancestor bone that the bone is attached to, if any.
+    pub parent: Option<Entity>,
+    /// The entity of the [`GenericJoint`] that attaches the bone to its `parent`.
+    pub joint: Option<Entity>,
+}
+
+impl Default for RagdollBone {
+    fn default() -> Self {
+        Self {
+            ragdoll: Entity::PLACEHOLDER,
+            parent: None,
+            joint: None,
+        }
+    }
+}
+
+/// Builds ragdolls for [`Ragdoll`]s once their scenes and skeletons have become available.
+#[allow(clippy::type_complexity)]
+pub fn build_ragdolls(
+    mut commands: Commands,
+    scene_spawner: Res<SceneSpawner>,
+    ragdolls: Query<
+        (
+            Entity,
+            &Ragdoll,
+            Option<&RagdollMode>,
+            Option<&SceneInstance>,
+        ),
+        Without<RagdollBones>,
+    >,
+    children: Query<&Children>,
+    skinned_meshes: Query<&SkinnedMesh>,
+    names: Query<&Name>,
+    transforms: Query<(&Transform, Option<&Parent>)>,
+) {
+    for (ragdoll_entity, ragdoll, mode, scene_instance) in &ragdolls {
+        if scene_instance.is_some_and(|instance| !scene_spawner.instance_is_ready(**instance)) {
+            continue;
+        }
+
+        // Collect the joints of all skinned meshes in the hierarchy
+        let mut skeleton = HashSet::new();
+        for entity in children.iter_descendants(ragdoll_entity) {
+            if let Ok(skinned_mesh) = skinned_meshes.get(entity) {
+                skeleton.extend(skinned_mesh.joints.iter().copied());
+            }
+        }
+
+        if skeleton.is_empty() {
+            continue;
+        }
+
+        // Find the bones that are a part of the ragdoll in hierarchy order
+        let bones: Vec<(Entity, RagdollBoneData)> = children
+            .iter_descendants(ragdoll_entity)
+            .filter(|entity| skeleton.contains(entity))
+            .filter_map(|entity| {
+                let name = names.get(entity).map_or("", |name| name.as_str());
+                ragdoll.bone_data(name).map(|data| (entity, data.clone()))
+            })
+            .collect();
+        let bone_set: HashSet<Entity> = bones.iter().map(|(entity, _)| *entity).collect();
+
+        let mode = mode.copied().unwrap_or_default();
+        let mut bone_entities = Vec::with_capacity(bones.len());
+
+        for (entity, data) in bones {
+            let transform = global_transform(entity, &transforms);
+            let position = transform.translation.adjust_precision();
+            let rotation = Rotation(transform.rotation.adjust_precision());
+
+            // The bone ends at its furthest child bone
+            let end = children
+                .get(entity)
+                .into_iter()
+                .flatten()
+                .filter(|child| skeleton.contains(*child))
+                .map(|child| {
+                    global_transform(*child, &transforms)
+                        .translation
+                        .adjust_precision()
+                })
+                .map(|child_position| rotation.inverse().rotate(child_position - position))
+                .max_by(|a, b| a.length_squared().total_cmp(&b.length_squared()));
+
+            let collider = match data.shape {
+                RagdollShape::Capsule { radius } => {
+                    let end = end.unwrap_or(Vector::Y * 2.0 * radius);
+                    Collider::capsule_endpoints(Vector::ZERO, end, radius)
+                }
+                RagdollShape::Sphere { radius } => {
+                    let center = end.map_or(Vector::Y * radius, |end| end * 0.5);
+                    Collider::compound(vec![(center, Rotation::default(), Collider::ball(radius))])
+                }
+            };
+
+            // Attach the bone to the closest ancestor bone
+            let parent = std::iter::successors(transforms.get(entity).ok(), |(_, parent)| {
+                parent.and_then(|parent| transforms.get(parent.get()).ok())
+            })
+            .filter_map(|(_, parent)| parent.map(|parent| parent.get()))
+            .take_while(|parent| *parent != ragdoll_entity)
+            .find(|parent| bone_set.contains(parent));
+
+            let joint = parent.map(|parent| {
+                let parent_transform = global_transform(parent, &transforms);
+                let parent_rotation = Rotation(parent_transform.rotation.adjust_precision());
+                let local_anchor = parent_rotation
+                    .inverse()
+                    .rotate(position - parent_transform.translation.adjust_precision());
+                let local_basis = Rotation(parent_rotation.inverse().0 * rotation.0);
+                let [x, y, z] = data.joint.angular_motions();
+
+                commands
+                    .spawn(
+                        GenericJoint::new(parent, entity)
+                            .with_local_anchor_1(local_anchor)
+                            .with_local_basis_1(local_basis)
+                            .with_angular_motion(0, x)
+                            .with_angular_motion(1, y)
+                            .with_angular_motion(2, z),
+                    )
+                    .id()
+            });
+
+            commands.entity(entity).insert((
+                mode.rigid_body(),
+                collider,
+                data.layers,
+                ColliderDensity(data.density),
+                RagdollBone {
+                    ragdoll: ragdoll_entity,
+                    parent,
+                    joint,
+                },
+            ));
+            bone_entities.push(entity);
+        }
+
+        commands
+            .entity(ragdoll_entity)
+            .insert((mode, RagdollBones(bone_entities)));
+    }
+}
+
+/// Switches the bones of ragdolls between kinematic and dynamic rigid bodies when their [`RagdollMode`] changes.
+fn update_ragdoll_modes(
+    ragdolls: Query<(&RagdollMode, &RagdollBones), Changed<RagdollMode>>,
+    mut bodies: Query<(&mut RigidBody, &mut LinearVelocity, &mut AngularVelocity)>,
+) {
+    for (mode, bones) in &ragdolls {
+        let mut iter = bodies.iter_many_mut(bones.iter());
+        while let Some((mut rb, mut lin_vel, mut ang_vel)) = iter.fetch_next() {
+            if *rb == mode.rigid_body() {
+                continue;
+            }
+            *rb = mode.rigid_body();
+
+            // Animated bones are moved by their transforms, so they shouldn't keep moving
+            // with the velocity they had while they were simulated
+            if *mode == RagdollMode::Animated {
+                lin_vel.0 = Vector::ZERO;
+                ang_vel.0 = Vector::ZERO;
+            }
+        }
+    }
+}
+
+/// Updates the [`OrientationDrive`]s of ragdoll joints to match the pose of the reference skeletons
+/// of [`RagdollPoseMatching`]s, and removes the drives when the component is removed.
+#[allow(clippy::too_many_arguments)]
+fn update_ragdoll_drive_targets(
+    ragdolls: Query<(&RagdollPoseMatching, &RagdollBones)>,
+    mut removed: RemovedComponents<RagdollPoseMatching>,
+    all_ragdoll_bones: Query<&RagdollBones>,
+    bones: Query<(&RagdollBone, Option<&Name>)>,
+    children: Query<&Children>,
+    names: Query<&Name>,
+    transforms: Query<(&Transform, Option<&Parent>)>,
+    mut joints: Query<&mut GenericJoint>,
+) {
+    // Make ragdolls without pose matching go limp
+    for ragdoll_bones in all_ragdoll_bones.iter_many(removed.read()) {
+        let mut iter = bones.iter_many(ragdoll_bones.iter());
+        while let Some((bone, _)) = iter.fetch_next() {
+            if let Some(mut joint) = bone.joint.and_then(|joint| joints.get_mut(joint).ok()) {
+                joint.orientation_drive = None;
+            }
+        }
+    }
+
+    for (pose_matching, ragdoll_bones) in &ragdolls {
+        // Find the bones of the reference skeleton by name
+        let reference_bones: HashMap<&str, Entity> = children
+            .iter_descendants(pose_matching.reference)
+            .filter_map(|entity| names.get(entity).ok().map(|name| (name.as_str(), entity)))
+            .collect();
+
+        for (bone, name) in bones.iter_many(ragdoll_bones.iter()) {
+            let (Some(joint), Some(parent), Some(name)) = (bone.joint, bone.parent, name) else {
+                continue;
+            };
+            let Ok(mut joint) = joints.get_mut(joint) else {
+                continue;
+            };
+
+            let Some(drive) = pose_matching.drive(name.as_str()) else {
+                joint.orientation_drive = None;
+                continue;
+            };
+
+            let reference_bone = reference_bones.get(name.as_str());
+            let reference_parent = names
+                .get(parent)
+                .ok()
+                .and_then(|name| reference_bones.get(name.as_str()));
+            let (Some(&reference_bone), Some(&reference_parent)) =
+                (reference_bone, reference_parent)
+            else {
+                continue;
+            };
+
+            // The rotation of the reference bone relative to its parent bone in the joint frames
+            let rotation = global_transform(reference_bone, &transforms).rotation;
+            let parent_rotation = global_transform(reference_parent, &transforms).rotation;
+            let relative_rotation = (parent_rotation.inverse() * rotation).adjust_precision();
+            let target =
+                Rotation(joint.local_basis1.inverse().0 * relative_rotation * joint.local_basis2.0);
+
+            joint.orientation_drive = Some(drive.with_target(target));
+        }
+    }
+}
+
+/// Computes the global transform of an entity from the transforms of its ancestors.
+///
+/// This doesn't depend on `GlobalTransform`, which may not have been propagated yet for newly spawned scenes.
+fn global_transform(
+    entity: Entity,
+    transforms: &Query<(&Transform, Option<&Parent>)>,
+) -> Transform {
+    let Ok((transform, parent)) = transforms.get(entity) else {
+        return Transform::IDENTITY;
+    };
+    match parent {
+        Some(parent) => global_transform(parent.get(), transforms).mul_transform(*transform),
+        None => *transform,
+    }
+}
@@ -92,6 +92,8 @@ impl Plugin for PhysicsSetupPlugin {
             .register_type::<PreSolveAngularVelocity>()
             .register_type::<Restitution>()
             .register_type::<Friction>()
+            .register_type::<RollingFriction>()
+            .register_type::<SurfaceVelocity>()
             .register_type::<LinearDamping>()
             .register_type::<AngularDamping>()
             .register_type::<ExternalForce>()
@@ -99,6 +101,7 @@ impl Plugin for PhysicsSetupPlugin {
             .register_type::<ExternalImpulse>()
             .register_type::<ExternalAngularImpulse>()
             .register_type::<GravityScale>()
+            .register_type::<GravityField>()
             .register_type::<Mass>()
             .register_type::<InverseMass>()
             .register_type::<Inertia>()
@@ -109,13 +112,18 @@ impl Plugin for PhysicsSetupPlugin {
             .register_type::<LockedAxes>()
             .register_type::<ColliderParent>()
             .register_type::<Dominance>()
+            .register_type::<Ccd>()
             .register_type::<CollisionLayers>()
+            .register_type::<CollisionExclusions>()
             .register_type::<CollidingEntities>()
             .register_type::<CoefficientCombine>()
             .register_type::<Sensor>()
             .register_type::<ColliderTransform>()
             .register_type::<PreviousColliderTransform>();
 
+        #[cfg(feature = "3d")]
+        app.register_type::<TorsionalFriction>();
+
         // Configure higher level system sets for the given schedule
         let schedule = self.schedule;
         app.configure_sets(
@@ -308,7 +316,7 @@ fn run_substep_schedule(world: &mut World) {
 }
 
 /// Runs the [`PostProcessCollisions`] schedule.
-fn run_post_process_collisions_schedule(world: &mut World) {
+pub(crate) fn run_post_process_collisions_schedule(world: &mut World) {
     trace!("running PostProcessCollisions");
     world.run_schedule(PostProcessCollisions);
 }
@@ -3,7 +3,7 @@
 //! See [`SleepingPlugin`].
 
 use crate::prelude::*;
-use bevy::prelude::*;
+use bevy::{ecs::query::Has, prelude::*};
 
 /// Controls when bodies should be deactivated and marked as [`Sleeping`] to improve performance.
 ///
@@ -12,6 +12,7 @@ use bevy::prelude::*;
 ///
 /// Bodies are woken up when an active body or constraint interacts with them, or when gravity changes,
 /// or when the body's position, rotation, velocity, or external forces are changed.
+/// Bodies attached to joints with a [`JointMotor`] are kept awake.
 ///
 /// This plugin does *not* handle constraints waking up bodies. That is done by the [solver].
 ///
@@ -25,6 +26,7 @@ impl Plugin for SleepingPlugin {
             .add_systems(wake_on_collision_ended.in_set(PhysicsStepSet::ReportContacts))
             .add_systems(
                 (
+                    keep_motorized_bodies_awake,
                     mark_sleeping_bodies,
                     wake_on_changed,
                     wake_on_collider_removed,
@@ -144,6 +146,41 @@ fn wake_on_collider_removed(
     }
 }
 
+/// Keeps the bodies attached to joints with a [`JointMotor`] awake so that the motor can keep driving them.
+fn keep_motorized_bodies_awake(
+    mut commands: Commands,
+    mut bodies: Query<(&mut TimeSleeping, Has<Sleeping>)>,
+    revolute_joints: Query<&RevoluteJoint>,
+    prismatic_joints: Query<&PrismaticJoint>,
+    generic_joints: Query<&GenericJoint>,
+) {
+    let motorized_bodies = revolute_joints
+        .iter()
+        .filter(|joint| joint.motor.is_some())
+        .flat_map(|joint| joint.entities())
+        .chain(
+            prismatic_joints
+                .iter()
+                .filter(|joint| joint.motor.is_some())
+                .flat_map(|joint| joint.entities()),
+        )
+        .chain(
+            generic_joints
+                .iter()
+                .filter(|joint| joint.has_drives())
+                .flat_map(|joint| joint.entities()),
+        );
+
+    for entity in motorized_bodies {
+        if let Ok((mut time_sleeping, is_sleeping)) = bodies.get_mut(entity) {
+            if is_sleeping {
+                commands.entity(entity).remove::<Sleeping>();
+            }
+            time_sleeping.0 = 0.0;
+        }
+    }
+}
+
 /// Removes the [`Sleeping`] component from all sleeping bodies.
 /// Triggered automatically when [`Gravity`] is changed.
 fn wake_all_sleeping_bodies(
@@ -5,7 +5,10 @@
 
 use crate::{
     prelude::*,
-    utils::{compute_dynamic_friction, compute_restitution, get_pos_translation},
+    utils::{
+        compute_dynamic_friction, compute_restitution, compute_rolling_friction,
+        get_pos_translation,
+    },
 };
 use bevy::{
     ecs::query::{Has, WorldQuery},
@@ -36,7 +39,33 @@ pub struct SolverPlugin;
 
 impl Plugin for SolverPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<PenetrationConstraints>();
+        app.init_resource::<PenetrationConstraints>()
+            .init_resource::<ConnectedBodies>()
+            .add_event::<JointBroken>();
+
+        let physics_schedule = app
+            .get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first");
+
+        physics_schedule
+            .configure_sets(SolverSet::UpdateConnectedBodies.before(PhysicsStepSet::BroadPhase));
+
+        physics_schedule.add_systems(
+            (
+                clear_connected_bodies,
+                update_connected_bodies::<FixedJoint>,
+                update_connected_bodies::<RevoluteJoint>,
+                update_connected_bodies::<SphericalJoint>,
+                update_connected_bodies::<PrismaticJoint>,
+                update_connected_bodies::<DistanceJoint>,
+                update_connected_bodies::<GenericJoint>,
+                update_connected_bodies::<GearJoint>,
+                update_connected_bodies::<RackAndPinionJoint>,
+                update_connected_bodies::<PulleyJoint>,
+            )
+                .chain()
+                .in_set(SolverSet::UpdateConnectedBodies),
+        );
 
         let substeps = app
             .get_schedule_mut(SubstepSchedule)
@@ -50,13 +79,37 @@ impl Plugin for SolverPlugin {
                 solve_constraint::<SphericalJoint, 2>,
                 solve_constraint::<PrismaticJoint, 2>,
                 solve_constraint::<DistanceJoint, 2>,
+                solve_constraint::<GenericJoint, 2>,
+                solve_constraint::<GearJoint, 2>,
+                solve_constraint::<RackAndPinionJoint, 2>,
+                solve_constraint::<PulleyJoint, 2>,
                 solve_constraint::<VolumeConstraint, 4>,
                 solve_constraint::<EdgeConstraint, 2>,
+                solve_constraint::<IsometricBendingConstraint, 4>,
+                solve_constraint::<DihedralBendingConstraint, 4>,
             )
                 .chain()
                 .in_set(SubstepSet::SolveConstraints),
         );
 
+        // Break joints whose force or torque exceeded their thresholds during the substep
+        substeps.add_systems(
+            (
+                break_joints::<FixedJoint>,
+                break_joints::<RevoluteJoint>,
+                break_joints::<SphericalJoint>,
+                break_joints::<PrismaticJoint>,
+                break_joints::<DistanceJoint>,
+                break_joints::<GenericJoint>,
+                break_joints::<GearJoint>,
+                break_joints::<RackAndPinionJoint>,
+                break_joints::<PulleyJoint>,
+            )
+                .chain()
+                .after(solve_constraint::<DihedralBendingConstraint, 4>)
+                .in_set(SubstepSet::SolveConstraints),
+        );
+
         substeps.add_systems((update_lin_vel, update_ang_vel).in_set(SubstepSet::UpdateVelocities));
 
         substeps.add_systems(
@@ -67,6 +120,10 @@ impl Plugin for SolverPlugin {
                 joint_damping::<SphericalJoint>,
                 joint_damping::<PrismaticJoint>,
                 joint_damping::<DistanceJoint>,
+                joint_damping::<GenericJoint>,
+                joint_damping::<GearJoint>,
+                joint_damping::<RackAndPinionJoint>,
+                joint_damping::<PulleyJoint>,
             )
                 .chain()
                 .in_set(SubstepSet::SolveVelocities),
@@ -76,6 +133,14 @@ impl Plugin for SolverPlugin {
     }
 }
 
+/// The system sets that the [`SolverPlugin`] runs in the [`PhysicsSchedule`].
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SolverSet {
+    /// Collects the bodies connected by [joints] into [`ConnectedBodies`] before [`PhysicsStepSet::BroadPhase`].
+    /// Systems that store other connected bodies should run after this set.
+    UpdateConnectedBodies,
+}
+
 /// Stores penetration constraints for colliding entity pairs.
 #[derive(Resource, Debug, Default)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
@@ -90,6 +155,10 @@ struct ColliderQuery<'w> {
     is_sensor: Has<Sensor>,
     friction: Option<&'w Friction>,
     restitution: Option<&'w Restitution>,
+    rolling_friction: Option<&'w RollingFriction>,
+    #[cfg(feature = "3d")]
+    torsional_friction: Option<&'w TorsionalFriction>,
+    surface_velocity: Option<&'w SurfaceVelocity>,
 }
 
 /// Iterates through broad phase collision pairs, checks which ones are actually colliding, and uses [`PenetrationConstraint`]s to resolve the collisions.
@@ -174,36 +243,109 @@ fn penetration_constraints(
                 .unwrap_or(body1.restitution)
                 .combine(*collider2.restitution.unwrap_or(body2.restitution))
                 .coefficient;
+            let rolling_friction_coefficient = collider1
+                .rolling_friction
+                .or(body1.rolling_friction)
+                .copied()
+                .unwrap_or_default()
+                .combine(
+                    collider2
+                        .rolling_friction
+                        .or(body2.rolling_friction)
+                        .copied()
+                        .unwrap_or_default(),
+                )
+                .coefficient;
+            #[cfg(feature = "3d")]
+            let torsional_friction_coefficient = collider1
+                .torsional_friction
+                .or(body1.torsional_friction)
+                .copied()
+                .unwrap_or_default()
+                .combine(
+                    collider2
+                        .torsional_friction
+                        .or(body2.torsional_friction)
+                        .copied()
+                        .unwrap_or_default(),
+                )
+                .coefficient;
 
             // Create and solve penetration constraints for each contact.
-            for contact_manifold in contacts.manifolds.iter() {
-                for contact in contact_manifold.contacts.iter() {
+            for contact_manifold in contacts.manifolds.iter_mut() {
+                for manifold_contact in contact_manifold.contacts.iter_mut() {
+                    // Move the static friction anchors along with the surfaces of the colliders
+                    let surface_velocity1 = collider1.surface_velocity.map_or(Vector::ZERO, |v| {
+                        v.velocity_at_point(manifold_contact.point1)
+                    });
+                    let surface_velocity2 = collider2.surface_velocity.map_or(Vector::ZERO, |v| {
+                        v.velocity_at_point(manifold_contact.point2)
+                    });
+                    manifold_contact.anchor1 += surface_velocity1 * delta_secs;
+                    manifold_contact.anchor2 += surface_velocity2 * delta_secs;
+
                     // Add collider transforms to local contact points
+                    let to_body1 = |point: Vector| {
+                        collider1
+                            .transform
+                            .map_or(point, |t| t.rotation.rotate(point) + t.translation)
+                    };
+                    let to_body2 = |point: Vector| {
+                        collider2
+                            .transform
+                            .map_or(point, |t| t.rotation.rotate(point) + t.translation)
+                    };
                     let contact = ContactData {
-                        point1: collider1.transform.map_or(contact.point1, |t| {
-                            t.rotation.rotate(contact.point1) + t.translation
+                        point1: to_body1(manifold_contact.point1),
+                        point2: to_body2(manifold_contact.point2),
+                        anchor1: to_body1(manifold_contact.anchor1),
+                        anchor2: to_body2(manifold_contact.anchor2),
+                        normal1: collider1.transform.map_or(manifold_contact.normal1, |t| {
+                            t.rotation.rotate(manifold_contact.normal1)
                         }),
-                        point2: collider2.transform.map_or(contact.point2, |t| {
-                            t.rotation.rotate(contact.point2) + t.translation
+                        normal2: collider2.transform.map_or(manifold_contact.normal2, |t| {
+                            t.rotation.rotate(manifold_contact.normal2)
                         }),
-                        normal1: collider1
+                        ..*manifold_contact
+                    };
+
+                    let surface_velocity = body1.rotation.rotate(
+                        collider1
                             .transform
-                            .map_or(contact.normal1, |t| t.rotation.rotate(contact.normal1)),
-                        normal2: collider2
+                            .map_or(surface_velocity1, |t| t.rotation.rotate(surface_velocity1)),
+                    ) - body2.rotation.rotate(
+                        collider2
                             .transform
-                            .map_or(contact.normal2, |t| t.rotation.rotate(contact.normal2)),
-                        ..*contact
-                    };
+                            .map_or(surface_velocity2, |t| t.rotation.rotate(surface_velocity2)),
+                    );
 
                     let mut constraint = PenetrationConstraint {
+                        collider_entity1: *collider_entity1,
+                        collider_entity2: *collider_entity2,
+                        surface_velocity,
                         dynamic_friction_coefficient: friction.dynamic_coefficient,
                         static_friction_coefficient: friction.static_coefficient,
                         restitution_coefficient,
+                        rolling_friction_coefficient,
+                        #[cfg(feature = "3d")]
+                        torsional_friction_coefficient,
                         ..PenetrationConstraint::new(&body1, &body2, contact)
                     };
                     constraint.solve([&mut body1, &mut body2], delta_secs);
                     penetration_constraints.0.push(constraint);
 
+                    // Accumulate the impulses applied by the contact during the frame
+                    contacts.total_normal_impulse += constraint.normal_lagrange.abs() / delta_secs;
+                    contacts.total_tangent_impulse +=
+                        constraint.tangent_lagrange.abs() / delta_secs;
+
+                    // Store the state of the contact so that it persists to the next substep
+                    manifold_contact.normal_lagrange = constraint.normal_lagrange;
+                    if !constraint.sticking {
+                        manifold_contact.anchor1 = manifold_contact.point1;
+                        manifold_contact.anchor2 = manifold_contact.point2;
+                    }
+
                     // Set collision as penetrating for this frame and substep.
                     // This is used for detecting when the collision has started or ended.
                     if contact.penetration > Scalar::EPSILON {
@@ -417,10 +559,13 @@ fn update_ang_vel(
 }
 
 /// Applies velocity corrections caused by dynamic friction and restitution.
+///
+/// The impulses and the impact speed are also accumulated in the [`Contacts`] of the colliders.
 #[allow(clippy::type_complexity)]
 fn solve_vel(
     mut bodies: Query<RigidBodyQuery, Without<Sleeping>>,
     penetration_constraints: Res<PenetrationConstraints>,
+    mut collisions: ResMut<Collisions>,
     gravity: Res<Gravity>,
     time: Res<Time>,
 ) {
@@ -452,7 +597,9 @@ fn solve_vel(
                 body2.pre_solve_angular_velocity.0,
                 r2,
             );
-            let pre_solve_relative_vel = pre_solve_contact_vel1 - pre_solve_contact_vel2;
+            // The surface velocities of the colliders move the contact points along the surfaces
+            let pre_solve_relative_vel =
+                pre_solve_contact_vel1 - pre_solve_contact_vel2 + constraint.surface_velocity;
             let pre_solve_normal_speed = normal.dot(pre_solve_relative_vel);
 
             // Compute relative normal and tangential velocities at the contact point (equation 29)
@@ -460,7 +607,7 @@ fn solve_vel(
                 compute_contact_vel(body1.linear_velocity.0, body1.angular_velocity.0, r1);
             let contact_vel2 =
                 compute_contact_vel(body2.linear_velocity.0, body2.angular_velocity.0, r2);
-            let relative_vel = contact_vel1 - contact_vel2;
+            let relative_vel = contact_vel1 - contact_vel2 + constraint.surface_velocity;
 
             let normal_speed = normal.dot(relative_vel);
             let tangent_vel = relative_vel - normal * normal_speed;
@@ -472,6 +619,8 @@ fn solve_vel(
             let inv_inertia2 = body2.effective_world_inv_inertia();
 
             let mut p = Vector::ZERO;
+            let mut restitution_impulse = 0.0;
+            let mut friction_impulse = 0.0;
 
             // Compute restitution
             let restitution_speed = compute_restitution(
@@ -484,7 +633,8 @@ fn solve_vel(
             if restitution_speed.abs() > Scalar::EPSILON {
                 let w1 = constraint.compute_generalized_inverse_mass(&body1, r1, normal);
                 let w2 = constraint.compute_generalized_inverse_mass(&body2, r2, normal);
-                p += restitution_speed / (w1 + w2) * normal;
+                restitution_impulse = restitution_speed / (w1 + w2);
+                p += restitution_impulse * normal;
             }
 
             // Compute dynamic friction
@@ -492,7 +642,7 @@ fn solve_vel(
                 let tangent_dir = tangent_vel / tangent_speed;
                 let w1 = constraint.compute_generalized_inverse_mass(&body1, r1, tangent_dir);
                 let w2 = constraint.compute_generalized_inverse_mass(&body2, r2, tangent_dir);
-                let friction_impulse = compute_dynamic_friction(
+                friction_impulse = compute_dynamic_friction(
                     tangent_speed,
                     w1 + w2,
                     constraint.dynamic_friction_coefficient,
@@ -502,6 +652,15 @@ fn solve_vel(
                 p += friction_impulse * tangent_dir;
             }
 
+            if let Some(contacts) = collisions
+                .get_internal_mut()
+                .get_mut(&(constraint.collider_entity1, constraint.collider_entity2))
+            {
+                contacts.total_normal_impulse += restitution_impulse.abs();
+                contacts.total_tangent_impulse += friction_impulse.abs();
+                contacts.max_impact_speed = contacts.max_impact_speed.max(pre_solve_normal_speed);
+            }
+
             if body1.rb.is_dynamic() && body1.dominance() <= body2.dominance() {
                 let delta_lin_vel = p * inv_mass1;
                 let delta_ang_vel = compute_delta_ang_vel(inv_inertia1, r1, p);
@@ -524,6 +683,98 @@ fn solve_vel(
                     body2.angular_velocity.0 -= delta_ang_vel;
                 }
             }
+
+            // Compute rolling and torsional friction
+            #[cfg(feature = "2d")]
+            let has_rolling_friction = constraint.rolling_friction_coefficient > 0.0;
+            #[cfg(feature = "3d")]
+            let has_rolling_friction = constraint.rolling_friction_coefficient > 0.0
+                || constraint.torsional_friction_coefficient > 0.0;
+
+            if has_rolling_friction {
+                let apply1 = body1.rb.is_dynamic() && body1.dominance() <= body2.dominance();
+                let apply2 = body2.rb.is_dynamic() && body2.dominance() <= body1.dominance();
+
+                // Only the bodies that the impulse is applied to resist the rotation
+                let inv_inertia_sum = match (apply1, apply2) {
+                    (true, true) => inv_inertia1 + inv_inertia2,
+                    (true, false) => inv_inertia1,
+                    (false, true) => inv_inertia2,
+                    (false, false) => continue,
+                };
+                let relative_ang_vel = body1.angular_velocity.0 - body2.angular_velocity.0;
+                let normal_impulse = constraint.normal_lagrange.abs() / delta_secs;
+
+                #[cfg(feature = "2d")]
+                let angular_impulse = compute_rolling_friction(
+                    relative_ang_vel,
+                    inv_inertia_sum,
+                    constraint.rolling_friction_coefficient,
+                    normal_impulse,
+                );
+                #[cfg(feature = "3d")]
+                let angular_impulse = compute_rolling_friction(
+                    relative_ang_vel,
+                    normal,
+                    inv_inertia_sum,
+                    constraint.rolling_friction_coefficient,
+                    constraint.torsional_friction_coefficient,
+                    normal_impulse,
+                );
+
+                if apply1 {
+                    body1.angular_velocity.0 += inv_inertia1 * angular_impulse;
+                }
+                if apply2 {
+                    body2.angular_velocity.0 -= inv_inertia2 * angular_impulse;
+                }
+            }
+        }
+    }
+}
+
+/// Clears the [`ConnectedBodies`] before they are collected again for the current step.
+fn clear_connected_bodies(mut connected_bodies: ResMut<ConnectedBodies>) {
+    connected_bodies.clear();
+}
+
+/// Stores the bodies connected by joints of type `T` in [`ConnectedBodies`]
+/// so that the broad phase can skip collisions between them.
+///
+/// Joints whose connected bodies [can collide](Joint::with_collide_connected) are ignored.
+pub fn update_connected_bodies<T: Joint>(
+    joints: Query<&T>,
+    mut connected_bodies: ResMut<ConnectedBodies>,
+) {
+    for joint in &joints {
+        if !joint.collide_connected() {
+            let [entity1, entity2] = joint.entities();
+            connected_bodies.insert(entity1, entity2);
+        }
+    }
+}
+
+/// Removes joints whose force or torque exceeded their [break force](Joint::with_break_force)
+/// or [break torque](Joint::with_break_torque) during the substep, and sends [`JointBroken`] events for them.
+pub fn break_joints<T: Joint>(
+    mut commands: Commands,
+    joints: Query<(Entity, &T)>,
+    mut broken_joints: EventWriter<JointBroken>,
+) {
+    for (entity, joint) in &joints {
+        let force = joint.force_magnitude();
+        let torque = joint.torque_magnitude();
+
+        if force > joint.break_force() || torque > joint.break_torque() {
+            let [entity1, entity2] = joint.entities();
+            commands.entity(entity).remove::<T>();
+            broken_joints.send(JointBroken {
+                entity,
+                entity1,
+                entity2,
+                force,
+                torque,
+            });
         }
     }
 }
@@ -115,6 +115,8 @@ impl Default for DeactivationTime {
 /// ```
 ///
 /// You can also modify gravity while the app is running.
+///
+/// Bodies inside of [`GravityField`]s ignore the global gravity and are affected by the fields instead.
 #[derive(Reflect, Resource, Debug)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[reflect(Resource)]
@@ -130,3 +132,37 @@ impl Gravity {
     /// Zero gravity.
     pub const ZERO: Gravity = Gravity(Vector::ZERO);
 }
+
+/// A resource for the global wind velocity used for [aerodynamics](Aerodynamics).
+///
+/// The default is no wind. Like the [`AerodynamicsPlugin`], the wind is only available in 3D.
+///
+/// ## Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// # #[cfg(feature = "f32")]
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default()))
+///         .insert_resource(Wind(Vec3::X * 5.0))
+///         .run();
+/// }
+/// # #[cfg(not(feature = "f32"))]
+/// # fn main() {} // Doc test needs main
+/// ```
+///
+/// You can also modify the wind while the app is running.
+#[cfg(feature = "3d")]
+#[derive(Reflect, Resource, Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource)]
+pub struct Wind(pub Vector);
+
+#[cfg(feature = "3d")]
+impl Wind {
+    /// No wind.
+    pub const ZERO: Wind = Wind(Vector::ZERO);
+}
@@ -38,6 +38,18 @@ fn create_app() -> App {
     app
 }
 
+/// Spawns a dynamic body at the given position with the mass properties of a ball with the given radius.
+fn spawn_body(app: &mut App, position: Vector, radius: Scalar) -> Entity {
+    app.world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Dynamic,
+            Position(position),
+            MassPropertiesBundle::new_computed(&Collider::ball(radius), 1.0),
+        ))
+        .id()
+}
+
 fn tick_60_fps(app: &mut App) {
     let mut update_strategy = app.world.resource_mut::<TimeUpdateStrategy>();
     let TimeUpdateStrategy::ManualInstant(prev_time) = *update_strategy else {
@@ -114,7 +126,9 @@ fn body_with_velocity_moves_on_first_frame() {
     // one tick only.
     tick_60_fps(&mut app);
 
-    let mut app_query = app.world.query::<(&Position, &RigidBody)>();
+    let mut app_query = app
+        .world
+        .query_filtered::<(&Position, &RigidBody), Without<WorldBody>>();
 
     let (pos, _body) = app_query.single(&app.world);
 
@@ -143,7 +157,9 @@ fn body_with_velocity_moves() {
         tick_60_fps(&mut app);
     }
 
-    let mut app_query = app.world.query::<(&Transform, &RigidBody)>();
+    let mut app_query = app
+        .world
+        .query_filtered::<(&Transform, &RigidBody), Without<WorldBody>>();
 
     let (transform, _body) = app_query.single(&app.world);
 
@@ -224,6 +240,2252 @@ fn cubes_simulation_is_locally_deterministic() {
     }
 }
 
+#[cfg(all(feature = "3d", feature = "f32"))]
+#[test]
+fn cut_splits_soft_body_along_plane() {
+    let mut app = create_app();
+
+    app.insert_resource(Gravity::ZERO);
+
+    // Two tetrahedra sharing a face on the YZ-plane.
+    let positions = [
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+        Vector::new(0.0, 0.0, 1.0),
+        Vector::new(-1.0, 0.0, 0.0),
+        Vector::new(1.0, 0.0, 0.0),
+    ];
+    let particles = positions
+        .iter()
+        .map(|position| {
+            app.world
+                .spawn((
+                    SpatialBundle::default(),
+                    RigidBody::Dynamic,
+                    LockedAxes::ROTATION_LOCKED,
+                    Position(*position),
+                    Collider::ball(0.05),
+                    // Keep the duplicated particles from pushing the originals away
+                    CollisionLayers::none(),
+                ))
+                .id()
+        })
+        .collect::<Vec<_>>();
+    let volume1 = app
+        .world
+        .spawn(VolumeConstraint::new(
+            &particles[0],
+            &positions[0],
+            &particles[1],
+            &positions[1],
+            &particles[2],
+            &positions[2],
+            &particles[3],
+            &positions[3],
+        ))
+        .id();
+    let volume2 = app
+        .world
+        .spawn(VolumeConstraint::new(
+            &particles[0],
+            &positions[0],
+            &particles[1],
+            &positions[1],
+            &particles[2],
+            &positions[2],
+            &particles[4],
+            &positions[4],
+        ))
+        .id();
+
+    tick_60_fps(&mut app);
+
+    let mut masses = app.world.query_filtered::<&Mass, With<RigidBody>>();
+    let total_mass = masses.iter(&app.world).map(|mass| mass.0).sum::<Scalar>();
+    let mut volumes = app.world.query::<&VolumeConstraint>();
+    let total_volume = volumes
+        .iter(&app.world)
+        .map(|volume| volume.rest_volume)
+        .sum::<Scalar>();
+
+    app.world
+        .send_event(CutSoftBody::plane(Vector::X * 0.1, Vector::X));
+
+    tick_60_fps(&mut app);
+
+    // A particle is spawned on each side of the three edges crossing the cut.
+    let mut bodies = app.world.query_filtered::<&RigidBody, Without<WorldBody>>();
+    assert_eq!(bodies.iter(&app.world).count(), 11);
+
+    // Splitting the particles doesn't change the total mass.
+    let split_mass = masses.iter(&app.world).map(|mass| mass.0).sum::<Scalar>();
+    assert!((split_mass - total_mass).abs() < 0.001 * total_mass);
+
+    // The intersected tetrahedron is split into a tetrahedron and a prism made of three tetrahedra.
+    let split_volumes = volumes
+        .iter(&app.world)
+        .map(|volume| volume.rest_volume)
+        .collect::<Vec<_>>();
+    assert_eq!(split_volumes.len(), 5);
+    assert!((split_volumes.iter().sum::<Scalar>() - total_volume).abs() < 0.001 * total_volume);
+
+    let entities1 = app
+        .world
+        .get::<VolumeConstraint>(volume1)
+        .unwrap()
+        .entities();
+    let entities2 = app
+        .world
+        .get::<VolumeConstraint>(volume2)
+        .unwrap()
+        .entities();
+    assert!(entities1.iter().all(|entity| !entities2.contains(entity)));
+
+    // Pulling the positive half away doesn't drag the negative half along with it.
+    app.world.get_mut::<LinearVelocity>(particles[4]).unwrap().0 = Vector::X * 2.0;
+
+    for _ in 0..30 {
+        tick_60_fps(&mut app);
+    }
+
+    for entity in entities1 {
+        let index = particles
+            .iter()
+            .position(|particle| *particle == entity)
+            .unwrap();
+        let position = app.world.get::<Position>(entity).unwrap().0;
+        assert!(position.distance(positions[index]) < 0.01);
+    }
+    let face_center = entities2
+        .iter()
+        .filter(|entity| **entity != particles[4])
+        .map(|entity| app.world.get::<Position>(*entity).unwrap().0)
+        .sum::<Vector>()
+        / 3.0;
+    // The cut face of the positive half started at x = 0.1 and follows the pulled particle.
+    assert!(face_center.x > 0.15);
+}
+
+#[cfg(all(feature = "3d", feature = "f32"))]
+#[test]
+fn blades_cut_elements_they_pass_through() {
+    let cut = |start: Vector, end: Vector| {
+        let mut app = create_app();
+
+        app.insert_resource(Gravity::ZERO);
+
+        let positions = [
+            Vector::new(0.0, 0.0, 0.0),
+            Vector::new(1.0, 0.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0),
+            Vector::new(0.0, 0.0, 1.0),
+        ];
+        let particles = positions.map(|position| {
+            app.world
+                .spawn((RigidBody::Dynamic, Position(position), Mass(1.0)))
+                .id()
+        });
+        let volume = app
+            .world
+            .spawn(VolumeConstraint::new(
+                &particles[0],
+                &positions[0],
+                &particles[1],
+                &positions[1],
+                &particles[2],
+                &positions[2],
+                &particles[3],
+                &positions[3],
+            ))
+            .id();
+
+        tick_60_fps(&mut app);
+
+        app.world
+            .send_event(CutSoftBody::blade(start, end, Vector::Y).with_mode(CutMode::Remove));
+
+        tick_60_fps(&mut app);
+
+        app.world.get_entity(volume).is_none()
+    };
+
+    // The blade only passes through the part of the tetrahedron near its top edge, away from its center.
+    assert!(cut(Vector::new(0.2, 0.5, -1.0), Vector::new(0.2, 0.5, 1.0)));
+
+    // The blade starts above the tetrahedron and misses it.
+    assert!(!cut(
+        Vector::new(0.2, 0.9, -1.0),
+        Vector::new(0.2, 0.9, 1.0)
+    ));
+}
+
+#[cfg(all(feature = "3d", feature = "f32"))]
+#[test]
+fn cuts_in_the_same_frame_cut_each_others_pieces() {
+    let mut app = create_app();
+
+    app.insert_resource(Gravity::ZERO);
+
+    // Two tetrahedra sharing a face on the YZ-plane, with edge constraints along their edges.
+    let positions = [
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+        Vector::new(0.0, 0.0, 1.0),
+        Vector::new(-1.0, 0.0, 0.0),
+        Vector::new(1.0, 0.0, 0.0),
+    ];
+    let particles = positions.map(|position| {
+        app.world
+            .spawn((RigidBody::Dynamic, Position(position), Mass(1.0)))
+            .id()
+    });
+    for tetrahedron in [[0, 1, 2, 3], [0, 1, 2, 4]] {
+        let [a, b, c, d] = tetrahedron;
+        app.world.spawn(VolumeConstraint::new(
+            &particles[a],
+            &positions[a],
+            &particles[b],
+            &positions[b],
+            &particles[c],
+            &positions[c],
+            &particles[d],
+            &positions[d],
+        ));
+    }
+    for (a, b) in [
+        (0, 1),
+        (0, 2),
+        (1, 2),
+        (0, 3),
+        (1, 3),
+        (2, 3),
+        (0, 4),
+        (1, 4),
+        (2, 4),
+    ] {
+        app.world.spawn(EdgeConstraint::new(
+            &particles[a],
+            &positions[a],
+            &particles[b],
+            &positions[b],
+        ));
+    }
+
+    tick_60_fps(&mut app);
+
+    let mut masses = app.world.query::<&Mass>();
+    let total_mass = masses.iter(&app.world).map(|mass| mass.0).sum::<Scalar>();
+    let mut volumes = app.world.query::<&VolumeConstraint>();
+    let total_volume = volumes
+        .iter(&app.world)
+        .map(|volume| volume.rest_volume)
+        .sum::<Scalar>();
+
+    // The second cut goes through the pieces left by the first one.
+    app.world
+        .send_event(CutSoftBody::plane(Vector::X * 0.5, Vector::X));
+    app.world
+        .send_event(CutSoftBody::plane(Vector::Y * 0.3, Vector::Y));
+
+    tick_60_fps(&mut app);
+
+    let mut bodies = app.world.query_filtered::<&RigidBody, Without<WorldBody>>();
+    assert!(bodies.iter(&app.world).count() > 11);
+
+    let split_mass = masses.iter(&app.world).map(|mass| mass.0).sum::<Scalar>();
+    assert!((split_mass - total_mass).abs() < 0.001 * total_mass);
+
+    let split_volume = volumes
+        .iter(&app.world)
+        .map(|volume| volume.rest_volume)
+        .sum::<Scalar>();
+    assert!((split_volume - total_volume).abs() < 0.001 * total_volume);
+
+    // All constraints use particles that exist.
+    let mut edges = app.world.query::<&EdgeConstraint>();
+    let constraint_particles = volumes
+        .iter(&app.world)
+        .flat_map(|volume| volume.entities())
+        .chain(edges.iter(&app.world).flat_map(|edge| edge.entities()))
+        .collect::<Vec<_>>();
+    assert!(constraint_particles
+        .iter()
+        .all(|particle| app.world.get::<Position>(*particle).is_some()));
+}
+
+#[cfg(all(feature = "3d", feature = "f32", feature = "async-collider"))]
+#[test]
+fn soft_body_surface_is_rendered_and_split() {
+    use bevy::render::{mesh::PrimitiveTopology, render_resource::VertexFormat};
+
+    let mut app = create_app();
+
+    app.insert_resource(Gravity::ZERO);
+
+    let positions = [
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(1.0, 0.0, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+        Vector::new(0.0, 0.0, 1.0),
+    ];
+    let particles = positions.map(|position| {
+        app.world
+            .spawn((
+                RigidBody::Dynamic,
+                LockedAxes::ROTATION_LOCKED,
+                Position(position),
+                Mass(1.0),
+            ))
+            .id()
+    });
+    app.world.spawn(VolumeConstraint::new(
+        &particles[0],
+        &positions[0],
+        &particles[1],
+        &positions[1],
+        &particles[2],
+        &positions[2],
+        &particles[3],
+        &positions[3],
+    ));
+
+    let mesh = app.world.resource_mut::<Assets<Mesh>>().add(
+        Mesh::new(PrimitiveTopology::TriangleList)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0, 0.0]; 3]),
+    );
+    let [a, b, c, d] = particles;
+    let surface = app
+        .world
+        .spawn((
+            SoftBodySurface {
+                triangles: vec![[a, c, b], [a, b, d], [a, d, c], [b, c, d]],
+            },
+            mesh.clone(),
+        ))
+        .id();
+
+    tick_60_fps(&mut app);
+
+    let vertex_count = |app: &App| {
+        let mesh = app.world.resource::<Assets<Mesh>>().get(&mesh).unwrap();
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        assert_eq!(VertexFormat::from(positions), VertexFormat::Float32x3);
+        (positions.len(), mesh.indices().unwrap().len())
+    };
+    assert_eq!(vertex_count(&app), (4, 12));
+    // The UVs don't match the rebuilt vertices
+    let meshes = app.world.resource::<Assets<Mesh>>();
+    assert!(meshes
+        .get(&mesh)
+        .unwrap()
+        .attribute(Mesh::ATTRIBUTE_UV_0)
+        .is_none());
+
+    // Three triangles cross the cut, and each of them is split into three.
+    app.world
+        .send_event(CutSoftBody::plane(Vector::X * 0.2, Vector::X));
+
+    tick_60_fps(&mut app);
+
+    let triangles = &app.world.get::<SoftBodySurface>(surface).unwrap().triangles;
+    assert_eq!(triangles.len(), 10);
+    assert_eq!(vertex_count(&app), (10, 30));
+
+    // Once the particles are asleep, the mesh is no longer rebuilt
+    for _ in 0..90 {
+        tick_60_fps(&mut app);
+    }
+    app.world
+        .resource_mut::<Assets<Mesh>>()
+        .get_mut(&mesh)
+        .unwrap()
+        .insert_attribute(Mesh::ATTRIBUTE_POSITION, vec![[0.0; 3]; 3]);
+    tick_60_fps(&mut app);
+    assert_eq!(vertex_count(&app).0, 3);
+}
+
+#[cfg(all(feature = "3d", feature = "f32"))]
+#[test]
+fn fracture_only_splits_off_fractured_tetrahedron() {
+    let mut app = create_app();
+
+    app.insert_resource(Gravity::ZERO);
+
+    let positions = [
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+        Vector::new(0.0, 0.0, 1.0),
+        Vector::new(-1.0, 0.0, 0.0),
+        Vector::new(1.0, 0.0, 0.0),
+        Vector::new(1.0, 1.0, 1.0),
+    ];
+    let particles = positions
+        .iter()
+        .map(|position| {
+            app.world
+                .spawn((
+                    SpatialBundle::default(),
+                    RigidBody::Dynamic,
+                    LockedAxes::ROTATION_LOCKED,
+                    Position(*position),
+                    Collider::ball(0.05),
+                    CollisionLayers::none(),
+                ))
+                .id()
+        })
+        .collect::<Vec<_>>();
+    let mut spawn_tetrahedron = |indices: [usize; 4]| {
+        let [a, b, c, d] = indices;
+        app.world
+            .spawn(VolumeConstraint::new(
+                &particles[a],
+                &positions[a],
+                &particles[b],
+                &positions[b],
+                &particles[c],
+                &positions[c],
+                &particles[d],
+                &positions[d],
+            ))
+            .id()
+    };
+    // The middle tetrahedron shares a face with both of its neighbors.
+    let left = spawn_tetrahedron([0, 1, 2, 3]);
+    let middle = spawn_tetrahedron([0, 1, 2, 4]);
+    let right = spawn_tetrahedron([1, 2, 4, 5]);
+    app.world.entity_mut(middle).insert(FractureThreshold(0.5));
+
+    // Make the tetrahedra soft so that the middle one can be crushed.
+    for tetrahedron in [left, middle, right] {
+        app.world
+            .get_mut::<VolumeConstraint>(tetrahedron)
+            .unwrap()
+            .compliance = 1.0;
+    }
+
+    tick_60_fps(&mut app);
+
+    // Crush the middle tetrahedron.
+    app.world.get_mut::<Position>(particles[4]).unwrap().0.x = 0.2;
+
+    tick_60_fps(&mut app);
+
+    // Only the four particles of the fractured tetrahedron are duplicated.
+    let mut bodies = app.world.query_filtered::<&RigidBody, Without<WorldBody>>();
+    assert_eq!(bodies.iter(&app.world).count(), 10);
+    assert!(app.world.get::<FractureThreshold>(middle).is_none());
+
+    let entities = |entity: Entity| {
+        app.world
+            .get::<VolumeConstraint>(entity)
+            .unwrap()
+            .entities()
+    };
+    let (left, middle, right) = (entities(left), entities(middle), entities(right));
+    assert!(middle.iter().all(|entity| !left.contains(entity)));
+    assert!(middle.iter().all(|entity| !right.contains(entity)));
+
+    // The neighbors stay connected to each other.
+    let shared = left.iter().filter(|entity| right.contains(entity)).count();
+    assert_eq!(shared, 2);
+}
+
+#[cfg(all(feature = "3d", feature = "f32"))]
+#[test]
+fn dihedral_bending_returns_to_rest_angle() {
+    use bevy::ecs::system::CommandQueue;
+
+    // Bends two flat triangles by lifting one of them and returns the angle between them after a few frames.
+    let bend = |compliance: Scalar| {
+        let mut app = create_app();
+
+        app.insert_resource(Gravity::ZERO);
+
+        let positions = [
+            Vector::ZERO,
+            Vector::X,
+            Vector::Z,
+            Vector::new(1.0, 0.0, -1.0),
+        ];
+        let particles = positions.map(|position| {
+            app.world
+                .spawn((
+                    SpatialBundle::default(),
+                    RigidBody::Dynamic,
+                    LockedAxes::ROTATION_LOCKED,
+                    Position(position),
+                    Collider::ball(0.05),
+                    CollisionLayers::none(),
+                ))
+                .id()
+        });
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &app.world);
+        let constraints = BendingModel::Dihedral.spawn_constraints(
+            &mut commands,
+            &particles,
+            &positions,
+            &[[0, 1, 2], [1, 0, 3]],
+            compliance,
+        );
+        queue.apply(&mut app.world);
+        assert_eq!(constraints.len(), 1);
+
+        let constraint = *app
+            .world
+            .get::<DihedralBendingConstraint>(constraints[0])
+            .unwrap();
+        assert_relative_eq!(constraint.rest_angle, 0.0);
+
+        tick_60_fps(&mut app);
+
+        // Bend the triangles by lifting the tip of one of them.
+        app.world.get_mut::<Position>(particles[2]).unwrap().0.y = 0.5;
+
+        for _ in 0..10 {
+            tick_60_fps(&mut app);
+        }
+
+        let [p1, p2, p3, p4] =
+            particles.map(|particle| app.world.get::<Position>(particle).unwrap().0);
+        let (angle, _) = DihedralBendingConstraint::angle_and_gradients(&[p1, p2, p3, p4]).unwrap();
+        angle.abs()
+    };
+
+    // A stiff constraint flattens the triangles, while a compliant one lets them stay bent.
+    let stiff_angle = bend(0.0);
+    let soft_angle = bend(10.0);
+    assert!(stiff_angle < 0.01);
+    assert!(soft_angle > 0.1);
+}
+
+#[cfg(all(feature = "3d", feature = "f32"))]
+#[test]
+fn wind_pushes_cloth_triangle() {
+    let mut app = create_app();
+
+    app.insert_resource(Gravity::ZERO)
+        .insert_resource(Wind(Vector::X * 10.0));
+
+    // A triangle facing the wind
+    let particles = [Vector::ZERO, Vector::Y, Vector::Z].map(|position| {
+        app.world
+            .spawn((
+                SpatialBundle::default(),
+                RigidBody::Dynamic,
+                LockedAxes::ROTATION_LOCKED,
+                Position(position),
+                Collider::ball(0.05),
+            ))
+            .id()
+    });
+    app.world.spawn(Aerodynamics::new(vec![particles]));
+
+    for _ in 0..10 {
+        tick_60_fps(&mut app);
+    }
+
+    for particle in particles {
+        assert!(app.world.get::<Position>(particle).unwrap().x > 0.0);
+        assert!(app.world.get::<LinearVelocity>(particle).unwrap().x > 0.0);
+        // The aerodynamic forces should not accumulate in the external forces
+        assert_eq!(
+            app.world.get::<ExternalForce>(particle).unwrap().force(),
+            Vector::ZERO
+        );
+    }
+}
+
+#[cfg(feature = "3d")]
+#[test]
+fn wind_wakes_sleeping_cloth_triangle() {
+    let mut app = create_app();
+
+    app.insert_resource(Gravity::ZERO);
+
+    let particles = [Vector::ZERO, Vector::Y, Vector::Z].map(|position| {
+        app.world
+            .spawn((
+                SpatialBundle::default(),
+                RigidBody::Dynamic,
+                LockedAxes::ROTATION_LOCKED,
+                Position(position),
+                Collider::ball(0.05),
+            ))
+            .id()
+    });
+    app.world.spawn(Aerodynamics::new(vec![particles]));
+
+    // Without wind, the particles fall asleep after the deactivation time
+    for _ in 0..90 {
+        tick_60_fps(&mut app);
+    }
+    for particle in particles {
+        assert!(app.world.get::<Sleeping>(particle).is_some());
+    }
+
+    app.insert_resource(Wind(Vector::X * 10.0));
+
+    for _ in 0..10 {
+        tick_60_fps(&mut app);
+    }
+
+    for particle in particles {
+        assert!(app.world.get::<Sleeping>(particle).is_none());
+        assert!(app.world.get::<LinearVelocity>(particle).unwrap().x > 0.0);
+    }
+}
+
+#[cfg(all(feature = "3d", feature = "f32"))]
+#[test]
+fn damped_edge_constraint_settles() {
+    fn relative_speed_after_one_second(damping: Scalar) -> Scalar {
+        let mut app = create_app();
+
+        app.insert_resource(Gravity::ZERO);
+
+        let [particle1, particle2] = [Vector::ZERO, Vector::X].map(|position| {
+            app.world
+                .spawn((
+                    SpatialBundle::default(),
+                    RigidBody::Dynamic,
+                    LockedAxes::ROTATION_LOCKED,
+                    Position(position),
+                    MassPropertiesBundle::new_computed(&Collider::ball(0.1), 1.0),
+                ))
+                .id()
+        });
+        // Stretched to twice the rest length
+        app.world.spawn(
+            EdgeConstraint::new(&particle1, &Vector::ZERO, &particle2, &Vector::X)
+                .with_rest_length(0.5)
+                .with_compliance(0.01)
+                .with_damping(damping),
+        );
+
+        for _ in 0..60 {
+            tick_60_fps(&mut app);
+        }
+
+        let velocity1 = app.world.get::<LinearVelocity>(particle1).unwrap().0;
+        let velocity2 = app.world.get::<LinearVelocity>(particle2).unwrap().0;
+        (velocity2 - velocity1).length()
+    }
+
+    assert!(relative_speed_after_one_second(1.0) < 0.5 * relative_speed_after_one_second(0.0));
+}
+
+#[test]
+fn force_field_pushes_bodies_inside() {
+    let mut app = create_app();
+
+    app.insert_resource(Gravity::ZERO);
+
+    app.world.spawn((
+        ForceField::wind(Vector::X * 10.0),
+        Collider::ball(2.0),
+        Sensor,
+    ));
+    let inside = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Dynamic,
+            Position(Vector::Y),
+            MassPropertiesBundle::new_computed(&Collider::ball(0.5), 1.0),
+            ExternalForce::new(Vector::Y).with_persistence(true),
+        ))
+        .id();
+    let outside = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Dynamic,
+            Position(Vector::Y * 5.0),
+            MassPropertiesBundle::new_computed(&Collider::ball(0.5), 1.0),
+        ))
+        .id();
+
+    for _ in 0..10 {
+        tick_60_fps(&mut app);
+    }
+
+    let velocity = app.world.get::<LinearVelocity>(inside).unwrap().0;
+    assert!(velocity.x > 0.0 && velocity.y > 0.0);
+    // The force of the field is applied without changing the body's own external force
+    assert_eq!(
+        app.world.get::<ExternalForce>(inside).unwrap().force(),
+        Vector::Y
+    );
+    assert_eq!(
+        *app.world.get::<LinearVelocity>(outside).unwrap(),
+        LinearVelocity::ZERO
+    );
+}
+
+#[test]
+fn force_fields_wake_sleeping_bodies() {
+    let mut app = create_app();
+
+    app.insert_resource(Gravity::ZERO);
+
+    let body = spawn_body(&mut app, Vector::ZERO, 0.5);
+
+    // The body falls asleep after the deactivation time, as it isn't moving
+    for _ in 0..90 {
+        tick_60_fps(&mut app);
+    }
+    assert!(app.world.get::<Sleeping>(body).is_some());
+
+    app.world.spawn((
+        ForceField::wind(Vector::X * 10.0),
+        Collider::ball(2.0),
+        Sensor,
+    ));
+
+    for _ in 0..10 {
+        tick_60_fps(&mut app);
+    }
+
+    assert!(app.world.get::<Sleeping>(body).is_none());
+    assert!(app.world.get::<LinearVelocity>(body).unwrap().x > 0.0);
+}
+
+#[test]
+fn gravity_field_overrides_global_gravity() {
+    let mut app = create_app();
+
+    // A planet at the origin without a collider and a room with upwards gravity that has a higher priority
+    app.world.spawn(GravityField::point(
+        10.0,
+        GravityFalloff::InverseSquare { radius: 1.0 },
+    ));
+    app.world.spawn((
+        Position(Vector::X * 20.0),
+        Collider::ball(2.0),
+        Sensor,
+        CollisionLayers::from_bits(0b01, 0b01),
+        GravityField::directional(Vector::Y * 5.0).with_priority(1),
+    ));
+
+    let orbiting = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Dynamic,
+            Position(Vector::X * 2.0),
+            MassPropertiesBundle::new_computed(&Collider::ball(0.1), 1.0),
+        ))
+        .id();
+    let in_room = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Dynamic,
+            Position(Vector::X * 20.0),
+            MassPropertiesBundle::new_computed(&Collider::ball(0.1), 1.0),
+        ))
+        .id();
+    let ignoring_room = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Dynamic,
+            Position(Vector::X * 20.0 + Vector::Y * 0.5),
+            MassPropertiesBundle::new_computed(&Collider::ball(0.1), 1.0),
+            CollisionLayers::from_bits(0b10, 0b10),
+        ))
+        .id();
+
+    for _ in 0..10 {
+        tick_60_fps(&mut app);
+    }
+
+    // Pulled towards the planet instead of down
+    let velocity = app.world.get::<LinearVelocity>(orbiting).unwrap();
+    assert!(velocity.x < 0.0);
+    assert_relative_eq!(velocity.y, 0.0);
+
+    // Pushed up by the room instead of towards the planet
+    let velocity = app.world.get::<LinearVelocity>(in_room).unwrap();
+    assert!(velocity.y > 0.0);
+    assert_relative_eq!(velocity.x, 0.0);
+
+    // The layers of the body don't interact with the room, so it's pulled towards the planet
+    let velocity = app.world.get::<LinearVelocity>(ignoring_room).unwrap();
+    assert!(velocity.x < 0.0);
+}
+
+#[test]
+fn joint_motors_drive_bodies() {
+    let mut app = create_app();
+
+    app.insert_resource(Gravity::ZERO);
+
+    let anchor = app.world.spawn(RigidBody::Static).id();
+    let wheel = spawn_body(&mut app, Vector::ZERO, 0.5);
+    let weak_wheel = spawn_body(&mut app, Vector::ZERO, 1.0);
+    let piston = spawn_body(&mut app, Vector::ZERO, 0.5);
+
+    let wheel_joint = app
+        .world
+        .spawn(RevoluteJoint::new(anchor, wheel).with_motor(JointMotor::velocity(2.0)))
+        .id();
+    app.world.spawn(
+        RevoluteJoint::new(anchor, weak_wheel)
+            .with_motor(JointMotor::velocity(2.0).with_max_force(0.5)),
+    );
+    app.world.spawn(
+        PrismaticJoint::new(anchor, piston).with_motor(
+            JointMotor::position(1.5)
+                .with_compliance(0.001)
+                .with_damping(50.0),
+        ),
+    );
+
+    for _ in 0..120 {
+        tick_60_fps(&mut app);
+    }
+
+    #[cfg(feature = "2d")]
+    let (speed, weak_speed) = (
+        app.world.get::<AngularVelocity>(wheel).unwrap().0,
+        app.world.get::<AngularVelocity>(weak_wheel).unwrap().0,
+    );
+    #[cfg(feature = "3d")]
+    let (speed, weak_speed) = (
+        app.world.get::<AngularVelocity>(wheel).unwrap().z,
+        app.world.get::<AngularVelocity>(weak_wheel).unwrap().z,
+    );
+
+    assert_relative_eq!(speed, 2.0, epsilon = 0.05);
+    // The torque is limited, so the heavier wheel accelerates slowly
+    assert!(weak_speed > 0.1 && weak_speed < 1.0);
+    assert!(
+        app.world
+            .get::<RevoluteJoint>(wheel_joint)
+            .unwrap()
+            .motor_lagrange
+            .abs()
+            > 0.0
+    );
+    assert_relative_eq!(
+        app.world.get::<Position>(piston).unwrap().x,
+        1.5,
+        epsilon = 0.05
+    );
+}
+
+#[cfg(feature = "3d")]
+#[test]
+fn revolute_motor_drives_tilted_hinge() {
+    let mut app = create_app();
+
+    app.insert_resource(Gravity::ZERO);
+
+    let axis = Vector::ONE.normalize();
+    let anchor = app.world.spawn(RigidBody::Static).id();
+    let wheel = spawn_body(&mut app, Vector::ZERO, 0.5);
+    let joint = app
+        .world
+        .spawn(
+            RevoluteJoint::new(anchor, wheel)
+                .with_aligned_axis(axis)
+                .with_motor(
+                    JointMotor::position(1.0)
+                        .with_compliance(0.001)
+                        .with_damping(50.0),
+                ),
+        )
+        .id();
+
+    for _ in 0..120 {
+        tick_60_fps(&mut app);
+    }
+
+    // The hinge is rotated by the target angle around the tilted axis
+    let rotation = *app.world.get::<Rotation>(wheel).unwrap();
+    let (rotation_axis, angle) = rotation.to_axis_angle();
+    assert_relative_eq!(angle, 1.0, epsilon = 0.05);
+    assert!(rotation_axis.dot(axis) > 0.99);
+
+    let joint = app.world.get::<RevoluteJoint>(joint).unwrap();
+    assert_relative_eq!(
+        joint.relative_angle(&Rotation::default(), &rotation),
+        1.0,
+        epsilon = 0.05
+    );
+}
+
+#[test]
+fn coupling_joints_couple_motion() {
+    let mut app = create_app();
+
+    app.insert_resource(Gravity::ZERO);
+
+    let anchor = app.world.spawn(RigidBody::Static).id();
+    let hinge = |app: &mut App, body: Entity, position: Vector, motor: Option<JointMotor>| {
+        let joint = RevoluteJoint::new(anchor, body).with_local_anchor_1(position);
+        app.world.spawn(match motor {
+            Some(motor) => joint.with_motor(motor),
+            None => joint,
+        });
+    };
+
+    // Two meshing gears, where the first one is driven by a motor
+    let gear1_position = Vector::ZERO;
+    let gear2_position = Vector::X * 3.0;
+    let gear1 = spawn_body(&mut app, gear1_position, 0.5);
+    let gear2 = spawn_body(&mut app, gear2_position, 0.5);
+    hinge(
+        &mut app,
+        gear1,
+        gear1_position,
+        Some(JointMotor::velocity(2.0)),
+    );
+    hinge(&mut app, gear2, gear2_position, None);
+    app.world
+        .spawn(GearJoint::new(gear1, gear2).with_ratio(0.5));
+
+    // A pinion driven by a motor and a rack that slides along the X axis
+    let pinion_position = Vector::Y * 3.0;
+    let pinion = spawn_body(&mut app, pinion_position, 0.5);
+    let rack = spawn_body(&mut app, Vector::Y * 5.0, 0.5);
+    hinge(
+        &mut app,
+        pinion,
+        pinion_position,
+        Some(JointMotor::velocity(1.0)),
+    );
+    app.world.spawn(
+        PrismaticJoint::new(anchor, rack)
+            .with_local_anchor_1(Vector::Y * 5.0)
+            .with_free_axis(Vector::X),
+    );
+    app.world
+        .spawn(RackAndPinionJoint::new(pinion, rack).with_ratio(0.5));
+
+    // Two bodies hanging from a pulley, where the first one starts moving down after the first frame
+    let weight1 = spawn_body(&mut app, Vector::X * -10.0 - Vector::Y * 2.0, 0.5);
+    let weight2 = spawn_body(&mut app, Vector::X * -6.0 - Vector::Y * 2.0, 0.5);
+    app.world.spawn(
+        PulleyJoint::new(weight1, weight2).with_ground_anchors(Vector::X * -10.0, Vector::X * -6.0),
+    );
+
+    tick_60_fps(&mut app);
+    app.world
+        .entity_mut(weight1)
+        .insert(LinearVelocity(Vector::NEG_Y));
+
+    for _ in 0..120 {
+        tick_60_fps(&mut app);
+    }
+
+    #[cfg(feature = "2d")]
+    let (gear1_speed, gear2_speed, pinion_speed) = (
+        app.world.get::<AngularVelocity>(gear1).unwrap().0,
+        app.world.get::<AngularVelocity>(gear2).unwrap().0,
+        app.world.get::<AngularVelocity>(pinion).unwrap().0,
+    );
+    #[cfg(feature = "3d")]
+    let (gear1_speed, gear2_speed, pinion_speed) = (
+        app.world.get::<AngularVelocity>(gear1).unwrap().z,
+        app.world.get::<AngularVelocity>(gear2).unwrap().z,
+        app.world.get::<AngularVelocity>(pinion).unwrap().z,
+    );
+    let rack_speed = app.world.get::<LinearVelocity>(rack).unwrap().x;
+
+    assert!(gear1_speed > 1.0);
+    assert_relative_eq!(gear2_speed, -0.5 * gear1_speed, epsilon = 0.05);
+    assert!(pinion_speed > 0.5);
+    assert_relative_eq!(rack_speed, 0.5 * pinion_speed, epsilon = 0.05);
+
+    // The first weight has pulled the second one up, and the rope has kept its length
+    let position1 = app.world.get::<Position>(weight1).unwrap().0;
+    let position2 = app.world.get::<Position>(weight2).unwrap().0;
+    assert!(position1.y < -2.5);
+    assert!(position2.y > -1.5);
+    assert_relative_eq!(-position1.y - position2.y, 4.0, epsilon = 0.05);
+}
+
+#[test]
+fn joints_break_when_force_exceeds_threshold() {
+    let mut app = create_app();
+
+    let anchor = app.world.spawn(RigidBody::Static).id();
+
+    // Two bodies hanging from distance joints, where only the weak joint should break under gravity
+    let strong_body = spawn_body(&mut app, Vector::NEG_Y, 0.5);
+    let weak_body = spawn_body(&mut app, Vector::X * 2.0 - Vector::Y, 0.5);
+    let strong_joint = app
+        .world
+        .spawn(
+            DistanceJoint::new(anchor, strong_body)
+                .with_rest_length(1.0)
+                .with_break_force(1000.0),
+        )
+        .id();
+    let weak_joint = app
+        .world
+        .spawn(
+            DistanceJoint::new(anchor, weak_body)
+                .with_local_anchor_1(Vector::X * 2.0)
+                .with_rest_length(1.0)
+                .with_break_force(0.1),
+        )
+        .id();
+
+    let mut reader = app.world.resource::<Events<JointBroken>>().get_reader();
+    let mut broken = vec![];
+
+    for _ in 0..60 {
+        tick_60_fps(&mut app);
+        let events = app.world.resource::<Events<JointBroken>>();
+        broken.extend(reader.read(events).copied());
+    }
+
+    assert_eq!(broken.len(), 1);
+    assert_eq!(broken[0].entity, weak_joint);
+    assert_eq!(broken[0].entity2, weak_body);
+    assert!(broken[0].force > 0.1);
+
+    assert!(app.world.get::<DistanceJoint>(weak_joint).is_none());
+    assert!(app.world.get::<DistanceJoint>(strong_joint).is_some());
+    assert!(app.world.get::<Position>(weak_body).unwrap().y < -2.0);
+    assert_relative_eq!(
+        app.world.get::<Position>(strong_body).unwrap().y,
+        -1.0,
+        epsilon = 0.05
+    );
+}
+
+#[test]
+fn coupling_joints_break_under_load() {
+    let mut app = create_app();
+
+    let anchor = app.world.spawn(RigidBody::Static).id();
+
+    // A gear driven by a motor that is coupled to a static gear, so the gear joint has to resist the motor
+    let gear = spawn_body(&mut app, Vector::ZERO, 0.5);
+    app.world.spawn(
+        RevoluteJoint::new(anchor, gear)
+            .with_motor(JointMotor::velocity(10.0).with_max_force(50.0)),
+    );
+    let gear_joint = app
+        .world
+        .spawn(
+            GearJoint::new(gear, anchor)
+                .with_break_force(0.0)
+                .with_break_torque(1.0),
+        )
+        .id();
+
+    // Two weights hanging from a pulley, where the second one is held in place
+    let weight1 = spawn_body(&mut app, Vector::X * -10.0 - Vector::Y * 2.0, 0.5);
+    let weight2 = spawn_body(&mut app, Vector::X * -6.0 - Vector::Y * 2.0, 0.5);
+    app.world.spawn(
+        FixedJoint::new(anchor, weight2).with_local_anchor_1(Vector::X * -6.0 - Vector::Y * 2.0),
+    );
+    let pulley_joint = app
+        .world
+        .spawn(
+            PulleyJoint::new(weight1, weight2)
+                .with_ground_anchors(Vector::X * -10.0, Vector::X * -6.0)
+                .with_break_torque(0.0)
+                .with_break_force(1.0),
+        )
+        .id();
+
+    let mut reader = app.world.resource::<Events<JointBroken>>().get_reader();
+    let mut broken = vec![];
+
+    for _ in 0..30 {
+        tick_60_fps(&mut app);
+        let events = app.world.resource::<Events<JointBroken>>();
+        broken.extend(reader.read(events).copied());
+    }
+
+    // The gear joint only exerts a torque and the pulley joint only exerts a force,
+    // so the zero break force and break torque don't break them
+    let gear_event = broken
+        .iter()
+        .find(|event| event.entity == gear_joint)
+        .unwrap();
+    assert_eq!(gear_event.force, 0.0);
+    assert!(gear_event.torque > 1.0);
+    let pulley_event = broken
+        .iter()
+        .find(|event| event.entity == pulley_joint)
+        .unwrap();
+    assert!(pulley_event.force > 1.0);
+    assert_eq!(pulley_event.torque, 0.0);
+}
+
+#[test]
+fn joint_springs_are_independent_of_mass() {
+    let mut app = create_app();
+
+    let anchor = app.world.spawn(RigidBody::Static).id();
+
+    // Hang a light and a heavy body from springs with the same frequency
+    let mut bodies = vec![];
+    for (i, radius) in [0.2, 1.0].into_iter().enumerate() {
+        let x = i as Scalar * 5.0;
+        let body = spawn_body(&mut app, Vector::X * x - Vector::Y, radius);
+        app.world.spawn(
+            DistanceJoint::new(anchor, body)
+                .with_local_anchor_1(Vector::X * x)
+                .with_rest_length(1.0)
+                .with_spring(JointSpring::new(1.0, 1.0)),
+        );
+        bodies.push(body);
+    }
+
+    for _ in 0..300 {
+        tick_60_fps(&mut app);
+    }
+
+    // At rest, the spring is stretched by m * g / k = g / ω^2
+    let stretch = app.world.resource::<Gravity>().0.length() / (2.0 * PI).powi(2);
+    for body in bodies {
+        assert_relative_eq!(
+            app.world.get::<Position>(body).unwrap().y,
+            -1.0 - stretch,
+            epsilon = 0.02
+        );
+    }
+}
+
+#[test]
+fn joints_attach_bodies_to_world() {
+    let mut app = create_app();
+
+    // A body hanging from a point in the world and a body fixed to a point in the world
+    let hanging_body = spawn_body(&mut app, Vector::X * 2.0 - Vector::Y, 0.5);
+    let fixed_body = spawn_body(&mut app, Vector::X * 5.0, 0.5);
+    let world_body = *app.world.resource::<WorldBodyEntity>();
+    app.world.spawn(
+        DistanceJoint::new_to_world(&world_body, hanging_body, Vector::X * 2.0 + Vector::Y)
+            .with_rest_length(2.0),
+    );
+    app.world.spawn(FixedJoint::new_to_world(
+        &world_body,
+        fixed_body,
+        Vector::X * 5.0,
+    ));
+
+    for _ in 0..60 {
+        tick_60_fps(&mut app);
+    }
+
+    let hanging_position = app.world.get::<Position>(hanging_body).unwrap().0;
+    assert_relative_eq!(hanging_position.x, 2.0, epsilon = 0.01);
+    assert_relative_eq!(hanging_position.y, -1.0, epsilon = 0.01);
+
+    let fixed_position = app.world.get::<Position>(fixed_body).unwrap().0;
+    assert_relative_eq!(fixed_position.x, 5.0, epsilon = 0.01);
+    assert_relative_eq!(fixed_position.y, 0.0, epsilon = 0.01);
+
+    // The world body stays at the origin
+    let mut world_body = app.world.query_filtered::<&Position, With<WorldBody>>();
+    assert_eq!(world_body.single(&app.world).0, Vector::ZERO);
+}
+
+#[test]
+fn world_body_is_respawned_after_despawn() {
+    let mut app = create_app();
+
+    let body = spawn_body(&mut app, Vector::NEG_Y, 0.5);
+    let old_world_body = *app.world.resource::<WorldBodyEntity>();
+    let joint = app
+        .world
+        .spawn(FixedJoint::new_to_world(
+            &old_world_body,
+            body,
+            Vector::NEG_Y,
+        ))
+        .id();
+
+    tick_60_fps(&mut app);
+
+    // Despawn the world body, which isn't remapped in the joints attached to it
+    app.world.despawn(old_world_body.0);
+
+    for _ in 0..10 {
+        tick_60_fps(&mut app);
+    }
+
+    let world_body = *app.world.resource::<WorldBodyEntity>();
+    assert_ne!(world_body, old_world_body);
+    assert!(app.world.get::<WorldBody>(world_body.0).is_some());
+    assert_eq!(
+        app.world.get::<RigidBody>(world_body.0),
+        Some(&RigidBody::Static)
+    );
+
+    // The joint still uses the old world body, so it isn't solved and the body falls
+    let start_position = app.world.get::<Position>(body).unwrap().0;
+    assert!(start_position.y < -1.1);
+
+    // Attach the joint to the new world body
+    app.world
+        .entity_mut(joint)
+        .insert(FixedJoint::new_to_world(&world_body, body, start_position));
+
+    // The joint is solved from the first substep, so the body doesn't fall
+    tick_60_fps(&mut app);
+
+    let position = app.world.get::<Position>(body).unwrap().0;
+    assert_relative_eq!(position.y, start_position.y, epsilon = 0.001);
+}
+
+#[test]
+fn custom_constraints_can_be_attached_to_world() {
+    use bevy::ecs::entity::{EntityMapper, MapEntities};
+
+    /// Keeps the distance between the centers of two bodies at `rest_length`.
+    #[derive(Component)]
+    struct CustomDistanceConstraint {
+        entity1: Entity,
+        entity2: Entity,
+        rest_length: Scalar,
+        lagrange: Scalar,
+    }
+
+    impl PositionConstraint for CustomDistanceConstraint {}
+
+    impl XpbdConstraint<2> for CustomDistanceConstraint {
+        fn entities(&self) -> [Entity; 2] {
+            [self.entity1, self.entity2]
+        }
+
+        fn clear_lagrange_multipliers(&mut self) {
+            self.lagrange = 0.0;
+        }
+
+        fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) {
+            let [body1, body2] = bodies;
+            let delta_x = body1.current_position() - body2.current_position();
+            let length = delta_x.length();
+            let c = length - self.rest_length;
+            if length <= 0.0 || c == 0.0 {
+                return;
+            }
+
+            let n = delta_x / length;
+            let w1 = self.compute_generalized_inverse_mass(body1, Vector::ZERO, n);
+            let w2 = self.compute_generalized_inverse_mass(body2, Vector::ZERO, n);
+            let delta_lagrange =
+                self.compute_lagrange_update(self.lagrange, c, &[n, -n], &[w1, w2], 0.0, dt);
+            self.lagrange += delta_lagrange;
+            self.apply_positional_correction(
+                body1,
+                body2,
+                delta_lagrange,
+                n,
+                Vector::ZERO,
+                Vector::ZERO,
+            );
+        }
+    }
+
+    impl MapEntities for CustomDistanceConstraint {
+        fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+            self.entity1 = entity_mapper.get_or_reserve(self.entity1);
+            self.entity2 = entity_mapper.get_or_reserve(self.entity2);
+        }
+    }
+
+    let mut app = create_app();
+
+    app.get_schedule_mut(SubstepSchedule).unwrap().add_systems(
+        solve_constraint::<CustomDistanceConstraint, 2>.in_set(SubstepSet::SolveUserConstraints),
+    );
+
+    // Hang a body from the origin using only the custom constraint
+    let body = spawn_body(&mut app, Vector::NEG_Y, 0.5);
+    let world_body = app.world.resource::<WorldBodyEntity>().0;
+    app.world.spawn(CustomDistanceConstraint {
+        entity1: world_body,
+        entity2: body,
+        rest_length: 1.0,
+        lagrange: 0.0,
+    });
+
+    for _ in 0..60 {
+        tick_60_fps(&mut app);
+    }
+
+    let position = app.world.get::<Position>(body).unwrap().0;
+    assert_relative_eq!(position.length(), 1.0, epsilon = 0.001);
+    assert_eq!(
+        app.world.get::<Position>(world_body).unwrap().0,
+        Vector::ZERO
+    );
+}
+
+#[cfg(feature = "3d")]
+#[test]
+fn swing_and_twist_limits_spherical_joint() {
+    let mut app = create_app();
+
+    app.insert_resource(Gravity::ZERO);
+
+    let anchor = app.world.spawn(RigidBody::Static).id();
+
+    // Swing two bodies by the same angle, once around the swing axis and once around the perpendicular axis,
+    // and rotate a third body around the swing axis
+    let limb = |app: &mut App, rotation: Quaternion, joint: SphericalJoint| {
+        let limb = app
+            .world
+            .spawn((
+                SpatialBundle::default(),
+                RigidBody::Dynamic,
+                Rotation(rotation),
+                MassPropertiesBundle::new_computed(&Collider::cuboid(0.2, 1.0, 0.2), 1.0),
+            ))
+            .id();
+        app.world.spawn(SphericalJoint {
+            entity2: limb,
+            ..joint
+        });
+        limb
+    };
+    let cone = SphericalJoint::new(anchor, anchor).with_swing_cone(0.3, 0.8);
+    let narrow = limb(&mut app, Quaternion::from_rotation_x(0.6), cone);
+    let wide = limb(&mut app, Quaternion::from_rotation_z(0.6), cone);
+    let twisted = limb(
+        &mut app,
+        Quaternion::from_rotation_x(0.6),
+        SphericalJoint::new(anchor, anchor).with_twist_limits(-0.2, 0.2),
+    );
+
+    for _ in 0..10 {
+        tick_60_fps(&mut app);
+    }
+
+    let swing_angle = |app: &App, entity: Entity| {
+        app.world
+            .get::<Rotation>(entity)
+            .unwrap()
+            .rotate(Vector::Y)
+            .angle_between(Vector::Y)
+    };
+
+    assert!(swing_angle(&app, narrow) < 0.35);
+    assert_relative_eq!(swing_angle(&app, wide), 0.6, epsilon = 0.01);
+
+    // The twist limits are measured as the rotation of the twist axes around the swing axes
+    assert!(swing_angle(&app, twisted) < 0.25);
+}
+
+#[test]
+fn generic_joint_limits_and_drives_axes() {
+    let mut app = create_app();
+
+    let anchor = app.world.spawn(RigidBody::Static).id();
+    let driven = spawn_body(&mut app, Vector::ZERO, 0.5);
+    let limited = spawn_body(&mut app, Vector::ZERO, 0.5);
+    let hinge = spawn_body(&mut app, Vector::ZERO, 0.5);
+
+    // Sliders along the X axis that are held up against gravity by the locked Y axis
+    app.world.spawn(
+        GenericJoint::new(anchor, driven)
+            .with_linear_motion(0, AxisMotion::limited(-1.0, 1.0))
+            .with_linear_drive(
+                0,
+                JointMotor::position(0.5)
+                    .with_compliance(0.001)
+                    .with_damping(50.0),
+            ),
+    );
+    app.world.spawn(
+        GenericJoint::new(anchor, limited)
+            .with_linear_motion(0, AxisMotion::limited(-1.0, 1.0))
+            .with_linear_drive(0, JointMotor::velocity(5.0)),
+    );
+
+    // A hinge around the Z axis that is driven past its angle limit
+    #[cfg(feature = "2d")]
+    let hinge_axis = 0;
+    #[cfg(feature = "3d")]
+    let hinge_axis = 2;
+    app.world.spawn(
+        GenericJoint::new(anchor, hinge)
+            .with_angular_motion(hinge_axis, AxisMotion::limited(-0.5, 0.5))
+            .with_angular_drive(hinge_axis, JointMotor::velocity(1.0)),
+    );
+
+    for _ in 0..120 {
+        tick_60_fps(&mut app);
+    }
+
+    let driven_position = app.world.get::<Position>(driven).unwrap();
+    assert_relative_eq!(driven_position.x, 0.5, epsilon = 0.05);
+    assert_relative_eq!(driven_position.y, 0.0, epsilon = 0.01);
+
+    let limited_position = app.world.get::<Position>(limited).unwrap();
+    assert_relative_eq!(limited_position.x, 1.0, epsilon = 0.01);
+    assert_relative_eq!(limited_position.y, 0.0, epsilon = 0.01);
+
+    let hinge_rotation = app.world.get::<Rotation>(hinge).unwrap();
+    #[cfg(feature = "2d")]
+    assert_relative_eq!(hinge_rotation.as_radians(), 0.5, epsilon = 0.01);
+    #[cfg(feature = "3d")]
+    assert_relative_eq!(
+        hinge_rotation.rotate(Vector::X).angle_between(Vector::X),
+        0.5,
+        epsilon = 0.01
+    );
+}
+
+#[cfg(all(feature = "3d", feature = "async-collider"))]
+#[test]
+fn ragdoll_is_built_from_skeleton() {
+    use bevy::render::mesh::skinning::SkinnedMesh;
+
+    let mut app = create_app();
+
+    // A leg made of bones that point down along their local Y axes
+    let spawn_bone = |app: &mut App, name: &str, transform: Transform| {
+        app.world
+            .spawn((
+                Name::new(name.to_string()),
+                SpatialBundle::from_transform(transform),
+            ))
+            .id()
+    };
+    let hips = spawn_bone(
+        &mut app,
+        "Hips",
+        Transform::from_xyz(0.0, 2.0, 0.0)
+            .with_rotation(Quat::from_rotation_z(std::f32::consts::PI)),
+    );
+    let thigh = spawn_bone(&mut app, "Thigh", Transform::from_xyz(0.0, 0.5, 0.0));
+    let shin = spawn_bone(&mut app, "Shin", Transform::from_xyz(0.0, 0.5, 0.0));
+    let toe = spawn_bone(&mut app, "Toe", Transform::from_xyz(0.0, 0.5, 0.0));
+    app.world.entity_mut(hips).add_child(thigh);
+    app.world.entity_mut(thigh).add_child(shin);
+    app.world.entity_mut(shin).add_child(toe);
+
+    let mesh = app
+        .world
+        .spawn(SkinnedMesh {
+            inverse_bindposes: default(),
+            joints: vec![hips, thigh, shin, toe],
+        })
+        .id();
+    let ragdoll = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            Ragdoll::new(Some(RagdollBoneData::default()))
+                .with_joint_for_name("Shin", RagdollJointLimits::Hinge { min: 0.0, max: 2.0 })
+                .without_bone_with_name("Toe"),
+        ))
+        .push_children(&[mesh, hips])
+        .id();
+
+    tick_60_fps(&mut app);
+
+    let bones = app.world.get::<RagdollBones>(ragdoll).unwrap();
+    assert_eq!(bones.0, vec![hips, thigh, shin]);
+    assert_eq!(
+        app.world.get::<RigidBody>(hips),
+        Some(&RigidBody::Kinematic)
+    );
+    assert!(app.world.get::<RigidBody>(toe).is_none());
+
+    let thigh_bone = *app.world.get::<RagdollBone>(thigh).unwrap();
+    let shin_bone = *app.world.get::<RagdollBone>(shin).unwrap();
+    assert_eq!(thigh_bone.parent, Some(hips));
+    assert_eq!(shin_bone.parent, Some(thigh));
+    assert!(app.world.get::<RagdollBone>(hips).unwrap().joint.is_none());
+    let shin_joint = app
+        .world
+        .get::<GenericJoint>(shin_bone.joint.unwrap())
+        .unwrap();
+    assert_eq!(shin_joint.entity1, thigh);
+    assert_eq!(shin_joint.angular_motions[1], AxisMotion::Locked);
+
+    // Let the ragdoll fall
+    *app.world.get_mut::<RagdollMode>(ragdoll).unwrap() = RagdollMode::Simulated;
+
+    for _ in 0..30 {
+        tick_60_fps(&mut app);
+    }
+
+    assert_eq!(app.world.get::<RigidBody>(hips), Some(&RigidBody::Dynamic));
+    let hips_position = app.world.get::<Position>(hips).unwrap().0;
+    let thigh_position = app.world.get::<Position>(thigh).unwrap().0;
+    assert!(hips_position.y < 1.5);
+    assert_relative_eq!(hips_position.distance(thigh_position), 0.5, epsilon = 0.01);
+
+    // The overlapping capsules of jointed bones don't collide
+    let collisions = app.world.resource::<Collisions>();
+    assert!(collisions.get(hips, thigh).is_none());
+    assert!(collisions.get(thigh, shin).is_none());
+}
+
+#[cfg(all(feature = "3d", feature = "async-collider"))]
+#[test]
+fn ragdoll_matches_reference_pose() {
+    use bevy::render::mesh::skinning::SkinnedMesh;
+
+    let mut app = create_app();
+
+    app.insert_resource(Gravity::ZERO);
+
+    let spawn_skeleton = |app: &mut App, arm_rotation: Quat| {
+        let hips = app
+            .world
+            .spawn((Name::new("Hips"), SpatialBundle::default()))
+            .id();
+        let arm = app
+            .world
+            .spawn((
+                Name::new("Arm"),
+                SpatialBundle::from_transform(
+                    Transform::from_xyz(0.0, 0.5, 0.0).with_rotation(arm_rotation),
+                ),
+            ))
+            .id();
+        app.world.entity_mut(hips).add_child(arm);
+        (hips, arm)
+    };
+
+    // The reference skeleton has its arm bent by 0.5 radians
+    let (reference_hips, _) = spawn_skeleton(&mut app, Quat::from_rotation_x(0.5));
+    let reference = app
+        .world
+        .spawn(SpatialBundle::default())
+        .add_child(reference_hips)
+        .id();
+
+    let (hips, arm) = spawn_skeleton(&mut app, Quat::IDENTITY);
+    let mesh = app
+        .world
+        .spawn(SkinnedMesh {
+            inverse_bindposes: default(),
+            joints: vec![hips, arm],
+        })
+        .id();
+    let ragdoll = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            Ragdoll::new(Some(RagdollBoneData::default())),
+            RagdollMode::Simulated,
+            RagdollPoseMatching::new(reference, Some(OrientationDrive::default())),
+        ))
+        .push_children(&[mesh, hips])
+        .id();
+
+    for _ in 0..60 {
+        tick_60_fps(&mut app);
+    }
+
+    let joint_entity = app.world.get::<RagdollBone>(arm).unwrap().joint.unwrap();
+    let drive = app
+        .world
+        .get::<GenericJoint>(joint_entity)
+        .unwrap()
+        .orientation_drive;
+    assert!(drive.is_some());
+
+    let hips_rotation = *app.world.get::<Rotation>(hips).unwrap();
+    let arm_rotation = *app.world.get::<Rotation>(arm).unwrap();
+    let relative_rotation = hips_rotation.inverse().0 * arm_rotation.0;
+    assert_relative_eq!(
+        relative_rotation.angle_between(Quaternion::from_rotation_x(0.5)),
+        0.0,
+        epsilon = 0.01
+    );
+
+    // Without pose matching, the ragdoll goes limp
+    app.world
+        .entity_mut(ragdoll)
+        .remove::<RagdollPoseMatching>();
+    tick_60_fps(&mut app);
+
+    let joint = app.world.get::<GenericJoint>(joint_entity).unwrap();
+    assert!(joint.orientation_drive.is_none());
+}
+
+#[test]
+fn jointed_and_excluded_bodies_dont_collide() {
+    let mut app = create_app();
+    app.insert_resource(Gravity::ZERO);
+
+    // Three pairs of overlapping bodies
+    let [jointed1, jointed2, colliding1, colliding2, excluded1, excluded2] = [
+        Vector::ZERO,
+        Vector::X * 0.5,
+        Vector::Y * 5.0,
+        Vector::X * 0.5 + Vector::Y * 5.0,
+        Vector::Y * 10.0,
+        Vector::X * 0.5 + Vector::Y * 10.0,
+    ]
+    .map(|position| {
+        let body = spawn_body(&mut app, position, 0.5);
+        app.world.entity_mut(body).insert(Collider::ball(0.5));
+        body
+    });
+
+    app.world
+        .spawn(DistanceJoint::new(jointed1, jointed2).with_rest_length(0.5));
+    app.world.spawn(
+        DistanceJoint::new(colliding1, colliding2)
+            .with_rest_length(0.5)
+            .with_collide_connected(true),
+    );
+    app.world
+        .entity_mut(excluded2)
+        .insert(CollisionExclusions::new([excluded1]));
+
+    tick_60_fps(&mut app);
+
+    let collisions = app.world.resource::<Collisions>();
+    assert!(!collisions.contains(jointed1, jointed2));
+    assert!(collisions.contains(colliding1, colliding2));
+    assert!(!collisions.contains(excluded1, excluded2));
+}
+
+#[cfg(feature = "3d")]
+#[test]
+fn articulated_chain_stays_connected() {
+    let mut app = create_app();
+
+    // A horizontal chain of ten links that swings down under gravity
+    let root = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Static,
+            Articulation::default(),
+        ))
+        .id();
+    let mut parent = root;
+    let mut links = vec![];
+    for i in 0..10 {
+        let link = app
+            .world
+            .spawn((
+                SpatialBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * (0.25 + 0.5 * i as Scalar)),
+                Collider::cuboid(0.5, 0.1, 0.1),
+                ArticulationLink::revolute(parent, Vector::Z)
+                    .with_local_anchor_1(if i == 0 {
+                        Vector::ZERO
+                    } else {
+                        Vector::X * 0.25
+                    })
+                    .with_local_anchor_2(Vector::NEG_X * 0.25),
+            ))
+            .id();
+        links.push(link);
+        parent = link;
+    }
+
+    for _ in 0..60 {
+        tick_60_fps(&mut app);
+    }
+
+    let articulation = app.world.get::<Articulation>(root).unwrap();
+    assert_eq!(articulation.links().collect::<Vec<_>>(), links);
+
+    // The first link still hangs from the root
+    let pos = app.world.get::<Position>(links[0]).unwrap();
+    let rot = app.world.get::<Rotation>(links[0]).unwrap();
+    assert!((pos.0 + rot.rotate(Vector::NEG_X * 0.25)).length() < 0.001);
+
+    // The chain started at rest at a height of zero, so the articulated-body algorithm
+    // should have turned the lost potential energy into kinetic energy
+    let mut potential_energy = 0.0;
+    let mut kinetic_energy = 0.0;
+    for &link in &links {
+        let mass = app.world.get::<Mass>(link).unwrap().0;
+        let inertia = app.world.get::<Inertia>(link).unwrap();
+        let rot = app.world.get::<Rotation>(link).unwrap();
+        let pos = app.world.get::<Position>(link).unwrap().0;
+        let lin_vel = app.world.get::<LinearVelocity>(link).unwrap().0;
+        let ang_vel = app.world.get::<AngularVelocity>(link).unwrap().0;
+        potential_energy += mass * 9.81 * pos.y;
+        kinetic_energy += 0.5 * mass * lin_vel.length_squared()
+            + 0.5 * ang_vel.dot(inertia.rotated(rot).0 * ang_vel);
+    }
+    assert_relative_eq!(kinetic_energy, -potential_energy, max_relative = 0.05);
+
+    // The chain has swung down, and the joint coordinates can be read back
+    let last = app.world.get::<Position>(links[9]).unwrap().0;
+    assert!(last.y < -2.0);
+    let first_link = app.world.get::<ArticulationLink>(links[0]).unwrap();
+    assert!(first_link.position() < -0.5);
+}
+
+#[cfg(feature = "3d")]
+#[test]
+fn links_attach_to_articulations_added_later() {
+    let mut app = create_app();
+
+    let root = app
+        .world
+        .spawn((SpatialBundle::default(), RigidBody::Static))
+        .id();
+    let link = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Dynamic,
+            Position(Vector::X * 0.5),
+            Collider::cuboid(0.5, 0.1, 0.1),
+            ArticulationLink::revolute(root, Vector::Z).with_local_anchor_2(Vector::NEG_X * 0.5),
+        ))
+        .id();
+
+    tick_60_fps(&mut app);
+    assert_eq!(
+        app.world
+            .get::<ArticulationLink>(link)
+            .unwrap()
+            .articulation(),
+        None
+    );
+
+    // Turning the root into an articulation attaches the existing link
+    app.world.entity_mut(root).insert(Articulation::default());
+    tick_60_fps(&mut app);
+
+    let articulation = app.world.get::<Articulation>(root).unwrap();
+    assert_eq!(articulation.links().collect::<Vec<_>>(), vec![link]);
+    assert_eq!(
+        app.world
+            .get::<ArticulationLink>(link)
+            .unwrap()
+            .articulation(),
+        Some(root)
+    );
+}
+
+#[cfg(feature = "3d")]
+#[test]
+fn articulation_drives_and_contacts_move_links() {
+    let mut app = create_app();
+
+    // A link driven to a target angle without gravity
+    let driven_root = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Static,
+            Articulation::default(),
+        ))
+        .id();
+    let driven_link = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Dynamic,
+            Position(Vector::X * 0.5),
+            GravityScale(0.0),
+            MassPropertiesBundle::new_computed(&Collider::cuboid(1.0, 0.1, 0.1), 1.0),
+            ArticulationLink::revolute(driven_root, Vector::Z)
+                .with_local_anchor_2(Vector::NEG_X * 0.5)
+                .with_drive(ArticulationDrive::new(100.0, 20.0).with_target_position(0.5)),
+        ))
+        .id();
+
+    // A link sliding down along a vertical axis onto the ground
+    app.world.spawn((
+        SpatialBundle::default(),
+        RigidBody::Static,
+        Position(Vector::X * 5.0 - Vector::Y),
+        Collider::cuboid(2.0, 1.0, 2.0),
+    ));
+    let sliding_root = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Static,
+            Position(Vector::X * 5.0 + Vector::Y * 3.0),
+            Articulation::default(),
+        ))
+        .id();
+    let sliding_link = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Dynamic,
+            Position(Vector::X * 5.0 + Vector::Y * 2.0),
+            Collider::cuboid(0.5, 0.5, 0.5),
+            ArticulationLink::prismatic(sliding_root, Vector::Y)
+                .with_local_anchor_2(Vector::Y)
+                .with_limits(-5.0, 0.0),
+        ))
+        .id();
+
+    for _ in 0..120 {
+        tick_60_fps(&mut app);
+    }
+
+    let driven = app.world.get::<ArticulationLink>(driven_link).unwrap();
+    assert_relative_eq!(driven.position(), 0.5, epsilon = 0.01);
+    assert_relative_eq!(driven.velocity(), 0.0, epsilon = 0.01);
+
+    // The link rests on the ground instead of falling to its lower limit
+    let sliding = app.world.get::<ArticulationLink>(sliding_link).unwrap();
+    assert_relative_eq!(sliding.position(), -2.25, epsilon = 0.05);
+    let sliding_position = app.world.get::<Position>(sliding_link).unwrap().0;
+    assert_relative_eq!(sliding_position.x, 5.0, epsilon = 0.001);
+}
+
+#[test]
+fn ccd_prevents_tunneling() {
+    let mut app = create_app();
+    app.insert_resource(Gravity::ZERO);
+
+    // A thin wall and three fast balls, of which only the one without CCD passes through the wall
+    app.world.spawn((
+        SpatialBundle::default(),
+        RigidBody::Static,
+        Position(Vector::X * 2.0),
+        #[cfg(feature = "2d")]
+        Collider::cuboid(0.05, 20.0),
+        #[cfg(feature = "3d")]
+        Collider::cuboid(0.05, 20.0, 20.0),
+    ));
+
+    let spawn_ball = |app: &mut App, y: Scalar, ccd: Option<Ccd>| {
+        let mut ball = app.world.spawn((
+            SpatialBundle::default(),
+            RigidBody::Dynamic,
+            Position(Vector::Y * y),
+            LinearVelocity(Vector::X * 500.0),
+            Collider::ball(0.1),
+        ));
+        if let Some(ccd) = ccd {
+            ball.insert(ccd);
+        }
+        ball.id()
+    };
+    let tunneling_ball = spawn_ball(&mut app, 0.0, None);
+    let swept_ball = spawn_ball(&mut app, 2.0, Some(Ccd::Sweep));
+    let speculative_ball = spawn_ball(&mut app, 4.0, Some(Ccd::Speculative));
+
+    // A frictionless ball that hits the wall at an angle after the first frame and should keep sliding along it
+    let grazing_ball = spawn_ball(&mut app, -4.0, Some(Ccd::Sweep));
+    app.world
+        .entity_mut(grazing_ball)
+        .insert((LinearVelocity::ZERO, Friction::ZERO));
+
+    tick_60_fps(&mut app);
+    app.world
+        .entity_mut(grazing_ball)
+        .insert(LinearVelocity(Vector::X * 500.0 + Vector::Y * 100.0));
+
+    for _ in 0..5 {
+        tick_60_fps(&mut app);
+    }
+
+    let x = |app: &App, entity: Entity| app.world.get::<Position>(entity).unwrap().x;
+    assert!(x(&app, tunneling_ball) > 2.0);
+    assert!(x(&app, swept_ball) < 2.0);
+    assert!(x(&app, speculative_ball) < 2.0);
+    assert!(x(&app, grazing_ball) < 2.0);
+    assert!(app.world.get::<Position>(grazing_ball).unwrap().y > -2.0);
+}
+
+#[test]
+fn static_friction_holds_resting_box() {
+    let mut app = create_app();
+
+    // Spawns a slope with the given angle and a box resting on it, returning the box
+    let spawn_slope = |app: &mut App, x: Scalar, angle: Scalar| {
+        #[cfg(feature = "2d")]
+        let rotation = Rotation::from_radians(angle);
+        #[cfg(feature = "3d")]
+        let rotation = Rotation(Quaternion::from_rotation_z(angle));
+
+        app.world.spawn((
+            SpatialBundle::default(),
+            RigidBody::Static,
+            Position(Vector::X * x),
+            rotation,
+            #[cfg(feature = "2d")]
+            Collider::cuboid(20.0, 1.0),
+            #[cfg(feature = "3d")]
+            Collider::cuboid(20.0, 1.0, 20.0),
+        ));
+        app.world
+            .spawn((
+                SpatialBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * x + rotation.rotate(Vector::Y)),
+                rotation,
+                #[cfg(feature = "2d")]
+                Collider::cuboid(1.0, 1.0),
+                #[cfg(feature = "3d")]
+                Collider::cuboid(1.0, 1.0, 1.0),
+            ))
+            .id()
+    };
+
+    // The default static friction coefficient of 0.3 corresponds to a friction angle of about 17 degrees
+    let resting_box = spawn_slope(&mut app, 0.0, PI / 18.0);
+    let sliding_box = spawn_slope(&mut app, 50.0, PI / 6.0);
+
+    // Let the contacts settle before measuring how far the boxes move
+    for _ in 0..10 {
+        tick_60_fps(&mut app);
+    }
+    let position = |app: &App, entity: Entity| app.world.get::<Position>(entity).unwrap().0;
+    let resting_start = position(&app, resting_box);
+    let sliding_start = position(&app, sliding_box);
+
+    for _ in 0..120 {
+        tick_60_fps(&mut app);
+    }
+
+    // The box on the shallow slope doesn't creep, while the box on the steep slope slides down
+    assert!(position(&app, resting_box).distance(resting_start) < 0.01);
+    assert!(position(&app, sliding_box).distance(sliding_start) > 1.0);
+}
+
+#[test]
+fn collision_impacts_report_impulses() {
+    let mut app = create_app();
+
+    app.world.spawn((
+        SpatialBundle::default(),
+        RigidBody::Static,
+        Position(Vector::NEG_Y),
+        #[cfg(feature = "2d")]
+        Collider::cuboid(20.0, 1.0),
+        #[cfg(feature = "3d")]
+        Collider::cuboid(20.0, 1.0, 20.0),
+    ));
+    // The ball falls 2 meters before hitting the ground
+    let ball = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Dynamic,
+            Position(Vector::Y * 2.0),
+            Collider::ball(0.5),
+        ))
+        .id();
+
+    let mut reader = app.world.resource::<Events<CollisionImpact>>().get_reader();
+    let mut impacts = vec![];
+
+    for _ in 0..90 {
+        tick_60_fps(&mut app);
+        let events = app.world.resource::<Events<CollisionImpact>>();
+        impacts.extend(reader.read(events).copied());
+    }
+
+    // The ball bounces, so the first impact is the hardest one
+    assert!(impacts.len() > 1);
+    let impact = impacts[0];
+    assert!(impact.entity1 == ball || impact.entity2 == ball);
+
+    // v = sqrt(2 * g * h)
+    let expected_speed = (2.0 * 9.81 * 2.0 as Scalar).sqrt();
+    assert!((impact.impact_speed - expected_speed).abs() < 0.5);
+
+    // The impulse must at least stop the ball
+    let mass = app.world.get::<Mass>(ball).unwrap().0;
+    assert!(impact.normal_impulse > 0.9 * mass * expected_speed);
+    assert!(impacts[1..]
+        .iter()
+        .all(|other| other.impact_speed > 0.0 && other.impact_speed < impact.impact_speed));
+
+    // The resting ball keeps accumulating impulses that hold it against gravity
+    let collisions = app.world.resource::<Collisions>();
+    let contacts = collisions.iter().next().unwrap();
+    assert!(contacts.total_normal_impulse > 0.0);
+    assert!(contacts.max_impact_speed < 0.5);
+}
+
+#[test]
+fn one_way_colliders_block_one_side() {
+    let mut app = create_app();
+
+    app.world.spawn((
+        SpatialBundle::default(),
+        RigidBody::Static,
+        #[cfg(feature = "2d")]
+        Collider::cuboid(4.0, 0.2),
+        #[cfg(feature = "3d")]
+        Collider::cuboid(4.0, 0.2, 4.0),
+        OneWayCollider::default(),
+    ));
+    // The ball is thrown up through the platform from below
+    let ball = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Dynamic,
+            Position(Vector::NEG_Y),
+            LinearVelocity(Vector::Y * 8.0),
+            Collider::ball(0.25),
+        ))
+        .id();
+
+    for _ in 0..120 {
+        tick_60_fps(&mut app);
+    }
+
+    // The ball lands on top of the platform
+    let y = |app: &App| app.world.get::<Position>(ball).unwrap().y;
+    assert!((y(&app) - 0.35).abs() < 0.05);
+
+    // Drop down through the platform
+    app.world
+        .entity_mut(ball)
+        .insert(PassThroughOneWayColliders)
+        .remove::<Sleeping>();
+
+    for _ in 0..30 {
+        tick_60_fps(&mut app);
+    }
+
+    assert!(y(&app) < -0.5);
+}
+
+#[test]
+fn one_way_colliders_forget_entities_that_stop_touching_them() {
+    let mut app = create_app();
+
+    let platform = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Static,
+            #[cfg(feature = "2d")]
+            Collider::cuboid(4.0, 0.2),
+            #[cfg(feature = "3d")]
+            Collider::cuboid(4.0, 0.2, 4.0),
+            OneWayCollider::default(),
+        ))
+        .id();
+    let spawn_ball = |app: &mut App, x: Scalar| {
+        app.world
+            .spawn((
+                SpatialBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * x),
+                Collider::ball(0.25),
+                PassThroughOneWayColliders,
+            ))
+            .id()
+    };
+
+    // Two balls that start passing through the platform
+    let ball = spawn_ball(&mut app, 0.0);
+    let despawned_ball = spawn_ball(&mut app, 1.0);
+
+    for _ in 0..2 {
+        tick_60_fps(&mut app);
+    }
+
+    let one_way_collider = app.world.get::<OneWayCollider>(platform).unwrap();
+    assert!(one_way_collider.is_passing_through(ball));
+    assert!(one_way_collider.is_passing_through(despawned_ball));
+
+    // Move the first ball above the platform while it is still passing through it, and despawn the second ball
+    app.world
+        .entity_mut(ball)
+        .remove::<PassThroughOneWayColliders>()
+        .insert((Position(Vector::Y * 2.0), LinearVelocity::ZERO));
+    app.world.despawn(despawned_ball);
+
+    for _ in 0..60 {
+        tick_60_fps(&mut app);
+    }
+
+    // The first ball lands on top of the platform instead of falling through it
+    let one_way_collider = app.world.get::<OneWayCollider>(platform).unwrap();
+    assert!(!one_way_collider.is_passing_through(ball));
+    assert!(!one_way_collider.is_passing_through(despawned_ball));
+    assert!((app.world.get::<Position>(ball).unwrap().y - 0.35).abs() < 0.05);
+}
+
+#[test]
+fn collision_hooks_remove_contacts() {
+    use bevy::ecs::system::SystemParam;
+
+    #[derive(Component)]
+    struct Ghost;
+
+    #[derive(SystemParam)]
+    struct GhostHook<'w, 's> {
+        ghosts: Query<'w, 's, (), With<Ghost>>,
+    }
+
+    impl CollisionHook for GhostHook<'_, '_> {
+        fn modify_contacts(&mut self, contacts: &mut Contacts) -> bool {
+            !self.ghosts.contains(contacts.entity1) && !self.ghosts.contains(contacts.entity2)
+        }
+    }
+
+    let mut app = create_app();
+    app.add_plugins(CollisionHookPlugin::<GhostHook>::default());
+
+    app.world.spawn((
+        SpatialBundle::default(),
+        RigidBody::Static,
+        Position(Vector::NEG_Y),
+        #[cfg(feature = "2d")]
+        Collider::cuboid(20.0, 1.0),
+        #[cfg(feature = "3d")]
+        Collider::cuboid(20.0, 1.0, 20.0),
+    ));
+
+    let spawn_ball = |app: &mut App, x: Scalar| {
+        app.world
+            .spawn((
+                SpatialBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * x),
+                Collider::ball(0.5),
+            ))
+            .id()
+    };
+    let ball = spawn_ball(&mut app, 0.0);
+    let ghost = spawn_ball(&mut app, 2.0);
+    app.world.entity_mut(ghost).insert(Ghost);
+
+    for _ in 0..60 {
+        tick_60_fps(&mut app);
+    }
+
+    assert!(app.world.get::<Position>(ball).unwrap().y > -0.1);
+    assert!(app.world.get::<Position>(ghost).unwrap().y < -1.0);
+}
+
+#[test]
+fn surface_velocity_carries_bodies() {
+    let mut app = create_app();
+
+    // A static conveyor belt moving towards positive X
+    app.world.spawn((
+        SpatialBundle::default(),
+        RigidBody::Static,
+        Position(Vector::NEG_Y),
+        #[cfg(feature = "2d")]
+        Collider::cuboid(40.0, 1.0),
+        #[cfg(feature = "3d")]
+        Collider::cuboid(40.0, 1.0, 40.0),
+        SurfaceVelocity::new(Vector::X * 2.0),
+    ));
+    let cube = app
+        .world
+        .spawn((
+            SpatialBundle::default(),
+            RigidBody::Dynamic,
+            #[cfg(feature = "2d")]
+            Collider::cuboid(1.0, 1.0),
+            #[cfg(feature = "3d")]
+            Collider::cuboid(1.0, 1.0, 1.0),
+        ))
+        .id();
+
+    for _ in 0..120 {
+        tick_60_fps(&mut app);
+    }
+
+    // The cube moves with the belt without sliding off of it
+    let velocity = app.world.get::<LinearVelocity>(cube).unwrap().0;
+    assert!((velocity.x - 2.0).abs() < 0.1);
+    let position = app.world.get::<Position>(cube).unwrap().0;
+    assert!(position.x > 3.0);
+    assert!(position.y.abs() < 0.05);
+}
+
+#[test]
+fn rolling_friction_stops_rolling_balls() {
+    let roll_ball = |rolling_friction: Option<RollingFriction>| {
+        let mut app = create_app();
+
+        app.world.spawn((
+            SpatialBundle::default(),
+            RigidBody::Static,
+            Position(Vector::NEG_Y),
+            #[cfg(feature = "2d")]
+            Collider::cuboid(200.0, 1.0),
+            #[cfg(feature = "3d")]
+            Collider::cuboid(200.0, 1.0, 200.0),
+        ));
+        let mut ball = app.world.spawn((
+            SpatialBundle::default(),
+            RigidBody::Dynamic,
+            Collider::ball(0.5),
+            LinearVelocity(Vector::X * 3.0),
+        ));
+        if let Some(rolling_friction) = rolling_friction {
+            ball.insert(rolling_friction);
+        }
+        let ball = ball.id();
+
+        for _ in 0..240 {
+            tick_60_fps(&mut app);
+        }
+
+        app.world.get::<LinearVelocity>(ball).unwrap().0
+    };
+
+    // Without rolling friction, the ball keeps rolling
+    assert!(roll_ball(None).x > 1.0);
+
+    // With rolling friction, the ball comes to rest
+    let velocity = roll_ball(Some(RollingFriction::new(0.1)));
+    assert!(velocity.length() < 0.05);
+}
+
+#[cfg(all(feature = "3d", feature = "debug-plugin"))]
+#[test]
+fn picking_finds_nearest_soft_body_surface_particle() {
+    use crate::plugins::debug::pick_soft_body_surface;
+
+    let mut world = World::new();
+    let mut spawn_particle = |position: Vector| world.spawn(Position(position)).id();
+
+    // Two stacked triangles facing the ray
+    let near = [
+        spawn_particle(Vector::new(-1.0, -1.0, 0.0)),
+        spawn_particle(Vector::new(1.0, -1.0, 0.0)),
+        spawn_particle(Vector::new(0.0, 1.0, 0.0)),
+    ];
+    let far = [
+        spawn_particle(Vector::new(-1.0, -1.0, -1.0)),
+        spawn_particle(Vector::new(1.0, -1.0, -1.0)),
+        spawn_particle(Vector::new(0.0, 1.0, -1.0)),
+    ];
+    let surfaces = [
+        SoftBodySurface {
+            triangles: vec![far],
+        },
+        SoftBodySurface {
+            triangles: vec![near],
+        },
+    ];
+    let positions = |particle| world.get::<Position>(particle).map(|position| position.0);
+
+    // The nearest triangle is hit, and the particle closest to the hit point is picked
+    let origin = Vector::new(0.5, -0.5, 5.0);
+    let (particle, hit_point) =
+        pick_soft_body_surface(&surfaces, positions, origin, Vector::NEG_Z).unwrap();
+    assert_eq!(particle, near[1]);
+    assert_relative_eq!(hit_point, Vector::new(0.5, -0.5, 0.0), epsilon = 0.0001);
+
+    // Rays that pass next to the triangles don't pick anything
+    let origin = Vector::new(2.0, 0.0, 5.0);
+    assert!(pick_soft_body_surface(&surfaces, positions, origin, Vector::NEG_Z).is_none());
+}
+
 #[test]
 fn no_ambiguity_errors() {
     #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
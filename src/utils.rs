@@ -58,6 +58,65 @@ pub(crate) fn compute_dynamic_friction(
     -(coefficient * normal_impulse.abs()).min(tangent_speed / generalized_inv_mass_sum)
 }
 
+/// Computes the angular impulse caused by rolling friction, opposing the relative angular velocity.
+///
+/// The impulse is clamped so that it never reverses the rotation.
+#[cfg(feature = "2d")]
+pub(crate) fn compute_rolling_friction(
+    relative_angular_velocity: Scalar,
+    inverse_inertia_sum: Scalar,
+    rolling_coefficient: Scalar,
+    normal_impulse: Scalar,
+) -> Scalar {
+    let angular_speed = relative_angular_velocity.abs();
+    if angular_speed <= Scalar::EPSILON || inverse_inertia_sum <= Scalar::EPSILON {
+        return 0.0;
+    }
+
+    -relative_angular_velocity.signum()
+        * (rolling_coefficient * normal_impulse).min(angular_speed / inverse_inertia_sum)
+}
+
+/// Computes the angular impulse caused by rolling and torsional friction, opposing the relative
+/// angular velocity around axes along the contact surface and around the contact normal respectively.
+///
+/// The impulses are clamped so that they never reverse the rotation.
+#[cfg(feature = "3d")]
+pub(crate) fn compute_rolling_friction(
+    relative_angular_velocity: Vector,
+    normal: Vector,
+    inverse_inertia_sum: Matrix3,
+    rolling_coefficient: Scalar,
+    torsional_coefficient: Scalar,
+    normal_impulse: Scalar,
+) -> Vector {
+    let torsional_velocity = normal * normal.dot(relative_angular_velocity);
+    let rolling_velocity = relative_angular_velocity - torsional_velocity;
+
+    let mut angular_impulse = Vector::ZERO;
+
+    for (velocity, coefficient) in [
+        (rolling_velocity, rolling_coefficient),
+        (torsional_velocity, torsional_coefficient),
+    ] {
+        let angular_speed = velocity.length();
+        if angular_speed <= Scalar::EPSILON || coefficient <= 0.0 {
+            continue;
+        }
+
+        let axis = velocity / angular_speed;
+        let generalized_inverse_inertia = axis.dot(inverse_inertia_sum * axis);
+        if generalized_inverse_inertia <= Scalar::EPSILON {
+            continue;
+        }
+
+        angular_impulse -=
+            axis * (coefficient * normal_impulse).min(angular_speed / generalized_inverse_inertia);
+    }
+
+    angular_impulse
+}
+
 /// Computes the speed correction caused by restitution.
 pub(crate) fn compute_restitution(
     normal_speed: Scalar,